@@ -0,0 +1,82 @@
+use std::{fs::read_to_string, path::Path};
+
+use aoc::manifest::{self, MANIFEST_FILE};
+use common::{Part, Solution};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+const WORKSPACE_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+/// Benchmarks parsing and each part separately, against the worked examples and the real input.
+/// Parts marked as slow in the answers manifest are skipped on the real input.
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    let root = Path::new(WORKSPACE_ROOT);
+    let day = S::DAY;
+
+    let slow: Vec<Part> = manifest::load(&root.join(MANIFEST_FILE))
+        .unwrap()
+        .into_iter()
+        .filter(|entry| entry.day == day && entry.slow)
+        .map(|entry| entry.part)
+        .collect();
+
+    let input = read_to_string(root.join(format!("day-{day:02}")).join("input")).unwrap();
+
+    let mut group = c.benchmark_group(format!("day-{day:02}"));
+
+    group.sample_size(10);
+
+    for (name, inputs, skip) in [
+        (
+            "example",
+            [S::EXAMPLE_PART1, S::EXAMPLE_PART2],
+            Vec::default(),
+        ),
+        ("input", [input.as_str(), input.as_str()], slow),
+    ] {
+        group.bench_with_input(BenchmarkId::new("parse", name), inputs[0], |b, input| {
+            b.iter(|| S::parse(black_box(input)));
+        });
+
+        if !skip.contains(&Part::One) {
+            let parsed = S::parse(inputs[0]).unwrap();
+
+            group.bench_with_input(BenchmarkId::new("part1", name), &parsed, |b, parsed| {
+                b.iter(|| S::part1(black_box(parsed)));
+            });
+        }
+
+        if !skip.contains(&Part::Two) {
+            let parsed = S::parse(inputs[1]).unwrap();
+
+            group.bench_with_input(BenchmarkId::new("part2", name), &parsed, |b, parsed| {
+                b.iter(|| S::part2(black_box(parsed)));
+            });
+        }
+    }
+
+    group.finish();
+}
+
+fn solvers(c: &mut Criterion) {
+    bench_day::<day_01::Day01>(c);
+    bench_day::<day_02::Day02>(c);
+    bench_day::<day_03::Day03>(c);
+    bench_day::<day_04::Day04>(c);
+    bench_day::<day_05::Day05>(c);
+    bench_day::<day_06::Day06>(c);
+    bench_day::<day_07::Day07>(c);
+    bench_day::<day_08::Day08>(c);
+    bench_day::<day_09::Day09>(c);
+    bench_day::<day_10::Day10>(c);
+    bench_day::<day_11::Day11>(c);
+    bench_day::<day_12::Day12>(c);
+    bench_day::<day_13::Day13>(c);
+    bench_day::<day_14::Day14>(c);
+    bench_day::<day_15::Day15>(c);
+    bench_day::<day_16::Day16>(c);
+    bench_day::<day_17::Day17>(c);
+    bench_day::<day_18::Day18>(c);
+}
+
+criterion_group!(benches, solvers);
+criterion_main!(benches);
@@ -0,0 +1,454 @@
+//! Turning visualisation frames into pictures: a PPM or PNG image per frame, or an animated GIF.
+
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fs::{create_dir_all, write, File},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use grid::render::{Colour, Frame};
+
+pub type Rgb = [u8; 3];
+
+/// The colours cells are drawn in: one for each ANSI colour, plus the shades for cells with
+/// neither, from `empty` for `.` up to `glyph` for anything else, with digits in between.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Palette {
+    pub empty: Rgb,
+    pub glyph: Rgb,
+    pub colours: [Rgb; 8],
+}
+
+const COLOUR_NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+impl Palette {
+    pub const fn dark() -> Self {
+        Self {
+            empty: [0x10, 0x10, 0x18],
+            glyph: [0x90, 0x90, 0x98],
+            colours: [
+                [0x00, 0x00, 0x00],
+                [0xe0, 0x30, 0x30],
+                [0x30, 0xc0, 0x50],
+                [0xf0, 0xc0, 0x20],
+                [0x30, 0x70, 0xe0],
+                [0xc0, 0x40, 0xc0],
+                [0x30, 0xc0, 0xc0],
+                [0xf0, 0xf0, 0xf0],
+            ],
+        }
+    }
+
+    pub const fn light() -> Self {
+        Self {
+            empty: [0xf8, 0xf8, 0xf4],
+            glyph: [0x50, 0x50, 0x58],
+            colours: [
+                [0x00, 0x00, 0x00],
+                [0xc0, 0x10, 0x10],
+                [0x10, 0x90, 0x30],
+                [0xe0, 0xa0, 0x00],
+                [0x10, 0x50, 0xc0],
+                [0xa0, 0x20, 0xa0],
+                [0x10, 0x90, 0x90],
+                [0xc0, 0xc0, 0xc0],
+            ],
+        }
+    }
+
+    /// Overrides one colour from a `name=#rrggbb` setting, where the name is `empty`, `glyph` or
+    /// one of the ANSI colours.
+    pub fn set(&mut self, setting: &str) -> Result<(), String> {
+        let invalid = || format!("Invalid colour `{setting}`, expected `name=#rrggbb`");
+
+        let (name, hex) = setting.split_once('=').ok_or_else(invalid)?;
+        let rgb = parse_rgb(hex).ok_or_else(invalid)?;
+
+        let slot = match name {
+            "empty" => &mut self.empty,
+            "glyph" => &mut self.glyph,
+            _ => {
+                let index = COLOUR_NAMES
+                    .iter()
+                    .position(|colour| *colour == name)
+                    .ok_or_else(|| {
+                        format!(
+                            "Unknown colour `{name}`, expected `empty`, `glyph` or one of {}",
+                            COLOUR_NAMES.join(", ")
+                        )
+                    })?;
+
+                &mut self.colours[index]
+            }
+        };
+
+        *slot = rgb;
+
+        Ok(())
+    }
+
+    const fn colour(&self, colour: Colour) -> Rgb {
+        self.colours[colour as usize]
+    }
+
+    fn shade(&self, glyph: char) -> Rgb {
+        match glyph {
+            '.' | ' ' => self.empty,
+            _ => match glyph.to_digit(10) {
+                Some(digit) => mix(self.empty, self.glyph, digit),
+                None => self.glyph,
+            },
+        }
+    }
+}
+
+fn parse_rgb(hex: &str) -> Option<Rgb> {
+    let hex = hex.strip_prefix('#')?;
+
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+
+    let channel = |index: usize| u8::from_str_radix(&hex[index..index + 2], 16).ok();
+
+    Some([channel(0)?, channel(2)?, channel(4)?])
+}
+
+/// `weight` ninths of the way from `from` to `to`.
+fn mix(from: Rgb, to: Rgb, weight: u32) -> Rgb {
+    let weight = weight.min(9);
+
+    [0, 1, 2].map(|channel| {
+        let (from, to) = (u32::from(from[channel]), u32::from(to[channel]));
+
+        u8::try_from((from * (9 - weight) + to * weight) / 9).unwrap_or(u8::MAX)
+    })
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// Draws each cell as a `cell_size` square in its background colour if it has one, or else
+    /// its foreground colour, or else the shade for its glyph. A cell with both colours gets its
+    /// foreground as a smaller square in the middle, so paths stay visible over highlights.
+    pub fn render(frame: &Frame, palette: &Palette, cell_size: usize) -> Self {
+        let width = frame.cells.width() * cell_size;
+        let height = frame.cells.height() * cell_size;
+
+        let mut pixels = vec![palette.empty; width * height];
+
+        let inset = cell_size / 4;
+
+        for (point, cell) in frame.cells.iter() {
+            let fill = cell.background.or(cell.foreground).map_or_else(
+                || palette.shade(cell.glyph),
+                |colour| palette.colour(colour),
+            );
+
+            let inner = cell
+                .background
+                .and(cell.foreground)
+                .map(|colour| palette.colour(colour));
+
+            for dy in 0..cell_size {
+                for dx in 0..cell_size {
+                    let inside = (inset..cell_size - inset).contains(&dx)
+                        && (inset..cell_size - inset).contains(&dy);
+
+                    pixels[(point.y * cell_size + dy) * width + point.x * cell_size + dx] =
+                        match inner {
+                            Some(inner) if inside => inner,
+                            _ => fill,
+                        };
+                }
+            }
+        }
+
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// A binary PPM (P6) image.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut ppm = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+
+        ppm.extend(self.pixels.iter().flatten());
+
+        ppm
+    }
+
+    pub fn write_png(&self, out: impl Write) -> Result<(), String> {
+        let width = u32::try_from(self.width).map_err(|e| e.to_string())?;
+        let height = u32::try_from(self.height).map_err(|e| e.to_string())?;
+
+        let mut encoder = png::Encoder::new(out, width, height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header().map_err(|e| e.to_string())?;
+
+        writer
+            .write_image_data(&self.pixels.concat())
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Every `stride`th frame, starting with the first, and always the last.
+pub fn every(frames: Vec<Frame>, stride: usize) -> Vec<Frame> {
+    let last = frames.len().saturating_sub(1);
+
+    frames
+        .into_iter()
+        .enumerate()
+        .filter(|(index, _)| index % stride.max(1) == 0 || *index == last)
+        .map(|(_, frame)| frame)
+        .collect()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+}
+
+/// Writes the images into `directory` as `frame-00001.png` and so on, returning their paths.
+pub fn write_sequence(
+    images: &[Image],
+    directory: &Path,
+    format: ImageFormat,
+) -> Result<Vec<PathBuf>, String> {
+    create_dir_all(directory)
+        .map_err(|e| format!("Could not create {}: {e}", directory.display()))?;
+
+    let extension = match format {
+        ImageFormat::Ppm => "ppm",
+        ImageFormat::Png => "png",
+    };
+
+    images
+        .iter()
+        .enumerate()
+        .map(|(index, image)| {
+            let path = directory.join(format!("frame-{:05}.{extension}", index + 1));
+            let could_not_write = |e: String| format!("Could not write {}: {e}", path.display());
+
+            match format {
+                ImageFormat::Ppm => write(&path, image.to_ppm()).map_err(|e| e.to_string()),
+                ImageFormat::Png => File::create(&path)
+                    .map_err(|e| e.to_string())
+                    .and_then(|file| image.write_png(BufWriter::new(file))),
+            }
+            .map_err(could_not_write)?;
+
+            Ok(path)
+        })
+        .collect()
+}
+
+/// Writes the images as a looping GIF, showing each for `delay`. Every colour goes in one
+/// global palette, which the handful used by the renderer fits in easily.
+pub fn write_gif(images: &[Image], delay: Duration, out: impl Write) -> Result<(), String> {
+    let first = images.first().ok_or("There are no frames to write")?;
+
+    let too_large = |_| {
+        format!(
+            "{}x{} is too large for a GIF, try a smaller cell size",
+            first.width, first.height
+        )
+    };
+
+    let width = u16::try_from(first.width).map_err(too_large)?;
+    let height = u16::try_from(first.height).map_err(too_large)?;
+
+    let mut indices: HashMap<Rgb, u8> = HashMap::new();
+    let mut palette: Vec<u8> = Vec::new();
+
+    let frames: Vec<Vec<u8>> = images
+        .iter()
+        .map(|image| {
+            image
+                .pixels
+                .iter()
+                .map(|rgb| {
+                    if let Some(index) = indices.get(rgb) {
+                        return Ok(*index);
+                    }
+
+                    let index = u8::try_from(indices.len())
+                        .map_err(|_| String::from("More than 256 colours for a GIF"))?;
+
+                    indices.insert(*rgb, index);
+                    palette.extend(rgb);
+
+                    Ok(index)
+                })
+                .collect()
+        })
+        .collect::<Result<_, String>>()?;
+
+    let delay = u16::try_from(delay.as_millis() / 10).unwrap_or(u16::MAX);
+
+    let mut encoder = gif::Encoder::new(out, width, height, &palette).map_err(|e| e.to_string())?;
+
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(|e| e.to_string())?;
+
+    for buffer in frames {
+        let frame = gif::Frame {
+            width,
+            height,
+            delay,
+            buffer: Cow::Owned(buffer),
+            ..gif::Frame::default()
+        };
+
+        encoder.write_frame(&frame).map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{read, remove_dir_all};
+
+    use grid::{Grid, Point};
+
+    use super::*;
+    use crate::mock_server::temp_root;
+
+    fn frame() -> Frame {
+        let mut frame = Frame::new(&Grid::parse(".#\n9.", Some).unwrap(), |c| *c);
+
+        frame
+            .highlight([Point::new(1, 1)], Colour::Yellow)
+            .path(&[Point::new(1, 1)], Colour::Red);
+
+        frame
+    }
+
+    #[test]
+    fn test_palette() {
+        let mut palette = Palette::dark();
+
+        palette.set("red=#FF0001").unwrap();
+        palette.set("empty=#000000").unwrap();
+
+        assert_eq!(palette.colour(Colour::Red), [0xff, 0x00, 0x01]);
+        assert_eq!(palette.empty, [0, 0, 0]);
+
+        assert_eq!(
+            palette.set("red=ff0000"),
+            Err(String::from(
+                "Invalid colour `red=ff0000`, expected `name=#rrggbb`"
+            ))
+        );
+        assert!(palette
+            .set("pink=#ff0000")
+            .unwrap_err()
+            .contains("Unknown colour `pink`"));
+        assert!(palette.set("red=#ff00").is_err());
+        assert!(palette.set("red=#ff00gg").is_err());
+    }
+
+    #[test]
+    fn test_render() {
+        let palette = Palette::dark();
+        let image = Image::render(&frame(), &palette, 4);
+
+        assert_eq!((image.width, image.height), (8, 8));
+
+        let pixel = |x: usize, y: usize| image.pixels[y * image.width + x];
+
+        assert_eq!(pixel(0, 0), palette.empty);
+        assert_eq!(pixel(7, 0), palette.glyph);
+        assert_eq!(pixel(0, 7), palette.glyph);
+        assert_eq!(pixel(4, 4), palette.colour(Colour::Yellow));
+        assert_eq!(pixel(5, 5), palette.colour(Colour::Red));
+        assert_eq!(pixel(6, 6), palette.colour(Colour::Red));
+        assert_eq!(pixel(7, 7), palette.colour(Colour::Yellow));
+
+        assert_eq!(mix([0, 0, 0], [90, 180, 9], 3), [30, 60, 3]);
+    }
+
+    #[test]
+    fn test_formats() {
+        let image = Image::render(&frame(), &Palette::light(), 1);
+
+        let ppm = image.to_ppm();
+        assert!(ppm.starts_with(b"P6\n2 2\n255\n"));
+        assert_eq!(ppm.len(), 11 + 2 * 2 * 3);
+
+        let mut png = Vec::new();
+        image.write_png(&mut png).unwrap();
+
+        let mut reader = png::Decoder::new(png.as_slice()).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        reader.next_frame(&mut pixels).unwrap();
+
+        assert_eq!(pixels, image.pixels.concat());
+
+        let mut gif = Vec::new();
+        write_gif(
+            &[image.clone(), image.clone(), image],
+            Duration::from_millis(50),
+            &mut gif,
+        )
+        .unwrap();
+
+        let mut decoder = gif::DecodeOptions::new().read_info(gif.as_slice()).unwrap();
+        let mut frames = 0;
+
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!((frame.width, frame.height, frame.delay), (2, 2, 5));
+            frames += 1;
+        }
+
+        assert_eq!(frames, 3);
+
+        assert!(write_gif(&[], Duration::ZERO, Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_write_sequence() {
+        let root = temp_root("export");
+        let images = [Image::render(&frame(), &Palette::dark(), 2)];
+
+        let paths = write_sequence(&images, &root.join("frames"), ImageFormat::Ppm).unwrap();
+
+        assert_eq!(paths, [root.join("frames").join("frame-00001.ppm")]);
+        assert_eq!(read(&paths[0]).unwrap(), images[0].to_ppm());
+
+        remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_every() {
+        let frames: Vec<Frame> = (0..8)
+            .map(|index| frame().with_caption(index.to_string()))
+            .collect();
+
+        let captions = |frames: Vec<Frame>| -> Vec<String> {
+            frames.into_iter().map(|frame| frame.caption).collect()
+        };
+
+        assert_eq!(captions(every(frames.clone(), 3)), ["0", "3", "6", "7"]);
+        assert_eq!(captions(every(frames.clone(), 1)).len(), 8);
+        assert_eq!(captions(every(frames, 7)), ["0", "7"]);
+        assert!(every(Vec::new(), 2).is_empty());
+    }
+}
@@ -0,0 +1,442 @@
+use std::{
+    env,
+    fs::{create_dir_all, read_to_string, write},
+    io::Read,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::Deserialize;
+
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+pub const CONFIG_ENV: &str = "AOC_CONFIG";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Where the last request time is kept, relative to the workspace root, so the rate limit holds
+/// across runs.
+pub const TIMESTAMP_FILE: &str = ".fetch-timestamp";
+
+/// The shortest gap allowed between two requests to the server.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+pub(crate) const YEAR: u16 = 2023;
+
+const USER_AGENT: &str = concat!(
+    "advent-of-code-2023/",
+    env!("CARGO_PKG_VERSION"),
+    " aoc fetch"
+);
+
+/// Settings read from the config file. The matching environment variables take precedence.
+#[derive(Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        toml::from_str(contents).map_err(|e| e.to_string())
+    }
+
+    /// Loads the config from `path`, `AOC_CONFIG` or `~/.config/aoc/config.toml`, in that order.
+    /// Only a file that was asked for explicitly has to exist.
+    pub fn load(path: Option<&Path>) -> Result<Self, String> {
+        let (path, required) = match path {
+            Some(path) => (path.to_path_buf(), true),
+            None => match env::var_os(CONFIG_ENV) {
+                Some(path) => (PathBuf::from(path), true),
+                None => match default_config_path() {
+                    Some(path) => (path, false),
+                    None => return Ok(Self::default()),
+                },
+            },
+        };
+
+        match read_to_string(&path) {
+            Ok(contents) => Self::parse(&contents)
+                .map_err(|e| format!("Invalid config {}: {e}", path.display())),
+            Err(_) if !required => Ok(Self::default()),
+            Err(e) => Err(format!("Could not load config {}: {e}", path.display())),
+        }
+    }
+}
+
+fn default_config_path() -> Option<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+        .map(|config| config.join("aoc").join("config.toml"))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached,
+    Downloaded { examples: usize },
+}
+
+pub struct Fetcher {
+    pub base_url: String,
+    /// Only needed once something has to be downloaded, so cached days can be fetched without it.
+    pub session: Option<String>,
+    /// Workspace root; each day's files go into its `day-NN` directory.
+    pub root: PathBuf,
+    pub min_interval: Duration,
+    agent: ureq::Agent,
+}
+
+impl Fetcher {
+    pub fn new(base_url: &str, session: Option<&str>, root: &Path) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session
+                .map(str::trim)
+                .filter(|session| !session.is_empty())
+                .map(str::to_string),
+            root: root.to_path_buf(),
+            min_interval: MIN_INTERVAL,
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    /// Builds a fetcher from the environment and `config`. The server is `base_url` if given,
+    /// then `AOC_BASE_URL`, then the config, falling back to the real site.
+    pub fn from_config(
+        config: &Config,
+        base_url: Option<&str>,
+        root: &Path,
+    ) -> Result<Self, String> {
+        let session = env::var(SESSION_ENV)
+            .ok()
+            .or_else(|| config.session.clone());
+
+        let base_url = base_url
+            .map(str::to_string)
+            .or_else(|| env::var(BASE_URL_ENV).ok())
+            .or_else(|| config.base_url.clone())
+            .unwrap_or_else(|| String::from(DEFAULT_BASE_URL));
+
+        Ok(Self::new(&base_url, session.as_deref(), root))
+    }
+
+    pub fn input_path(&self, day: u8) -> PathBuf {
+        self.root.join(format!("day-{day:02}")).join("input")
+    }
+
+    pub fn example_path(&self, day: u8, index: usize) -> PathBuf {
+        self.root
+            .join(format!("day-{day:02}"))
+            .join("examples")
+            .join(index.to_string())
+    }
+
+    /// Downloads the input and the example blocks from the puzzle page for `day`, unless its input
+    /// is already on disk. The input is written last, so an interrupted fetch is retried in full.
+    pub fn fetch(&self, day: u8) -> Result<Fetched, String> {
+        let input_path = self.input_path(day);
+
+        if read_to_string(&input_path).is_ok_and(|input| !input.is_empty()) {
+            return Ok(Fetched::Cached);
+        }
+
+        let input = self.get(&format!("/{YEAR}/day/{day}/input"))?;
+        let page = self.get(&format!("/{YEAR}/day/{day}"))?;
+
+        let examples = extract_examples(&page);
+
+        for (index, example) in examples.iter().enumerate() {
+            write_file(&self.example_path(day, index + 1), example)?;
+        }
+
+        write_file(&input_path, &input)?;
+
+        Ok(Fetched::Downloaded {
+            examples: examples.len(),
+        })
+    }
+
+    pub(crate) fn get(&self, path: &str) -> Result<String, String> {
+        self.send("GET", path, None)
+    }
+
+    pub(crate) fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, String> {
+        self.send("POST", path, Some(form))
+    }
+
+    fn send(
+        &self,
+        method: &str,
+        path: &str,
+        form: Option<&[(&str, &str)]>,
+    ) -> Result<String, String> {
+        let session = self.session.as_deref().ok_or_else(|| {
+            format!("No session token: set {SESSION_ENV} or `session` in the config file")
+        })?;
+
+        self.throttle()?;
+
+        let url = format!("{}{path}", self.base_url);
+
+        let request = self
+            .agent
+            .request(method, &url)
+            .set("Cookie", &format!("session={session}"));
+
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+
+        let response = match response {
+            Ok(response) => response,
+            Err(ureq::Error::Status(429, response)) => {
+                return Err(format!(
+                    "Rate limited by {url}, retry after {} seconds",
+                    response.header("Retry-After").unwrap_or("some")
+                ));
+            }
+            Err(ureq::Error::Status(status, _)) => {
+                return Err(format!("Could not fetch {url}: HTTP {status}"));
+            }
+            // Transport errors already name the URL.
+            Err(e) => return Err(format!("Could not fetch {e}")),
+        };
+
+        let mut body = String::default();
+
+        response
+            .into_reader()
+            .read_to_string(&mut body)
+            .map_err(|e| format!("Could not read {url}: {e}"))?;
+
+        Ok(body)
+    }
+
+    /// Sleeps until `min_interval` has passed since the last request, then records this one.
+    fn throttle(&self) -> Result<(), String> {
+        let path = self.root.join(TIMESTAMP_FILE);
+
+        let last = read_to_string(&path)
+            .ok()
+            .and_then(|millis| millis.trim().parse::<u64>().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+
+        thread::sleep(wait_time(last, SystemTime::now(), self.min_interval));
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+
+        write_file(&path, &now.to_string())
+    }
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)
+            .map_err(|e| format!("Could not create {}: {e}", parent.display()))?;
+    }
+
+    write(path, contents).map_err(|e| format!("Could not write {}: {e}", path.display()))
+}
+
+fn wait_time(last: Option<SystemTime>, now: SystemTime, min_interval: Duration) -> Duration {
+    last.and_then(|last| now.duration_since(last).ok())
+        .map_or(Duration::ZERO, |elapsed| {
+            min_interval.saturating_sub(elapsed)
+        })
+}
+
+/// The contents of every `<pre><code>` block on a puzzle page, with markup removed and entities
+/// decoded.
+pub fn extract_examples(page: &str) -> Vec<String> {
+    page.split("<pre><code>")
+        .skip(1)
+        .filter_map(|block| block.split_once("</code></pre>"))
+        .map(|(code, _)| {
+            let mut text = String::default();
+            let mut in_tag = false;
+
+            for c in code.chars() {
+                match c {
+                    '<' => in_tag = true,
+                    '>' if in_tag => in_tag = false,
+                    _ if !in_tag => text.push(c),
+                    _ => (),
+                }
+            }
+
+            text.replace("&lt;", "<")
+                .replace("&gt;", ">")
+                .replace("&quot;", "\"")
+                .replace("&#39;", "'")
+                .replace("&amp;", "&")
+                .trim_end_matches('\n')
+                .to_string()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::{serve, temp_root};
+    use std::fs::remove_dir_all;
+
+    const PAGE: &str = "<article><p>For example:</p>
+<pre><code>two1nine
+<em>eight</em>wothree
+</code></pre>
+<p>Then:</p><pre><code>a &lt; b &amp;&amp; c &gt; d
+</code></pre></article>";
+
+    fn fetcher(address: &str, root: &Path) -> Fetcher {
+        let mut fetcher = Fetcher::new(address, Some("secret\n"), root);
+        fetcher.min_interval = Duration::ZERO;
+        fetcher
+    }
+
+    #[test]
+    fn test_fetch() {
+        let (address, requests) = serve(vec![
+            ("/2023/day/19/input", 200, "px{a<2006:qkq,m>2090:A,rfg}\n"),
+            ("/2023/day/19", 200, PAGE),
+        ]);
+
+        let root = temp_root("fetch");
+        let fetcher = fetcher(&address, &root);
+
+        assert_eq!(fetcher.fetch(19), Ok(Fetched::Downloaded { examples: 2 }));
+
+        assert_eq!(
+            read_to_string(fetcher.input_path(19)).unwrap(),
+            "px{a<2006:qkq,m>2090:A,rfg}\n"
+        );
+        assert_eq!(
+            read_to_string(fetcher.example_path(19, 1)).unwrap(),
+            "two1nine\neightwothree"
+        );
+        assert_eq!(
+            read_to_string(fetcher.example_path(19, 2)).unwrap(),
+            "a < b && c > d"
+        );
+
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec![
+                "GET /2023/day/19/input session=secret",
+                "GET /2023/day/19 session=secret"
+            ]
+        );
+
+        // A cached day is never fetched again.
+        assert_eq!(fetcher.fetch(19), Ok(Fetched::Cached));
+        assert_eq!(requests.lock().unwrap().len(), 2);
+
+        assert!(root.join(TIMESTAMP_FILE).exists());
+
+        remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_cached_without_session() {
+        let (address, requests) = serve(Vec::default());
+
+        let root = temp_root("fetch-cached");
+        let mut fetcher = Fetcher::new(&address, None, &root);
+        fetcher.min_interval = Duration::ZERO;
+
+        write_file(&fetcher.input_path(19), "px{a<2006:qkq,m>2090:A,rfg}\n").unwrap();
+
+        assert_eq!(fetcher.fetch(19), Ok(Fetched::Cached));
+
+        assert_eq!(
+            fetcher.fetch(20),
+            Err(format!(
+                "No session token: set {SESSION_ENV} or `session` in the config file"
+            ))
+        );
+
+        assert!(requests.lock().unwrap().is_empty());
+
+        remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let (address, requests) = serve(vec![("/2023/day/20/input", 429, "")]);
+
+        let root = temp_root("fetch-errors");
+        let fetcher = fetcher(&address, &root);
+
+        assert_eq!(
+            fetcher.fetch(20),
+            Err(format!(
+                "Rate limited by {address}/2023/day/20/input, retry after 60 seconds"
+            ))
+        );
+
+        assert_eq!(
+            fetcher.fetch(21),
+            Err(format!(
+                "Could not fetch {address}/2023/day/21/input: HTTP 404"
+            ))
+        );
+
+        assert!(!fetcher.input_path(20).exists());
+        assert!(!fetcher.input_path(21).exists());
+        assert_eq!(requests.lock().unwrap().len(), 2);
+
+        remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_wait_time() {
+        let now = UNIX_EPOCH + Duration::from_secs(100);
+        let interval = Duration::from_secs(5);
+
+        assert_eq!(wait_time(None, now, interval), Duration::ZERO);
+
+        assert_eq!(
+            wait_time(Some(now - Duration::from_secs(2)), now, interval),
+            Duration::from_secs(3)
+        );
+
+        assert_eq!(
+            wait_time(Some(now - Duration::from_secs(60)), now, interval),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn test_extract_examples() {
+        assert_eq!(
+            extract_examples(PAGE),
+            vec!["two1nine\neightwothree", "a < b && c > d"]
+        );
+
+        assert!(extract_examples("<p>No examples</p>").is_empty());
+    }
+
+    #[test]
+    fn test_config() {
+        assert_eq!(
+            Config::parse("session = \"abc\"\nbase_url = \"http://localhost:8080\""),
+            Ok(Config {
+                session: Some(String::from("abc")),
+                base_url: Some(String::from("http://localhost:8080")),
+            })
+        );
+
+        assert_eq!(Config::parse(""), Ok(Config::default()));
+        assert!(Config::parse("token = \"abc\"").is_err());
+    }
+}
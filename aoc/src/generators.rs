@@ -0,0 +1,756 @@
+//! Random but valid puzzle inputs of a configurable size, for stress testing the solvers.
+
+use std::collections::BTreeMap;
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+/// Builds an input for one day from a random number generator and a size. What the size counts
+/// depends on the day: lines for list-based inputs, the side of the map for grid-based ones.
+pub type Generator = fn(&mut StdRng, usize) -> String;
+
+const NUMBER_WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+const STAGES: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// Every day with a generator, keyed by day number.
+pub fn registry() -> BTreeMap<u8, Generator> {
+    BTreeMap::from([
+        (1, calibration_document as Generator),
+        (2, games as Generator),
+        (3, engine_schematic as Generator),
+        (4, scratchcards as Generator),
+        (5, almanac as Generator),
+        (6, races as Generator),
+        (7, camel_cards as Generator),
+        (8, network as Generator),
+        (9, histories as Generator),
+        (10, pipe_maze as Generator),
+        (11, galaxy_image as Generator),
+        (12, condition_records as Generator),
+        (13, mirror_patterns as Generator),
+        (14, platform as Generator),
+        (15, initialisation_sequence as Generator),
+        (16, contraption as Generator),
+        (17, heat_loss_map as Generator),
+        (18, dig_plan as Generator),
+    ])
+}
+
+/// Generates an input for the given day, or `None` if there is no generator for that day. The
+/// same day, size and seed always give the same input.
+pub fn generate(day: u8, size: usize, seed: u64) -> Option<String> {
+    registry()
+        .get(&day)
+        .map(|generator| generator(&mut StdRng::seed_from_u64(seed), size.max(1)))
+}
+
+fn grid(rng: &mut StdRng, size: usize, mut cell: impl FnMut(&mut StdRng) -> char) -> String {
+    (0..size)
+        .map(|_| (0..size).map(|_| cell(rng)).collect::<String>())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn join(numbers: impl IntoIterator<Item = impl ToString>, separator: &str) -> String {
+    numbers
+        .into_iter()
+        .map(|number| number.to_string())
+        .collect::<Vec<String>>()
+        .join(separator)
+}
+
+/// `size` lines of up to `size` letters, digits and spelled-out digits, each with at least one
+/// digit.
+fn calibration_document(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let mut line = String::default();
+
+            for _ in 0..rng.gen_range(1..=size) {
+                match rng.gen_range(0..4) {
+                    0 => line.push(char::from(b'1' + rng.gen_range(0..9))),
+                    1 => line.push_str(NUMBER_WORDS.choose(rng).unwrap()),
+                    _ => line.push(char::from(b'a' + rng.gen_range(0..26))),
+                }
+            }
+
+            let index = rng.gen_range(0..=line.len());
+            line.insert(index, char::from(b'1' + rng.gen_range(0..9)));
+
+            line
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// `size` games of up to six sets of cubes.
+fn games(rng: &mut StdRng, size: usize) -> String {
+    (1..=size)
+        .map(|id| {
+            let sets: Vec<String> = (0..rng.gen_range(1..=6))
+                .map(|_| {
+                    let mut colours = ["red", "green", "blue"];
+                    colours.shuffle(rng);
+
+                    let count = rng.gen_range(1..=3);
+
+                    join(
+                        colours[..count]
+                            .iter()
+                            .map(|colour| format!("{} {colour}", rng.gen_range(1..=20))),
+                        ", ",
+                    )
+                })
+                .collect();
+
+            format!("Game {id}: {}", sets.join("; "))
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// A `size` by `size` schematic of part numbers up to three digits long and symbols, with plenty
+/// of gears.
+fn engine_schematic(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let mut row = String::default();
+
+            while row.len() < size {
+                let roll = rng.gen_range(0..100);
+
+                if roll < 15 {
+                    let digits = rng.gen_range(1..=3).min(size - row.len());
+                    row.push_str(&rng.gen_range(1..10_u32.pow(digits as u32)).to_string());
+
+                    if row.len() < size {
+                        row.push('.');
+                    }
+                } else if roll < 20 {
+                    row.push('*');
+                } else if roll < 25 {
+                    row.push(*b"#+$/=%@&-".choose(rng).unwrap() as char);
+                } else {
+                    row.push('.');
+                }
+            }
+
+            row
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// `size` scratchcards with ten winning numbers and twenty-five numbers owned. Matches are kept
+/// rare enough that the number of copies grows linearly rather than exponentially.
+fn scratchcards(rng: &mut StdRng, size: usize) -> String {
+    let width = size.to_string().len();
+
+    (1..=size)
+        .map(|id| {
+            let mut numbers: Vec<u8> = (1..100).collect();
+            numbers.shuffle(rng);
+
+            let winning = &numbers[..10];
+
+            let matches = if rng.gen_bool(0.25) {
+                rng.gen_range(1..=5)
+            } else {
+                0
+            };
+
+            let mut owned: Vec<u8> = winning[..matches]
+                .iter()
+                .chain(&numbers[10..35 - matches])
+                .copied()
+                .collect();
+            owned.shuffle(rng);
+
+            format!(
+                "Card {id:>width$}: {} | {}",
+                join(winning.iter().map(|number| format!("{number:>2}")), " "),
+                join(owned.iter().map(|number| format!("{number:>2}")), " ")
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// An almanac with `size` seed ranges and `size` ranges in every map. Within a map the source
+/// ranges are disjoint, but the seed ranges overlap each other and straddle the map ranges.
+fn almanac(rng: &mut StdRng, size: usize) -> String {
+    let domain = size as u64 * 60;
+
+    let seeds: Vec<String> = (0..size)
+        .map(|_| format!("{} {}", rng.gen_range(0..domain), rng.gen_range(1..=100)))
+        .collect();
+
+    let maps: Vec<String> = STAGES
+        .iter()
+        .map(|stage| {
+            let mut source = 0;
+
+            let mut lines: Vec<String> = (0..size)
+                .map(|_| {
+                    source += rng.gen_range(0..=20);
+
+                    let length = rng.gen_range(1..=100);
+                    let line = format!("{} {source} {length}", rng.gen_range(0..domain));
+
+                    source += length;
+
+                    line
+                })
+                .collect();
+
+            lines.shuffle(rng);
+
+            format!("{stage} map:\n{}", lines.join("\n"))
+        })
+        .collect();
+
+    format!("seeds: {}\n\n{}", seeds.join(" "), maps.join("\n\n"))
+}
+
+/// Up to three races. Part two concatenates the times and allocates once per millisecond, so
+/// the size is capped to keep that joined race manageable.
+fn races(rng: &mut StdRng, size: usize) -> String {
+    let count = size.min(3);
+
+    let record = |time: u64| (time / 2) * (time - time / 2);
+
+    loop {
+        let times: Vec<u64> = (0..count).map(|_| rng.gen_range(10..100)).collect();
+
+        // Holding the button for a single millisecond never wins.
+        let distances: Vec<u64> = times
+            .iter()
+            .map(|time| rng.gen_range(*time..record(*time)))
+            .collect();
+
+        let time: u64 = join(&times, "").parse().unwrap();
+        let distance: u64 = join(&distances, "").parse().unwrap();
+
+        if (time..record(time)).contains(&distance) {
+            return format!(
+                "Time:     {}\nDistance: {}",
+                join(times.iter().map(|time| format!("{time:>4}")), " "),
+                join(
+                    distances.iter().map(|distance| format!("{distance:>4}")),
+                    " "
+                )
+            );
+        }
+    }
+}
+
+/// `size` hands, drawn from a few distinct labels each so every hand type turns up.
+fn camel_cards(rng: &mut StdRng, size: usize) -> String {
+    let labels = b"23456789TJQKA";
+
+    (0..size)
+        .map(|_| {
+            let distinct = rng.gen_range(1..=5);
+
+            let pool: Vec<u8> = labels.choose_multiple(rng, distinct).copied().collect();
+
+            let hand: String = (0..5).map(|_| *pool.choose(rng).unwrap() as char).collect();
+
+            format!("{hand} {}", rng.gen_range(1..=1000))
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// A network with `size` ghosts (at most twenty), starting with `AAA` to `ZZZ`. Each ghost walks
+/// a cycle through its `..Z` node whose length is a multiple of the instructions, which is what
+/// part two relies on; the other branch of every node leads somewhere random.
+fn network(rng: &mut StdRng, size: usize) -> String {
+    const PRIMES: [usize; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    let instructions: Vec<char> = (0..rng.gen_range(3..=20))
+        .map(|_| if rng.gen_bool(0.5) { 'L' } else { 'R' })
+        .collect();
+
+    let name = |n: usize| -> String {
+        [n / 676, n / 26 % 26, n % 26]
+            .iter()
+            .map(|letter| char::from(b'A' + *letter as u8))
+            .collect()
+    };
+
+    let mut middle: Vec<String> = (0..26 * 26 * 26)
+        .map(name)
+        .filter(|name| !name.ends_with(['A', 'Z']))
+        .collect();
+    middle.shuffle(rng);
+
+    // `AA` and `ZZ` are kept for the part one ghost.
+    let mut prefixes: Vec<String> = (1..26 * 26 - 1).map(|n| name(n)[1..].to_string()).collect();
+    prefixes.shuffle(rng);
+
+    let mut paths: Vec<Vec<String>> = Vec::default();
+
+    for ghost in 0..size.min(20) {
+        let prefix = if ghost == 0 {
+            String::from("AA")
+        } else {
+            prefixes.pop().unwrap()
+        };
+
+        let length = instructions.len() * PRIMES.choose(rng).unwrap();
+
+        let mut path = vec![format!("{prefix}A")];
+        path.extend(middle.drain(..length - 1));
+        path.push(format!(
+            "{}Z",
+            if ghost == 0 { "ZZ" } else { prefix.as_str() }
+        ));
+
+        paths.push(path);
+    }
+
+    let names: Vec<String> = paths.iter().flatten().cloned().collect();
+
+    let mut nodes: Vec<String> = Vec::default();
+
+    for path in &paths {
+        let length = path.len() - 1;
+
+        for (step, node) in path.iter().enumerate() {
+            // The ending node carries on to the same node the start led to.
+            let next = &path[if step == length { 1 } else { step + 1 }];
+            let other = names.choose(rng).unwrap();
+
+            let (left, right) = if instructions[step % instructions.len()] == 'L' {
+                (next, other)
+            } else {
+                (other, next)
+            };
+
+            nodes.push(format!("{node} = ({left}, {right})"));
+        }
+    }
+
+    nodes.shuffle(rng);
+
+    format!(
+        "{}\n\n{}",
+        instructions.iter().collect::<String>(),
+        nodes.join("\n")
+    )
+}
+
+/// `size` histories of five to twenty-five values, each a polynomial sequence so the differences
+/// always reach zero.
+fn histories(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let length = rng.gen_range(5..=25);
+
+            // The first value of each row of differences, down to a constant row.
+            let mut differences: Vec<i64> = (0..rng.gen_range(1..=6.min(length - 1)))
+                .map(|_| rng.gen_range(-10..=10))
+                .collect();
+
+            let values = (0..length).map(|_| {
+                let value = differences[0];
+
+                for index in 0..differences.len() - 1 {
+                    differences[index] += differences[index + 1];
+                }
+
+                value
+            });
+
+            join(values.collect::<Vec<i64>>(), " ")
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// A `size` by `size` maze (at least five) whose loop runs around a random skyline, with junk
+/// pipes everywhere else. The tiles next to `S` that are not on the loop are left empty so the
+/// start tile can only be read one way.
+fn pipe_maze(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(5);
+    let bottom = size - 2;
+
+    let tops: Vec<usize> = (0..size - 2).map(|_| rng.gen_range(1..bottom)).collect();
+
+    let mut path: Vec<(usize, usize)> = (tops[1]..=bottom).rev().map(|y| (1, y)).collect();
+
+    for x in 1..size - 3 {
+        path.push((x + 1, tops[x]));
+
+        if tops[x + 1] < tops[x] {
+            path.extend((tops[x + 1]..tops[x]).rev().map(|y| (x + 1, y)));
+        } else {
+            path.extend((tops[x] + 1..=tops[x + 1]).map(|y| (x + 1, y)));
+        }
+    }
+
+    path.extend((tops[size - 3]..=bottom).map(|y| (size - 2, y)));
+    path.extend((2..size - 2).rev().map(|x| (x, bottom)));
+
+    let mut tiles: Vec<Vec<char>> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| *b"|-LJ7F..".choose(rng).unwrap() as char)
+                .collect()
+        })
+        .collect();
+
+    for (index, (x, y)) in path.iter().enumerate() {
+        let previous = path[(index + path.len() - 1) % path.len()];
+        let next = path[(index + 1) % path.len()];
+
+        let connects = |(nx, ny): (usize, usize)| (nx.cmp(x), ny.cmp(y));
+
+        tiles[*y][*x] = match (connects(previous), connects(next)) {
+            (a, b) if a.0 == b.0 => '|',
+            (a, b) if a.1 == b.1 => '-',
+            (a, b) => {
+                let vertical = if a.0.is_eq() { a.1 } else { b.1 };
+                let horizontal = if a.1.is_eq() { a.0 } else { b.0 };
+
+                match (vertical.is_lt(), horizontal.is_gt()) {
+                    (true, true) => 'L',
+                    (true, false) => 'J',
+                    (false, false) => '7',
+                    (false, true) => 'F',
+                }
+            }
+        };
+    }
+
+    let start = rng.gen_range(0..path.len());
+    let (x, y) = path[start];
+
+    for (nx, ny) in [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)] {
+        if !path.contains(&(nx, ny)) {
+            tiles[ny][nx] = '.';
+        }
+    }
+
+    tiles[y][x] = 'S';
+
+    tiles
+        .iter()
+        .map(|row| row.iter().collect::<String>())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// A `size` by `size` image with a few empty rows and columns to expand.
+fn galaxy_image(rng: &mut StdRng, size: usize) -> String {
+    let empty_rows: Vec<bool> = (0..size).map(|_| rng.gen_bool(0.1)).collect();
+    let empty_columns: Vec<bool> = (0..size).map(|_| rng.gen_bool(0.1)).collect();
+
+    let mut image: Vec<Vec<char>> = (0..size)
+        .map(|y| {
+            (0..size)
+                .map(|x| {
+                    if !empty_rows[y] && !empty_columns[x] && rng.gen_bool(0.05) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    image[rng.gen_range(0..size)][rng.gen_range(0..size)] = '#';
+
+    image
+        .iter()
+        .map(|row| row.iter().collect::<String>())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// `size` records of up to twenty springs, with the groups taken from a random arrangement before
+/// some springs are hidden.
+fn condition_records(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let mut springs: Vec<char> = (0..rng.gen_range(1..=20))
+                .map(|_| if rng.gen_bool(0.5) { '#' } else { '.' })
+                .collect();
+
+            let index = rng.gen_range(0..springs.len());
+            springs[index] = '#';
+
+            let groups: Vec<usize> = springs
+                .split(|spring| *spring == '.')
+                .map(<[char]>::len)
+                .filter(|length| *length > 0)
+                .collect();
+
+            for spring in &mut springs {
+                if rng.gen_bool(0.4) {
+                    *spring = '?';
+                }
+            }
+
+            format!(
+                "{} {}",
+                springs.iter().collect::<String>(),
+                join(groups, ",")
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// `size` patterns, each with a perfect horizontal reflection and a vertical one that is only
+/// broken by a single smudge. The smudge sits in a row the horizontal reflection does not reach.
+fn mirror_patterns(rng: &mut StdRng, size: usize) -> String {
+    // Maps a row or column onto the one it mirrors on the near side of the line, if any.
+    let fold = |index: usize, line: usize| {
+        if index >= line && index < 2 * line {
+            2 * line - 1 - index
+        } else {
+            index
+        }
+    };
+
+    (0..size)
+        .map(|_| {
+            let width = rng.gen_range(5..=17);
+            let height = rng.gen_range(5..=17);
+
+            let row_line = rng.gen_range(1..=(height - 1) / 2);
+            let column_line = rng.gen_range(1..width);
+
+            let cells: Vec<Vec<bool>> = (0..height)
+                .map(|_| (0..width).map(|_| rng.gen_bool(0.5)).collect())
+                .collect();
+
+            let mut pattern: Vec<Vec<bool>> = (0..height)
+                .map(|y| {
+                    (0..width)
+                        .map(|x| cells[fold(y, row_line)][fold(x, column_line)])
+                        .collect()
+                })
+                .collect();
+
+            let y = rng.gen_range(2 * row_line..height);
+            let x = rng
+                .gen_range((2 * column_line).saturating_sub(width)..(2 * column_line).min(width));
+            pattern[y][x] = !pattern[y][x];
+
+            pattern
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|rock| if *rock { '#' } else { '.' })
+                        .collect::<String>()
+                })
+                .collect::<Vec<String>>()
+                .join("\n")
+        })
+        .collect::<Vec<String>>()
+        .join("\n\n")
+}
+
+/// A `size` by `size` platform of rounded and cube-shaped rocks.
+fn platform(rng: &mut StdRng, size: usize) -> String {
+    grid(rng, size, |rng| match rng.gen_range(0..100) {
+        0..=19 => 'O',
+        20..=34 => '#',
+        _ => '.',
+    })
+}
+
+/// `size` steps over a pool of labels small enough that lenses are often replaced and removed.
+fn initialisation_sequence(rng: &mut StdRng, size: usize) -> String {
+    let labels: Vec<String> = (0..size / 4 + 1)
+        .map(|_| {
+            (0..rng.gen_range(1..=6))
+                .map(|_| char::from(b'a' + rng.gen_range(0..26)))
+                .collect()
+        })
+        .collect();
+
+    join(
+        (0..size).map(|_| {
+            let label = labels.choose(rng).unwrap();
+
+            if rng.gen_bool(0.3) {
+                format!("{label}-")
+            } else {
+                format!("{label}={}", rng.gen_range(1..=9))
+            }
+        }),
+        ",",
+    )
+}
+
+/// A `size` by `size` contraption, mostly empty space with scattered mirrors and splitters.
+fn contraption(rng: &mut StdRng, size: usize) -> String {
+    grid(rng, size, |rng| match rng.gen_range(0..100) {
+        0..=4 => '/',
+        5..=9 => '\\',
+        10..=12 => '|',
+        13..=15 => '-',
+        _ => '.',
+    })
+}
+
+/// A `size` by `size` map (at least five, so part two can reach the end) of heat losses.
+fn heat_loss_map(rng: &mut StdRng, size: usize) -> String {
+    grid(rng, size.max(5), |rng| {
+        char::from_digit(rng.gen_range(1..=9), 10).unwrap()
+    })
+}
+
+/// A dig plan tracing the outline of a skyline of `size` columns, so it never crosses itself.
+/// The colours trace a second, much larger skyline with the same number of instructions for
+/// part two.
+fn dig_plan(rng: &mut StdRng, size: usize) -> String {
+    let part1 = skyline(rng, size, 10, 20);
+    let part2 = skyline(rng, size, 0xfffff / size, 500_000);
+
+    part1
+        .iter()
+        .zip(&part2)
+        .map(|((direction, count), (colour_direction, colour_count))| {
+            let digit = "RDLU".find(*colour_direction).unwrap();
+
+            format!("{direction} {count} (#{colour_count:05x}{digit})")
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// The outline of `columns` columns of random width and height, as `2 * columns + 2` moves.
+/// Neighbouring columns never share a height, so every column adds exactly two moves.
+fn skyline(
+    rng: &mut StdRng,
+    columns: usize,
+    max_width: usize,
+    max_height: usize,
+) -> Vec<(char, usize)> {
+    let mut moves: Vec<(char, usize)> = Vec::default();
+
+    let mut height = 0;
+    let mut total_width = 0;
+
+    for _ in 0..columns {
+        let next_height = loop {
+            let next_height = rng.gen_range(1..=max_height);
+
+            if next_height != height {
+                break next_height;
+            }
+        };
+
+        if next_height > height {
+            moves.push(('U', next_height - height));
+        } else {
+            moves.push(('D', height - next_height));
+        }
+
+        let width = rng.gen_range(1..=max_width.max(1));
+
+        moves.push(('R', width));
+
+        height = next_height;
+        total_width += width;
+    }
+
+    moves.push(('D', height));
+    moves.push(('L', total_width));
+
+    moves
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solvers::{self, Part, FIRST_DAY, LAST_DAY};
+
+    #[test]
+    fn test_registry() {
+        assert_eq!(
+            registry().keys().copied().collect::<Vec<u8>>(),
+            (FIRST_DAY..=LAST_DAY).collect::<Vec<u8>>()
+        );
+    }
+
+    #[test]
+    fn test_generate_is_deterministic() {
+        for day in FIRST_DAY..=LAST_DAY {
+            assert_eq!(generate(day, 20, 7), generate(day, 20, 7));
+        }
+
+        assert_ne!(generate(7, 20, 1), generate(7, 20, 2));
+        assert_eq!(generate(19, 20, 1), None);
+    }
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        for day in FIRST_DAY..=LAST_DAY {
+            for seed in 0..5 {
+                for size in [1, 12] {
+                    let input = generate(day, size, seed).unwrap();
+
+                    for part in Part::iterator() {
+                        let result = solvers::solve(day, part, &input).unwrap();
+
+                        assert!(
+                            result.is_ok(),
+                            "day {day} part {part} seed {seed} size {size}: {}\n{input}",
+                            result.unwrap_err()
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_pipe_maze_loop() {
+        for seed in 0..10 {
+            let input = generate(10, 30, seed).unwrap();
+
+            let answer: usize = solvers::solve(10, Part::One, &input)
+                .unwrap()
+                .unwrap()
+                .parse()
+                .unwrap();
+
+            assert!(answer >= 4, "seed {seed}: {answer}\n{input}");
+        }
+    }
+
+    #[test]
+    fn test_mirror_patterns() {
+        for seed in 0..10 {
+            let input = generate(13, 10, seed).unwrap();
+
+            // Every pattern has a reflection in both parts, each worth at least one.
+            for part in Part::iterator() {
+                let answer: usize = solvers::solve(13, part, &input)
+                    .unwrap()
+                    .unwrap()
+                    .parse()
+                    .unwrap();
+
+                assert!(answer >= 10, "seed {seed} part {part}: {answer}\n{input}");
+            }
+        }
+    }
+}
@@ -0,0 +1,457 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    fs::{read_to_string, OpenOptions},
+    io::{ErrorKind, Write},
+    path::Path,
+    process::Command,
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::timing::Spread;
+
+/// Where `aoc run` appends its timings, relative to the workspace root.
+pub const TIMINGS_FILE: &str = ".timings.jsonl";
+
+/// Appended to the commit of runs made with uncommitted changes.
+const DIRTY: &str = "-dirty";
+
+/// How long one part took in one run, written as a line of JSON.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Record {
+    /// When the run started, in milliseconds since the Unix epoch; shared by every part in it.
+    pub run: u64,
+    /// The commit checked out, with `-dirty` appended if tracked files had changed.
+    pub commit: String,
+    /// Threads solving parts at once: 1 unless the run was `--parallel`. Records written before
+    /// this was tracked are taken as serial.
+    #[serde(default = "serial")]
+    pub threads: usize,
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub parse_time_ns: u64,
+    pub solve_time_ns: u64,
+    pub total_time_ns: u64,
+}
+
+const fn serial() -> usize {
+    1
+}
+
+/// Loads every record at `path`; a missing file is an empty history.
+pub fn load(path: &Path) -> Result<Vec<Record>, String> {
+    let contents = match read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::default()),
+        Err(e) => {
+            return Err(format!(
+                "Could not load timing history {}: {e}",
+                path.display()
+            ))
+        }
+    };
+
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str(line).map_err(|e| {
+                format!(
+                    "Invalid timing history {}, line {}: {e}",
+                    path.display(),
+                    index + 1
+                )
+            })
+        })
+        .collect()
+}
+
+pub fn append(path: &Path, records: &[Record]) -> Result<(), String> {
+    let mut contents = String::new();
+
+    for record in records {
+        contents += &serde_json::to_string(record).map_err(|e| e.to_string())?;
+        contents.push('\n');
+    }
+
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(contents.as_bytes()))
+        .map_err(|e| format!("Could not write {}: {e}", path.display()))
+}
+
+fn git(root: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(root)
+        .output()
+        .ok()?;
+
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// The commit checked out in `root`, marked `-dirty` when tracked files have uncommitted changes,
+/// or `unknown` outside a git repository.
+pub fn current_commit(root: &Path) -> String {
+    let Some(commit) = git(root, &["rev-parse", "HEAD"]) else {
+        return String::from("unknown");
+    };
+
+    match git(root, &["status", "--porcelain", "--untracked-files=no"]) {
+        Some(status) if !status.is_empty() => format!("{commit}{DIRTY}"),
+        _ => commit,
+    }
+}
+
+/// Resolves a revision such as `HEAD~1` or a branch name to a full commit hash, if git knows it.
+pub fn resolve_commit(root: &Path, revision: &str) -> Option<String> {
+    git(
+        root,
+        &[
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("{revision}^{{commit}}"),
+        ],
+    )
+}
+
+/// One part's time in the latest run against its median time at the baseline commit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Change {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub baseline: Duration,
+    pub latest: Duration,
+}
+
+impl Change {
+    /// How much slower the latest run was, as a percentage of the baseline; negative if faster.
+    pub fn slowdown(&self) -> f64 {
+        if self.baseline.is_zero() {
+            0.0
+        } else {
+            100.0 * (self.latest.as_secs_f64() / self.baseline.as_secs_f64() - 1.0)
+        }
+    }
+
+    pub fn regressed(&self, threshold: f64) -> bool {
+        self.slowdown() > threshold
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Comparison {
+    pub baseline: String,
+    pub latest: String,
+    pub changes: Vec<Change>,
+}
+
+/// The commit recorded as exactly `baseline`, or else the one clean commit it is a prefix of.
+fn find_commit<'a>(
+    records: impl Iterator<Item = &'a Record> + Clone,
+    baseline: &str,
+) -> Result<String, String> {
+    if records.clone().any(|record| record.commit == baseline) {
+        return Ok(baseline.to_string());
+    }
+
+    let matches: BTreeSet<&str> = records
+        .map(|record| record.commit.as_str())
+        .filter(|commit| commit.starts_with(baseline) && !commit.ends_with(DIRTY))
+        .collect();
+
+    if matches.len() > 1 {
+        return Err(format!(
+            "Commit {baseline} is ambiguous, it could be any of {}",
+            matches.into_iter().collect::<Vec<_>>().join(", ")
+        ));
+    }
+
+    matches
+        .first()
+        .map(|commit| commit.to_string())
+        .ok_or_else(|| format!("No timings recorded for commit {baseline}"))
+}
+
+/// Compares the latest run against the runs recorded at the `baseline` commit, matched exactly or
+/// by an unambiguous prefix, or by default at the most recent commit other than the latest run's.
+/// Only runs with as many threads as the latest one count, as parallel parts compete for the CPU.
+/// Baseline times are the median over all those runs, to smooth out noise; parts missing from
+/// either side are skipped.
+pub fn compare(records: &[Record], baseline: Option<&str>) -> Result<Comparison, String> {
+    let latest_run = records
+        .iter()
+        .map(|record| record.run)
+        .max()
+        .ok_or("No timings recorded yet")?;
+
+    let latest: Vec<&Record> = records
+        .iter()
+        .filter(|record| record.run == latest_run)
+        .collect();
+
+    let latest_commit = latest[0].commit.clone();
+    let threads = latest[0].threads;
+
+    let earlier = records
+        .iter()
+        .filter(|record| record.run != latest_run && record.threads == threads);
+
+    let baseline_commit = match baseline {
+        Some(baseline) => find_commit(earlier.clone(), baseline)?,
+        None => earlier
+            .clone()
+            .filter(|record| record.commit != latest_commit)
+            .max_by_key(|record| record.run)
+            .map(|record| record.commit.clone())
+            .ok_or("No earlier run at another commit to compare with")?,
+    };
+
+    let mut baseline_times: HashMap<(u8, u8, &str), Vec<Duration>> = HashMap::default();
+
+    for record in earlier.filter(|record| record.commit == baseline_commit) {
+        baseline_times
+            .entry((record.day, record.part, &record.input))
+            .or_default()
+            .push(Duration::from_nanos(record.total_time_ns));
+    }
+
+    let mut changes: Vec<Change> = latest
+        .iter()
+        .filter_map(|record| {
+            let times = baseline_times.get(&(record.day, record.part, record.input.as_str()))?;
+
+            Some(Change {
+                day: record.day,
+                part: record.part,
+                input: record.input.clone(),
+                baseline: Spread::of(times)?.median,
+                latest: Duration::from_nanos(record.total_time_ns),
+            })
+        })
+        .collect();
+
+    changes.sort_by(|a, b| (a.day, a.part, &a.input).cmp(&(b.day, b.part, &b.input)));
+
+    Ok(Comparison {
+        baseline: baseline_commit,
+        latest: latest_commit,
+        changes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::remove_dir_all;
+
+    use super::*;
+    use crate::mock_server::temp_root;
+
+    fn record(run: u64, commit: &str, day: u8, part: u8, total_ms: u64) -> Record {
+        Record {
+            run,
+            commit: commit.to_string(),
+            threads: 1,
+            day,
+            part,
+            input: format!("day-{day:02}/input"),
+            parse_time_ns: 0,
+            solve_time_ns: total_ms * 1_000_000,
+            total_time_ns: total_ms * 1_000_000,
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let root = temp_root("timings");
+        let path = root.join(TIMINGS_FILE);
+
+        assert_eq!(load(&path), Ok(Vec::default()));
+
+        let first = vec![record(1, "abc", 1, 1, 5), record(1, "abc", 1, 2, 7)];
+        let second = vec![record(2, "def", 1, 1, 6)];
+
+        append(&path, &first).unwrap();
+        append(&path, &second).unwrap();
+
+        assert_eq!(load(&path), Ok([first, second].concat()));
+
+        std::fs::write(&path, "{\"run\": 1}\n").unwrap();
+
+        assert!(load(&path).unwrap_err().contains("line 1"));
+
+        remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_compare_against_previous_commit() {
+        let records = vec![
+            record(1, "abc", 5, 2, 100),
+            record(2, "abc", 5, 2, 120),
+            record(3, "abc", 5, 2, 110),
+            record(3, "abc", 12, 1, 40),
+            record(4, "def", 5, 2, 200),
+            record(4, "def", 12, 1, 38),
+            record(4, "def", 16, 2, 10),
+        ];
+
+        let comparison = compare(&records, None).unwrap();
+
+        assert_eq!(comparison.baseline, "abc");
+        assert_eq!(comparison.latest, "def");
+
+        assert_eq!(
+            comparison.changes,
+            vec![
+                Change {
+                    day: 5,
+                    part: 2,
+                    input: String::from("day-05/input"),
+                    baseline: Duration::from_millis(110),
+                    latest: Duration::from_millis(200),
+                },
+                Change {
+                    day: 12,
+                    part: 1,
+                    input: String::from("day-12/input"),
+                    baseline: Duration::from_millis(40),
+                    latest: Duration::from_millis(38),
+                },
+            ]
+        );
+
+        assert!(comparison.changes[0].regressed(10.0));
+        assert!(!comparison.changes[0].regressed(90.0));
+        assert!(!comparison.changes[1].regressed(0.0));
+        assert!((comparison.changes[1].slowdown() + 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_compare_same_threads() {
+        let parallel = |record: Record| Record {
+            threads: 4,
+            ..record
+        };
+
+        let records = vec![
+            record(1, "abc", 1, 1, 10),
+            parallel(record(2, "def", 1, 1, 40)),
+            record(3, "def", 1, 1, 12),
+        ];
+
+        // The parallel run at `def` is neither the baseline nor part of it.
+        let comparison = compare(&records, None).unwrap();
+
+        assert_eq!(comparison.baseline, "abc");
+        assert_eq!(comparison.changes[0].baseline, Duration::from_millis(10));
+
+        let records = [records, vec![parallel(record(4, "ghi", 1, 1, 50))]].concat();
+
+        let comparison = compare(&records, None).unwrap();
+
+        assert_eq!(comparison.baseline, "def");
+        assert_eq!(comparison.changes[0].baseline, Duration::from_millis(40));
+    }
+
+    #[test]
+    fn test_load_without_threads() {
+        let root = temp_root("timings-threads");
+        let path = root.join(TIMINGS_FILE);
+
+        std::fs::write(
+            &path,
+            "{\"run\":1,\"commit\":\"abc\",\"day\":1,\"part\":1,\"input\":\"day-01/input\",\
+             \"parse_time_ns\":0,\"solve_time_ns\":5,\"total_time_ns\":5}\n",
+        )
+        .unwrap();
+
+        assert_eq!(load(&path).map(|records| records[0].threads), Ok(1));
+
+        remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_compare_against_named_commit() {
+        let records = vec![
+            record(1, "abc123", 1, 1, 10),
+            record(2, "def456", 1, 1, 20),
+            record(3, "def456", 1, 1, 30),
+        ];
+
+        let comparison = compare(&records, Some("abc")).unwrap();
+
+        assert_eq!(comparison.baseline, "abc123");
+        assert_eq!(comparison.changes[0].baseline, Duration::from_millis(10));
+
+        // The latest run's own commit can be the baseline, using its earlier runs.
+        let comparison = compare(&records, Some("def")).unwrap();
+
+        assert_eq!(comparison.changes[0].baseline, Duration::from_millis(20));
+
+        assert_eq!(
+            compare(&records, Some("fff")),
+            Err(String::from("No timings recorded for commit fff"))
+        );
+    }
+
+    #[test]
+    fn test_compare_against_commit_prefix() {
+        let records = vec![
+            record(1, "abc123-dirty", 1, 1, 50),
+            record(2, "abc123", 1, 1, 10),
+            record(3, "abd456", 1, 1, 30),
+            record(4, "fed789", 1, 1, 20),
+        ];
+
+        // A prefix picks the clean commit over runs with uncommitted changes on top of it.
+        let comparison = compare(&records, Some("abc")).unwrap();
+
+        assert_eq!(comparison.baseline, "abc123");
+        assert_eq!(comparison.changes[0].baseline, Duration::from_millis(10));
+
+        let comparison = compare(&records, Some("abc123-dirty")).unwrap();
+
+        assert_eq!(comparison.changes[0].baseline, Duration::from_millis(50));
+
+        assert_eq!(
+            compare(&records, Some("ab")),
+            Err(String::from(
+                "Commit ab is ambiguous, it could be any of abc123, abd456"
+            ))
+        );
+
+        assert_eq!(
+            compare(&records[..1], Some("abc")),
+            Err(String::from("No timings recorded for commit abc"))
+        );
+    }
+
+    #[test]
+    fn test_compare_without_baseline() {
+        assert_eq!(
+            compare(&[], None),
+            Err(String::from("No timings recorded yet"))
+        );
+
+        assert_eq!(
+            compare(
+                &[record(1, "abc", 1, 1, 10), record(2, "abc", 1, 1, 10)],
+                None
+            ),
+            Err(String::from(
+                "No earlier run at another commit to compare with"
+            ))
+        );
+    }
+}
@@ -0,0 +1,12 @@
+pub mod export;
+pub mod fetch;
+pub mod generators;
+pub mod history;
+pub mod manifest;
+#[cfg(test)]
+mod mock_server;
+pub mod scaffold;
+pub mod solvers;
+pub mod submit;
+pub mod timing;
+pub mod visualise;
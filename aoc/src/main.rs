@@ -0,0 +1,921 @@
+mod report;
+
+use std::{
+    fs::{read_to_string, write, File},
+    io::BufWriter,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use clap::{Parser, Subcommand, ValueEnum};
+use grid::render::{self, Frame, Playback};
+use rayon::prelude::*;
+
+use aoc::{
+    export,
+    fetch::{self, Fetched},
+    generators,
+    history::{self, TIMINGS_FILE},
+    manifest::{self, MANIFEST_FILE},
+    scaffold,
+    solvers::{self, Part, FIRST_DAY, LAST_DAY},
+    submit::{self, HISTORY_FILE},
+    timing::Spread,
+    visualise,
+};
+use report::Row;
+
+const WORKSPACE_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2023 solutions runner")]
+struct Cli {
+    /// Tracing filter written to standard error, e.g. `debug` or `day_17=trace`; overrides `AOC_LOG`
+    #[arg(long, global = true)]
+    log: Option<String>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+    /// Rows sorted slowest first with parse and solve times, phase totals and the wall clock time
+    Timing,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum PaletteName {
+    Dark,
+    Light,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ImageFormatName {
+    Png,
+    Ppm,
+}
+
+struct ExportSettings {
+    cell_size: usize,
+    palette: PaletteName,
+    colours: Vec<String>,
+    stride: usize,
+    delay: Duration,
+    image_format: ImageFormatName,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one or more days and print a summary of answers and timings
+    Run {
+        /// Days to run: `all`, a single day (`7`) or a list of days and ranges (`1,3,5-7`)
+        days: String,
+
+        /// Only run this part (1 or 2)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Input file to use instead of `day-XX/input`; only valid when running a single day
+        input: Option<PathBuf>,
+
+        /// Output format: a summary table, one JSON object per line with timings and statistics, or
+        /// a timing report
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+
+        /// Run every day and part concurrently on a thread pool
+        #[arg(long)]
+        parallel: bool,
+
+        /// Number of threads for `--parallel`; defaults to one per CPU
+        #[arg(long, requires = "parallel", value_parser = clap::value_parser!(u16).range(1..))]
+        jobs: Option<u16>,
+
+        /// Run each part this many times, reporting the fastest, median and slowest
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+        repeat: u16,
+
+        /// Do not append the timings to `.timings.jsonl`
+        #[arg(long)]
+        no_history: bool,
+    },
+    /// Compare the latest run's timings against a baseline and flag parts that got slower
+    Compare {
+        /// Commit or revision to compare against; defaults to the last commit run before the latest
+        baseline: Option<String>,
+
+        /// Percentage slowdown beyond which a part is flagged
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+
+        /// Timing history to use instead of `.timings.jsonl` at the workspace root
+        #[arg(long)]
+        history: Option<PathBuf>,
+    },
+    /// Check answers against the expected answers manifest and report any mismatches
+    Verify {
+        /// Days to verify, in the same format as `run`
+        #[arg(default_value = "all")]
+        days: String,
+
+        /// Manifest to use instead of `answers.toml` at the workspace root
+        #[arg(long)]
+        manifest: Option<PathBuf>,
+
+        /// Skip answers marked as slow in the manifest
+        #[arg(long)]
+        fast: bool,
+    },
+    /// Generate a random input for a day, for stress testing its solver
+    Generate {
+        /// Day to generate an input for
+        #[arg(value_parser = clap::value_parser!(u8).range(FIRST_DAY as i64..=LAST_DAY as i64))]
+        day: u8,
+
+        /// How big an input to generate: lines, or the side of the map for grid-based days
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+
+        /// Seed for the random number generator; a random seed is picked and reported if omitted
+        #[arg(long)]
+        seed: Option<u64>,
+
+        /// Write the input to this file instead of standard output
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Download a day's input and examples into `day-NN`, unless its input is already there
+    Fetch {
+        /// Day to fetch
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Server to fetch from instead of adventofcode.com; overrides `AOC_BASE_URL` and the config
+        #[arg(long)]
+        base_url: Option<String>,
+
+        /// Config file holding `session` and `base_url`, instead of `~/.config/aoc/config.toml`
+        #[arg(long)]
+        config: Option<PathBuf>,
+    },
+    /// Solve a day's part and submit the answer, unless earlier submissions rule it out
+    Submit {
+        /// Day to submit
+        #[arg(value_parser = clap::value_parser!(u8).range(FIRST_DAY as i64..=LAST_DAY as i64))]
+        day: u8,
+
+        /// Part to submit (1 or 2)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Input file to solve instead of `day-XX/input`
+        input: Option<PathBuf>,
+
+        /// Submit this answer instead of solving
+        #[arg(long)]
+        answer: Option<String>,
+
+        /// Submit even if the answer was already rejected or is outside the known bounds
+        #[arg(long)]
+        force: bool,
+
+        /// Server to submit to instead of adventofcode.com; overrides `AOC_BASE_URL` and the config
+        #[arg(long)]
+        base_url: Option<String>,
+
+        /// Config file holding `session` and `base_url`, instead of `~/.config/aoc/config.toml`
+        #[arg(long)]
+        config: Option<PathBuf>,
+    },
+    /// Draw a grid puzzle being solved, frame by frame, with ANSI colours
+    Visualise {
+        /// Day to draw: 10, 14, 16 or 17
+        #[arg(value_parser = clap::value_parser!(u8).range(FIRST_DAY as i64..=LAST_DAY as i64))]
+        day: u8,
+
+        /// Part to draw (1 or 2); defaults to part one
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Input file to draw instead of `day-XX/input`
+        input: Option<PathBuf>,
+
+        /// Draw the puzzle's worked example instead of an input file
+        #[arg(long, conflicts_with = "input")]
+        example: bool,
+
+        /// Show one frame at a time, waiting for Enter before the next
+        #[arg(long, conflicts_with = "delay")]
+        step: bool,
+
+        /// Show the frames in place, pausing this many milliseconds between them
+        #[arg(long)]
+        delay: Option<u64>,
+
+        /// Print plain text without colours, marking highlighted cells with characters instead
+        #[arg(long)]
+        plain: bool,
+    },
+    /// Save a visualisation as an animated GIF or as a PNG or PPM image per frame
+    Export {
+        /// Day to draw: 10, 14, 16 or 17
+        #[arg(value_parser = clap::value_parser!(u8).range(FIRST_DAY as i64..=LAST_DAY as i64))]
+        day: u8,
+
+        /// Part to draw (1 or 2); defaults to part one
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Input file to draw instead of `day-XX/input`
+        input: Option<PathBuf>,
+
+        /// Draw the puzzle's worked example instead of an input file
+        #[arg(long, conflicts_with = "input")]
+        example: bool,
+
+        /// A `.gif` file for an animation, or a directory to write one image per frame into
+        #[arg(long, short)]
+        output: PathBuf,
+
+        /// Format of the images written into a directory
+        #[arg(long, value_enum, default_value_t = ImageFormatName::Png)]
+        image_format: ImageFormatName,
+
+        /// Side of each cell, in pixels
+        #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u8).range(1..))]
+        cell_size: u8,
+
+        /// Colour scheme to start from
+        #[arg(long, value_enum, default_value_t = PaletteName::Dark)]
+        palette: PaletteName,
+
+        /// Override a colour, e.g. `yellow=#ffd700`: `empty`, `glyph` or an ANSI colour name
+        #[arg(long = "colour", value_name = "NAME=#RRGGBB")]
+        colours: Vec<String>,
+
+        /// Keep only every this many frames, along with the last
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+        stride: u32,
+
+        /// How long each GIF frame is shown, in milliseconds
+        #[arg(long, default_value_t = 100)]
+        delay: u64,
+    },
+    /// Create a `day-NN` crate from the template and add it to the workspace
+    New {
+        /// Day to create
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+fn parse_days(days: &str) -> Result<Vec<u8>, String> {
+    if days == "all" {
+        return Ok((FIRST_DAY..=LAST_DAY).collect());
+    }
+
+    let mut parsed: Vec<u8> = Vec::default();
+
+    for token in days.split(',').map(str::trim) {
+        let (start, end) = token.split_once('-').unwrap_or((token, token));
+
+        let start = start
+            .parse::<u8>()
+            .map_err(|_| format!("Invalid day `{start}`"))?;
+        let end = end
+            .parse::<u8>()
+            .map_err(|_| format!("Invalid day `{end}`"))?;
+
+        if start > end {
+            return Err(format!("Invalid day range `{token}`"));
+        }
+
+        for day in start..=end {
+            if !(FIRST_DAY..=LAST_DAY).contains(&day) {
+                return Err(format!("No solution for day {day}"));
+            }
+
+            if !parsed.contains(&day) {
+                parsed.push(day);
+            }
+        }
+    }
+
+    parsed.sort_unstable();
+
+    Ok(parsed)
+}
+
+fn default_input_path(day: u8) -> PathBuf {
+    Path::new(WORKSPACE_ROOT)
+        .join(format!("day-{day:02}"))
+        .join("input")
+}
+
+/// Runs one part `repeat` times, stopping early if it fails. The row keeps the median time, as
+/// `Spread` reports it, and the answer and phase timings of the run nearest the middle.
+fn run_part(
+    day: u8,
+    part: Part,
+    path: &Path,
+    contents: &Result<String, String>,
+    repeat: u16,
+) -> Row {
+    let mut runs: Vec<(Duration, Result<solvers::Measurement, String>)> = Vec::default();
+
+    for _ in 0..repeat {
+        let start = Instant::now();
+
+        let result = contents
+            .as_ref()
+            .map_err(Clone::clone)
+            .and_then(|contents| {
+                solvers::measure(day, part, contents)
+                    .ok_or_else(|| format!("No solution for day {day} part {part}"))?
+                    .map_err(|e| format!("{}: {e}", path.display()))
+            });
+
+        let failed = result.is_err();
+
+        runs.push((start.elapsed(), result));
+
+        if failed {
+            break;
+        }
+    }
+
+    let samples: Vec<Duration> = runs.iter().map(|(duration, _)| *duration).collect();
+    let duration = Spread::of(&samples).map_or(Duration::ZERO, |spread| spread.median);
+
+    runs.sort_by_key(|(duration, _)| *duration);
+    let (_, result) = runs.swap_remove(runs.len() / 2);
+
+    Row {
+        day,
+        part,
+        input: path.to_path_buf(),
+        result,
+        duration,
+        samples,
+    }
+}
+
+/// Runs every part of every day, on the current rayon thread pool if `parallel` is set. Rows come
+/// back in day and part order either way.
+fn run(days: &[u8], parts: &[Part], input: Option<&Path>, repeat: u16, parallel: bool) -> Vec<Row> {
+    let inputs: Vec<(u8, PathBuf, Result<String, String>)> = days
+        .iter()
+        .map(|day| {
+            let path = input.map_or_else(|| default_input_path(*day), Path::to_path_buf);
+
+            let contents = read_to_string(&path)
+                .map_err(|e| format!("Could not load input file {}: {e}", path.display()));
+
+            (*day, path, contents)
+        })
+        .collect();
+
+    let jobs: Vec<(u8, Part, &Path, &Result<String, String>)> = inputs
+        .iter()
+        .flat_map(|(day, path, contents)| {
+            parts
+                .iter()
+                .map(move |part| (*day, *part, path.as_path(), contents))
+        })
+        .collect();
+
+    let run_job = |(day, part, path, contents)| run_part(day, part, path, contents, repeat);
+
+    if parallel {
+        jobs.into_par_iter().map(run_job).collect()
+    } else {
+        jobs.into_iter().map(run_job).collect()
+    }
+}
+
+/// Appends the timings of every part that was solved, keyed by the commit checked out.
+fn record_history(rows: &[Row], started: SystemTime, threads: usize) -> Result<(), String> {
+    let root = Path::new(WORKSPACE_ROOT);
+    let commit = history::current_commit(root);
+
+    let run = started.duration_since(UNIX_EPOCH).map_or(0, |elapsed| {
+        u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX)
+    });
+
+    let records: Vec<history::Record> = rows
+        .iter()
+        .filter_map(|row| {
+            let measurement = row.result.as_ref().ok()?;
+
+            Some(history::Record {
+                run,
+                commit: commit.clone(),
+                threads,
+                day: row.day,
+                part: row.part.number(),
+                input: row
+                    .input
+                    .strip_prefix(root)
+                    .unwrap_or(&row.input)
+                    .display()
+                    .to_string(),
+                parse_time_ns: common::nanos(measurement.parse_time),
+                solve_time_ns: common::nanos(measurement.solve_time),
+                total_time_ns: common::nanos(row.duration),
+            })
+        })
+        .collect();
+
+    history::append(&root.join(TIMINGS_FILE), &records)
+}
+
+fn compare(baseline: Option<&str>, threshold: f64, path: &Path) -> Result<bool, String> {
+    let records = history::load(path)?;
+
+    let baseline = baseline.map(|baseline| {
+        history::resolve_commit(Path::new(WORKSPACE_ROOT), baseline)
+            .unwrap_or_else(|| baseline.to_string())
+    });
+
+    let comparison = history::compare(&records, baseline.as_deref())?;
+
+    report::print_comparison(&comparison, threshold);
+
+    Ok(comparison
+        .changes
+        .iter()
+        .all(|change| !change.regressed(threshold)))
+}
+
+/// Draws the frames for a day's part, from its worked example or an input file.
+fn load_frames(
+    day: u8,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    example: bool,
+) -> Result<Vec<Frame>, String> {
+    let part = part.and_then(Part::from_number).unwrap_or(Part::One);
+
+    let contents = if example {
+        solvers::registry()
+            .get(&day)
+            .map(|solver| solver.example(part).to_string())
+            .ok_or_else(|| format!("No solution for day {day}"))?
+    } else {
+        let path = input.unwrap_or_else(|| default_input_path(day));
+
+        read_to_string(&path)
+            .map_err(|e| format!("Could not load input file {}: {e}", path.display()))?
+    };
+
+    visualise::frames(day, part, &contents).unwrap_or_else(|| {
+        Err(format!(
+            "No visualisation for day {day}, only for days {:?}",
+            visualise::DAYS
+        ))
+    })
+}
+
+/// Renders `frames` and writes them to `output`: an animated GIF if it ends in `.gif`, or else a
+/// directory of images. Returns what was written, for reporting.
+fn export_frames(
+    frames: Vec<Frame>,
+    output: &Path,
+    settings: &ExportSettings,
+) -> Result<String, String> {
+    let mut palette = match settings.palette {
+        PaletteName::Dark => export::Palette::dark(),
+        PaletteName::Light => export::Palette::light(),
+    };
+
+    for setting in &settings.colours {
+        palette.set(setting)?;
+    }
+
+    let images: Vec<export::Image> = export::every(frames, settings.stride)
+        .iter()
+        .map(|frame| export::Image::render(frame, &palette, settings.cell_size))
+        .collect();
+
+    if output
+        .extension()
+        .is_some_and(|extension| extension == "gif")
+    {
+        let file = File::create(output)
+            .map_err(|e| format!("Could not write {}: {e}", output.display()))?;
+
+        export::write_gif(&images, settings.delay, BufWriter::new(file))?;
+
+        Ok(format!(
+            "Wrote {} frames to {}",
+            images.len(),
+            output.display()
+        ))
+    } else {
+        let format = match settings.image_format {
+            ImageFormatName::Png => export::ImageFormat::Png,
+            ImageFormatName::Ppm => export::ImageFormat::Ppm,
+        };
+
+        let paths = export::write_sequence(&images, output, format)?;
+
+        Ok(format!(
+            "Wrote {} images to {}",
+            paths.len(),
+            output.display()
+        ))
+    }
+}
+
+fn verify(days: &[u8], manifest: &Path, fast: bool) -> Result<bool, String> {
+    let entries = manifest::load(manifest)?;
+
+    let verifications: Vec<manifest::Verification> = entries
+        .iter()
+        .filter(|entry| days.contains(&entry.day) && !(fast && entry.slow))
+        .map(manifest::Entry::verify)
+        .collect();
+
+    report::print_verification(&verifications);
+
+    Ok(verifications
+        .iter()
+        .all(|verification| verification.outcome == manifest::Outcome::Pass))
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    if let Err(e) = common::init_tracing(cli.log.as_deref()) {
+        eprintln!("{e}");
+        return ExitCode::FAILURE;
+    }
+
+    match cli.command {
+        Command::Run {
+            days,
+            part,
+            input,
+            format,
+            parallel,
+            jobs,
+            repeat,
+            no_history,
+        } => {
+            let days = match parse_days(&days) {
+                Ok(days) => days,
+                Err(e) => {
+                    eprintln!("{e}");
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            if input.is_some() && days.len() != 1 {
+                eprintln!("An input file can only be provided when running a single day");
+                return ExitCode::FAILURE;
+            }
+
+            let parts: Vec<Part> = part
+                .and_then(Part::from_number)
+                .map_or_else(|| Part::iterator().collect(), |part| vec![part]);
+
+            let pool = match rayon::ThreadPoolBuilder::new()
+                .num_threads(jobs.map_or(0, usize::from))
+                .build()
+            {
+                Ok(pool) => pool,
+                Err(e) => {
+                    eprintln!("Could not start the thread pool: {e}");
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let started = SystemTime::now();
+            let start = Instant::now();
+            let rows = pool.install(|| run(&days, &parts, input.as_deref(), repeat, parallel));
+            let wall_clock = start.elapsed();
+
+            let threads = if parallel {
+                pool.current_num_threads()
+            } else {
+                1
+            };
+
+            match format {
+                Format::Text => report::print_summary(&rows),
+                Format::Json => report::print_json(&rows),
+                Format::Timing => report::print_timing(&rows, wall_clock, threads),
+            }
+
+            if !no_history {
+                if let Err(e) = record_history(&rows, started, threads) {
+                    eprintln!("{e}");
+                }
+            }
+
+            if rows.iter().any(|row| row.result.is_err()) {
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            }
+        }
+        Command::Compare {
+            baseline,
+            threshold,
+            history,
+        } => {
+            let history = history.unwrap_or_else(|| Path::new(WORKSPACE_ROOT).join(TIMINGS_FILE));
+
+            match compare(baseline.as_deref(), threshold, &history) {
+                Ok(true) => ExitCode::SUCCESS,
+                Ok(false) => ExitCode::FAILURE,
+                Err(e) => {
+                    eprintln!("{e}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Verify {
+            days,
+            manifest,
+            fast,
+        } => {
+            let days = match parse_days(&days) {
+                Ok(days) => days,
+                Err(e) => {
+                    eprintln!("{e}");
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let manifest =
+                manifest.unwrap_or_else(|| Path::new(WORKSPACE_ROOT).join(MANIFEST_FILE));
+
+            match verify(&days, &manifest, fast) {
+                Ok(true) => ExitCode::SUCCESS,
+                Ok(false) => ExitCode::FAILURE,
+                Err(e) => {
+                    eprintln!("{e}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Generate {
+            day,
+            size,
+            seed,
+            output,
+        } => {
+            let seed = seed.unwrap_or_else(|| {
+                let seed = rand::random();
+                eprintln!("Seed: {seed}");
+                seed
+            });
+
+            let Some(input) = generators::generate(day, size, seed) else {
+                eprintln!("No generator for day {day}");
+                return ExitCode::FAILURE;
+            };
+
+            match output {
+                Some(path) => match write(&path, input + "\n") {
+                    Ok(()) => ExitCode::SUCCESS,
+                    Err(e) => {
+                        eprintln!("Could not write {}: {e}", path.display());
+                        ExitCode::FAILURE
+                    }
+                },
+                None => {
+                    println!("{input}");
+                    ExitCode::SUCCESS
+                }
+            }
+        }
+        Command::Fetch {
+            day,
+            base_url,
+            config,
+        } => {
+            let root = Path::new(WORKSPACE_ROOT);
+
+            let fetcher = fetch::Config::load(config.as_deref())
+                .and_then(|config| fetch::Fetcher::from_config(&config, base_url.as_deref(), root));
+
+            match fetcher.and_then(|fetcher| fetcher.fetch(day)) {
+                Ok(Fetched::Cached) => {
+                    println!("Day {day} is already fetched");
+                    ExitCode::SUCCESS
+                }
+                Ok(Fetched::Downloaded { examples }) => {
+                    let plural = if examples == 1 { "" } else { "s" };
+
+                    println!("Fetched the input and {examples} example{plural} for day {day}");
+                    ExitCode::SUCCESS
+                }
+                Err(e) => {
+                    eprintln!("{e}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Submit {
+            day,
+            part,
+            input,
+            answer,
+            force,
+            base_url,
+            config,
+        } => {
+            let root = Path::new(WORKSPACE_ROOT);
+            let part = Part::from_number(part).unwrap_or(Part::One);
+
+            let answer = answer.map_or_else(
+                || {
+                    let path = input.unwrap_or_else(|| default_input_path(day));
+
+                    let contents = read_to_string(&path).map_err(|e| {
+                        format!("Could not load input file {}: {e}", path.display())
+                    })?;
+
+                    solvers::solve(day, part, &contents)
+                        .ok_or_else(|| format!("No solution for day {day} part {part}"))?
+                        .map_err(|e| format!("{}: {e}", path.display()))
+                },
+                Ok,
+            );
+
+            let result = answer.and_then(|answer| {
+                let fetcher = fetch::Config::load(config.as_deref()).and_then(|config| {
+                    fetch::Fetcher::from_config(&config, base_url.as_deref(), root)
+                })?;
+
+                let response = submit::submit(
+                    &fetcher,
+                    &root.join(HISTORY_FILE),
+                    day,
+                    part,
+                    &answer,
+                    force,
+                )?;
+
+                Ok((answer, response))
+            });
+
+            match result {
+                Ok((answer, response)) => {
+                    println!("Day {day} part {part}: {answer} is {response}");
+
+                    if response == submit::Response::Correct {
+                        ExitCode::SUCCESS
+                    } else {
+                        ExitCode::FAILURE
+                    }
+                }
+                Err(e) => {
+                    eprintln!("{e}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Visualise {
+            day,
+            part,
+            input,
+            example,
+            step,
+            delay,
+            plain,
+        } => {
+            let frames = load_frames(day, part, input, example);
+
+            let playback = match (step, delay) {
+                (true, _) => Playback::Step,
+                (false, Some(delay)) => Playback::Animate(Duration::from_millis(delay)),
+                (false, None) => Playback::Dump,
+            };
+
+            let result = frames.and_then(|frames| {
+                render::play(
+                    &frames,
+                    playback,
+                    !plain,
+                    &mut std::io::stdout().lock(),
+                    &mut std::io::stdin().lock(),
+                )
+                .map_err(|e| e.to_string())
+            });
+
+            match result {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(e) => {
+                    eprintln!("{e}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Export {
+            day,
+            part,
+            input,
+            example,
+            output,
+            image_format,
+            cell_size,
+            palette,
+            colours,
+            stride,
+            delay,
+        } => {
+            let settings = ExportSettings {
+                cell_size: usize::from(cell_size),
+                palette,
+                colours,
+                stride: usize::try_from(stride).unwrap_or(usize::MAX),
+                delay: Duration::from_millis(delay),
+                image_format,
+            };
+
+            match load_frames(day, part, input, example)
+                .and_then(|frames| export_frames(frames, &output, &settings))
+            {
+                Ok(written) => {
+                    println!("{written}");
+                    ExitCode::SUCCESS
+                }
+                Err(e) => {
+                    eprintln!("{e}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::New { day } => {
+            let root = Path::new(WORKSPACE_ROOT);
+            let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+
+            match scaffold::new_day(&root, day) {
+                Ok(written) => {
+                    for path in written {
+                        println!("Wrote {}", path.display());
+                    }
+
+                    println!(
+                        "Add day-{day:02} to aoc/Cargo.toml and `aoc::solvers::registry` to run it with the others"
+                    );
+
+                    ExitCode::SUCCESS
+                }
+                Err(e) => {
+                    eprintln!("{e}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("7"), Ok(vec![7]));
+
+        assert_eq!(parse_days("1,3,5-7"), Ok(vec![1, 3, 5, 6, 7]));
+
+        assert_eq!(parse_days("3,1,3"), Ok(vec![1, 3]));
+
+        assert_eq!(
+            parse_days("all"),
+            Ok((FIRST_DAY..=LAST_DAY).collect::<Vec<u8>>())
+        );
+
+        assert!(parse_days("19").is_err());
+        assert!(parse_days("7-5").is_err());
+        assert!(parse_days("seven").is_err());
+    }
+
+    #[test]
+    fn test_run_part_median() {
+        let contents = Ok(String::from(day_01::EXAMPLE_PART1));
+
+        for repeat in [1, 2, 4] {
+            let row = run_part(1, Part::One, Path::new("example"), &contents, repeat);
+
+            assert_eq!(row.samples.len(), usize::from(repeat));
+            assert_eq!(
+                Some(row.duration),
+                Spread::of(&row.samples).map(|spread| spread.median)
+            );
+            assert_eq!(
+                row.result.map(|measurement| measurement.answer).ok(),
+                Some(String::from("142"))
+            );
+        }
+    }
+}
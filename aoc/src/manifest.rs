@@ -0,0 +1,227 @@
+use std::{
+    fmt,
+    fs::read_to_string,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use serde::Deserialize;
+
+use crate::solvers::{self, Part, FIRST_DAY, LAST_DAY};
+
+pub const MANIFEST_FILE: &str = "answers.toml";
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Manifest {
+    #[serde(default)]
+    answer: Vec<RawEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawEntry {
+    day: u8,
+    part: u8,
+    input: PathBuf,
+    expected: String,
+    #[serde(default)]
+    slow: bool,
+}
+
+/// A known-good answer for one day and part against a specific input file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    pub day: u8,
+    pub part: Part,
+    pub input: PathBuf,
+    pub expected: String,
+    pub slow: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Mismatch(String),
+    Error(String),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pass => write!(f, "ok"),
+            Self::Mismatch(actual) => write!(f, "MISMATCH, got {actual}"),
+            Self::Error(error) => write!(f, "ERROR, {error}"),
+        }
+    }
+}
+
+pub struct Verification {
+    pub entry: Entry,
+    pub outcome: Outcome,
+    pub duration: Duration,
+}
+
+impl Entry {
+    pub fn verify(&self) -> Verification {
+        let start = Instant::now();
+
+        let outcome = match read_to_string(&self.input) {
+            Ok(contents) => match solvers::solve(self.day, self.part, &contents) {
+                Some(Ok(actual)) if actual == self.expected => Outcome::Pass,
+                Some(Ok(actual)) => Outcome::Mismatch(actual),
+                Some(Err(e)) => Outcome::Error(format!("{}: {e}", self.input.display())),
+                None => Outcome::Error(format!("No solution for day {}", self.day)),
+            },
+            Err(e) => Outcome::Error(format!(
+                "Could not load input file {}: {e}",
+                self.input.display()
+            )),
+        };
+
+        Verification {
+            entry: self.clone(),
+            outcome,
+            duration: start.elapsed(),
+        }
+    }
+}
+
+/// Parses a manifest, resolving each entry's input path relative to `root`.
+pub fn parse(contents: &str, root: &Path) -> Result<Vec<Entry>, String> {
+    let manifest: Manifest = toml::from_str(contents).map_err(|e| e.to_string())?;
+
+    let mut entries: Vec<Entry> = Vec::default();
+
+    for raw in manifest.answer {
+        if !(FIRST_DAY..=LAST_DAY).contains(&raw.day) {
+            return Err(format!("No solution for day {}", raw.day));
+        }
+
+        let part = Part::from_number(raw.part)
+            .ok_or_else(|| format!("Invalid part {} for day {}", raw.part, raw.day))?;
+
+        let input = root.join(&raw.input);
+
+        if entries
+            .iter()
+            .any(|entry| entry.day == raw.day && entry.part == part && entry.input == input)
+        {
+            return Err(format!(
+                "Duplicate answer for day {} part {part} with input {}",
+                raw.day,
+                raw.input.display()
+            ));
+        }
+
+        entries.push(Entry {
+            day: raw.day,
+            part,
+            input,
+            expected: raw.expected,
+            slow: raw.slow,
+        });
+    }
+
+    Ok(entries)
+}
+
+pub fn load(path: &Path) -> Result<Vec<Entry>, String> {
+    let contents = read_to_string(path)
+        .map_err(|e| format!("Could not load manifest {}: {e}", path.display()))?;
+
+    let root = path.parent().unwrap_or_else(|| Path::new("."));
+
+    parse(&contents, root).map_err(|e| format!("Invalid manifest {}: {e}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let contents = r#"
+[[answer]]
+day = 1
+part = 2
+input = "day-01/input"
+expected = "281"
+
+[[answer]]
+day = 5
+part = 2
+input = "day-05/input"
+expected = "46"
+slow = true
+"#;
+
+        let entries = parse(contents, Path::new("/tmp")).unwrap();
+
+        assert_eq!(
+            entries[0],
+            Entry {
+                day: 1,
+                part: Part::Two,
+                input: PathBuf::from("/tmp/day-01/input"),
+                expected: String::from("281"),
+                slow: false,
+            }
+        );
+
+        assert!(entries[1].slow);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let entry = |day: u8, part: u8| {
+            format!("[[answer]]\nday = {day}\npart = {part}\ninput = \"input\"\nexpected = \"1\"\n")
+        };
+
+        assert_eq!(
+            parse(&entry(1, 3), Path::new(".")),
+            Err(String::from("Invalid part 3 for day 1"))
+        );
+
+        assert_eq!(
+            parse(&entry(26, 1), Path::new(".")),
+            Err(String::from("No solution for day 26"))
+        );
+
+        assert_eq!(
+            parse(&format!("{}{}", entry(1, 1), entry(1, 1)), Path::new(".")),
+            Err(String::from(
+                "Duplicate answer for day 1 part 1 with input input"
+            ))
+        );
+
+        assert!(parse("[[answer]]\nday = 1\n", Path::new(".")).is_err());
+    }
+
+    #[test]
+    fn test_verify() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+
+        let entry = |expected: &str| Entry {
+            day: 1,
+            part: Part::One,
+            input: root.join("day-01/input"),
+            expected: String::from(expected),
+            slow: false,
+        };
+
+        assert_eq!(entry("54331").verify().outcome, Outcome::Pass);
+
+        assert_eq!(
+            entry("54330").verify().outcome,
+            Outcome::Mismatch(String::from("54331"))
+        );
+
+        let missing = Entry {
+            input: root.join("day-01/missing"),
+            ..entry("54331")
+        };
+
+        assert!(matches!(missing.verify().outcome, Outcome::Error(_)));
+    }
+}
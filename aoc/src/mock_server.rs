@@ -0,0 +1,90 @@
+//! A stand-in for the puzzle server, so fetching and submitting can be tested without a network.
+
+use std::{
+    env,
+    fs::{create_dir_all, remove_dir_all},
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+/// Serves `routes` of path, status and body on a local port until the tests finish, returning
+/// the base URL and a log of every request as `METHOD path cookie`, followed by the form body
+/// for a `POST`. Unknown paths get a 404.
+pub fn serve(routes: Vec<(&'static str, u16, &'static str)>) -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = format!("http://{}", listener.local_addr().unwrap());
+
+    let requests = Arc::new(Mutex::new(Vec::default()));
+    let seen = Arc::clone(&requests);
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request_line = String::default();
+            reader.read_line(&mut request_line).unwrap();
+
+            let mut cookie = String::default();
+            let mut length = 0;
+
+            loop {
+                let mut header = String::default();
+                reader.read_line(&mut header).unwrap();
+
+                if header.trim().is_empty() {
+                    break;
+                }
+
+                let (name, value) = header.split_once(':').unwrap_or_default();
+
+                match name.to_ascii_lowercase().as_str() {
+                    "cookie" => cookie = value.trim().to_string(),
+                    "content-length" => length = value.trim().parse().unwrap_or_default(),
+                    _ => (),
+                }
+            }
+
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+
+            let mut parts = request_line.split_whitespace();
+            let method = parts.next().unwrap_or_default();
+            let path = parts.next().unwrap_or_default();
+
+            seen.lock().unwrap().push(
+                format!(
+                    "{method} {path} {cookie} {}",
+                    String::from_utf8_lossy(&body)
+                )
+                .trim_end()
+                .to_string(),
+            );
+
+            let (status, body) = routes
+                .iter()
+                .find(|(route, _, _)| *route == path)
+                .map_or((404, ""), |(_, status, body)| (*status, *body));
+
+            write!(
+                stream,
+                "HTTP/1.1 {status} Status\r\nContent-Length: {}\r\nRetry-After: 60\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        }
+    });
+
+    (address, requests)
+}
+
+/// An empty directory to use as the workspace root in a test.
+pub fn temp_root(name: &str) -> PathBuf {
+    let root = env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+    let _ = remove_dir_all(&root);
+    create_dir_all(&root).unwrap();
+    root
+}
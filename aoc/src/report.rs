@@ -0,0 +1,332 @@
+use std::{path::PathBuf, time::Duration};
+
+use aoc::{
+    history::Comparison,
+    manifest::{Outcome, Verification},
+    solvers::{Measurement, Part},
+    timing::Spread,
+};
+
+pub struct Row {
+    pub day: u8,
+    pub part: Part,
+    pub input: PathBuf,
+    pub result: Result<Measurement, String>,
+    pub duration: Duration,
+    /// How long each run took; `duration` is the median run's.
+    pub samples: Vec<Duration>,
+}
+
+impl Row {
+    fn spread(&self) -> Option<Spread> {
+        Spread::of(&self.samples).filter(|_| self.samples.len() > 1)
+    }
+
+    /// Time spent across every run of this row.
+    fn busy(&self) -> Duration {
+        self.samples.iter().sum()
+    }
+
+    fn answer(&self) -> String {
+        match &self.result {
+            Ok(measurement) => measurement.answer.clone(),
+            Err(error) => format!("error: {error}"),
+        }
+    }
+}
+
+fn spread_headings() -> String {
+    format!("  {:>12}  {:>12}  {:>12}", "Min", "Median", "Max")
+}
+
+fn spread_columns(spread: Option<&Spread>) -> String {
+    spread.map_or_else(
+        || format!("  {:>12}  {:>12}  {:>12}", "", "", ""),
+        |spread| {
+            format!(
+                "  {:>12}  {:>12}  {:>12}",
+                format!("{:.2?}", spread.min),
+                format!("{:.2?}", spread.median),
+                format!("{:.2?}", spread.max)
+            )
+        },
+    )
+}
+
+pub fn print_summary(rows: &[Row]) {
+    let answers: Vec<String> = rows.iter().map(Row::answer).collect();
+
+    let answer_width = answers
+        .iter()
+        .map(String::len)
+        .chain(std::iter::once("Answer".len()))
+        .max()
+        .unwrap_or_default();
+
+    let repeated = rows.iter().any(|row| row.spread().is_some());
+    let spread_width = if repeated { 3 * (2 + 12) } else { 0 };
+
+    let separator = "-".repeat(3 + 2 + 4 + 2 + answer_width + 2 + 12 + spread_width);
+
+    println!(
+        "{:>3}  {:>4}  {:<answer_width$}  {:>12}{}",
+        "Day",
+        "Part",
+        "Answer",
+        "Time",
+        if repeated {
+            spread_headings()
+        } else {
+            String::new()
+        }
+    );
+    println!("{separator}");
+
+    for (row, answer) in rows.iter().zip(answers) {
+        println!(
+            "{:>3}  {:>4}  {:<answer_width$}  {:>12}{}",
+            row.day,
+            row.part,
+            answer,
+            format!("{:.2?}", row.duration),
+            if repeated {
+                spread_columns(row.spread().as_ref())
+            } else {
+                String::new()
+            }
+        );
+    }
+
+    let total: Duration = rows.iter().map(|row| row.duration).sum();
+
+    println!("{separator}");
+    println!(
+        "{:>3}  {:>4}  {:<answer_width$}  {:>12}",
+        "",
+        "",
+        "Total",
+        format!("{total:.2?}")
+    );
+}
+
+fn percentage(part: Duration, whole: Duration) -> f64 {
+    if whole.is_zero() {
+        0.0
+    } else {
+        100.0 * part.as_secs_f64() / whole.as_secs_f64()
+    }
+}
+
+/// Prints the rows slowest first, split into parse and solve time, followed by the totals of each
+/// phase and how the time spent in solvers compares with the wall clock time of the whole run.
+pub fn print_timing(rows: &[Row], wall_clock: Duration, threads: usize) {
+    let mut sorted: Vec<&Row> = rows.iter().collect();
+    sorted.sort_by_key(|row| std::cmp::Reverse(row.duration));
+
+    let repeated = rows.iter().any(|row| row.spread().is_some());
+    let spread_width = if repeated { 3 * (2 + 12) } else { 0 };
+
+    let separator = "-".repeat(3 + 2 + 4 + 3 * (2 + 12) + spread_width);
+
+    println!(
+        "{:>3}  {:>4}  {:>12}  {:>12}  {:>12}{}",
+        "Day",
+        "Part",
+        "Parse",
+        "Solve",
+        "Total",
+        if repeated {
+            spread_headings()
+        } else {
+            String::new()
+        }
+    );
+    println!("{separator}");
+
+    for row in &sorted {
+        let (parse, solve) = row.result.as_ref().map_or_else(
+            |_| ("error".to_string(), "error".to_string()),
+            |measurement| {
+                (
+                    format!("{:.2?}", measurement.parse_time),
+                    format!("{:.2?}", measurement.solve_time),
+                )
+            },
+        );
+
+        println!(
+            "{:>3}  {:>4}  {:>12}  {:>12}  {:>12}{}",
+            row.day,
+            row.part,
+            parse,
+            solve,
+            format!("{:.2?}", row.duration),
+            if repeated {
+                spread_columns(row.spread().as_ref())
+            } else {
+                String::new()
+            }
+        );
+    }
+
+    let measurements = rows.iter().filter_map(|row| row.result.as_ref().ok());
+    let parse: Duration = measurements.clone().map(|m| m.parse_time).sum();
+    let solve: Duration = measurements.map(|m| m.solve_time).sum();
+    let total: Duration = rows.iter().map(|row| row.duration).sum();
+
+    println!("{separator}");
+    println!(
+        "{:>3}  {:>4}  {:>12}  {:>12}  {:>12}",
+        "",
+        "",
+        format!("{parse:.2?}"),
+        format!("{solve:.2?}"),
+        format!("{total:.2?}")
+    );
+    println!();
+    println!(
+        "Parsing {:.1}%, solving {:.1}%, overhead {:.1}% across {} parts",
+        percentage(parse, total),
+        percentage(solve, total),
+        percentage(total.saturating_sub(parse + solve), total),
+        rows.len()
+    );
+
+    let busy: Duration = rows.iter().map(Row::busy).sum();
+    let plural = if threads == 1 { "" } else { "s" };
+
+    println!(
+        "Wall clock {wall_clock:.2?} on {threads} thread{plural} for {busy:.2?} of solver time ({:.2}x)",
+        if wall_clock.is_zero() {
+            0.0
+        } else {
+            busy.as_secs_f64() / wall_clock.as_secs_f64()
+        }
+    );
+}
+
+/// Prints one JSON object per row, so the output can be consumed line by line.
+pub fn print_json(rows: &[Row]) {
+    for row in rows {
+        let input = row.input.display().to_string();
+
+        match &row.result {
+            Ok(measurement) => {
+                let mut json = measurement.to_json_value(row.day, row.part, &input);
+
+                if let Some(spread) = row.spread() {
+                    json["min_time_ns"] = common::nanos(spread.min).into();
+                    json["median_time_ns"] = common::nanos(spread.median).into();
+                    json["max_time_ns"] = common::nanos(spread.max).into();
+                }
+
+                println!("{json}");
+            }
+            Err(error) => println!(
+                "{}",
+                serde_json::json!({
+                    "day": row.day,
+                    "part": row.part.number(),
+                    "input": input,
+                    "error": error,
+                })
+            ),
+        }
+    }
+}
+
+pub fn print_comparison(comparison: &Comparison, threshold: f64) {
+    println!(
+        "Comparing {} against {}",
+        comparison.latest, comparison.baseline
+    );
+    println!();
+
+    let separator = "-".repeat(3 + 2 + 4 + 2 + 12 + 2 + 12 + 2 + 8 + 2 + 6);
+
+    println!(
+        "{:>3}  {:>4}  {:>12}  {:>12}  {:>8}",
+        "Day", "Part", "Baseline", "Latest", "Change"
+    );
+    println!("{separator}");
+
+    for change in &comparison.changes {
+        println!(
+            "{:>3}  {:>4}  {:>12}  {:>12}  {:>8}{}",
+            change.day,
+            change.part,
+            format!("{:.2?}", change.baseline),
+            format!("{:.2?}", change.latest),
+            format!("{:+.1}%", change.slowdown()),
+            if change.regressed(threshold) {
+                "  slower"
+            } else {
+                ""
+            }
+        );
+    }
+
+    let regressions = comparison
+        .changes
+        .iter()
+        .filter(|change| change.regressed(threshold))
+        .count();
+
+    println!("{separator}");
+    println!(
+        "{} compared, {regressions} more than {threshold}% slower",
+        comparison.changes.len()
+    );
+}
+
+pub fn print_verification(verifications: &[Verification]) {
+    let expected_width = verifications
+        .iter()
+        .map(|verification| verification.entry.expected.len())
+        .chain(std::iter::once("Expected".len()))
+        .max()
+        .unwrap_or_default();
+
+    let outcomes: Vec<String> = verifications
+        .iter()
+        .map(|verification| verification.outcome.to_string())
+        .collect();
+
+    let outcome_width = outcomes
+        .iter()
+        .map(String::len)
+        .chain(std::iter::once("Result".len()))
+        .max()
+        .unwrap_or_default();
+
+    let separator = "-".repeat(3 + 2 + 4 + 2 + expected_width + 2 + outcome_width + 2 + 12);
+
+    println!(
+        "{:>3}  {:>4}  {:<expected_width$}  {:<outcome_width$}  {:>12}",
+        "Day", "Part", "Expected", "Result", "Time"
+    );
+    println!("{separator}");
+
+    for (verification, outcome) in verifications.iter().zip(outcomes) {
+        println!(
+            "{:>3}  {:>4}  {:<expected_width$}  {:<outcome_width$}  {:>12}",
+            verification.entry.day,
+            verification.entry.part,
+            verification.entry.expected,
+            outcome,
+            format!("{:.2?}", verification.duration)
+        );
+    }
+
+    let failures = verifications
+        .iter()
+        .filter(|verification| verification.outcome != Outcome::Pass)
+        .count();
+
+    println!("{separator}");
+    println!(
+        "{} checked, {} passed, {failures} failed",
+        verifications.len(),
+        verifications.len() - failures
+    );
+}
@@ -0,0 +1,197 @@
+use std::{
+    fs::{create_dir_all, read_to_string, write},
+    path::{Path, PathBuf},
+};
+
+pub const WORKSPACE_MANIFEST: &str = "Cargo.toml";
+
+/// The files making up a new day's crate, relative to the crate directory, with the template
+/// each one is rendered from.
+const TEMPLATES: [(&str, &str); 6] = [
+    (
+        "Cargo.toml",
+        include_str!("../templates/day/Cargo.toml.template"),
+    ),
+    ("src/lib.rs", include_str!("../templates/day/src/lib.rs")),
+    (
+        "src/part1.rs",
+        include_str!("../templates/day/src/part1.rs"),
+    ),
+    (
+        "src/part2.rs",
+        include_str!("../templates/day/src/part2.rs"),
+    ),
+    (
+        "src/bin/part1.rs",
+        include_str!("../templates/day/src/bin/part1.rs"),
+    ),
+    (
+        "src/bin/part2.rs",
+        include_str!("../templates/day/src/bin/part2.rs"),
+    ),
+];
+
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{crate}}", &format!("day-{day:02}"))
+        .replace("{{lib}}", &format!("day_{day:02}"))
+        .replace("{{type}}", &format!("Day{day:02}"))
+        .replace("{{day}}", &day.to_string())
+}
+
+/// Adds `member` to the `members` list of a workspace manifest, keeping the days in order.
+fn add_member(manifest: &str, member: &str) -> Result<String, String> {
+    let start = manifest
+        .find("members = [")
+        .ok_or("No `members` list in the workspace manifest")?;
+
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .ok_or("Unterminated `members` list in the workspace manifest")?;
+
+    let entry = format!("\"{member}\"");
+
+    if manifest[start..end].contains(&entry) {
+        return Err(format!("{member} is already a workspace member"));
+    }
+
+    // Insert before the first day that sorts after the new one, or else at the end of the list.
+    let mut offset = start;
+    let mut insert_at = manifest[..end].rfind('\n').map_or(end, |line| line + 1);
+
+    for line in manifest[start..end].split_inclusive('\n') {
+        let name = line.trim().trim_end_matches(',');
+
+        if name.starts_with("\"day-") && name > entry.as_str() {
+            insert_at = offset;
+            break;
+        }
+
+        offset += line.len();
+    }
+
+    let mut updated = manifest.to_string();
+    updated.insert_str(insert_at, &format!("    {entry},\n"));
+
+    Ok(updated)
+}
+
+/// Creates the `day-NN` crate for `day` under the workspace at `root`, with part one and two
+/// skeletons, binaries, an example test placeholder and an empty `input` file, and adds it to the
+/// workspace members. Returns the files written.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    let directory = root.join(format!("day-{day:02}"));
+
+    if directory.exists() {
+        return Err(format!("{} already exists", directory.display()));
+    }
+
+    let manifest_path = root.join(WORKSPACE_MANIFEST);
+
+    let manifest = read_to_string(&manifest_path)
+        .map_err(|e| format!("Could not read {}: {e}", manifest_path.display()))?;
+
+    let manifest = add_member(&manifest, &format!("day-{day:02}"))?;
+
+    let mut written: Vec<PathBuf> = Vec::default();
+
+    for (file, template) in TEMPLATES
+        .iter()
+        .map(|(file, template)| (*file, render(template, day)))
+        .chain([("input", String::default())])
+    {
+        let path = directory.join(file);
+
+        if let Some(parent) = path.parent() {
+            create_dir_all(parent)
+                .map_err(|e| format!("Could not create {}: {e}", parent.display()))?;
+        }
+
+        write(&path, template).map_err(|e| format!("Could not write {}: {e}", path.display()))?;
+
+        written.push(path);
+    }
+
+    write(&manifest_path, manifest)
+        .map_err(|e| format!("Could not write {}: {e}", manifest_path.display()))?;
+
+    written.push(manifest_path);
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, fs::remove_dir_all};
+
+    const MANIFEST: &str = r#"[workspace]
+members = [
+    "aoc",
+    "day-01",
+    "day-03",
+]
+"#;
+
+    #[test]
+    fn test_add_member() {
+        assert_eq!(
+            add_member(MANIFEST, "day-02"),
+            Ok(String::from(
+                r#"[workspace]
+members = [
+    "aoc",
+    "day-01",
+    "day-02",
+    "day-03",
+]
+"#
+            ))
+        );
+
+        assert_eq!(
+            add_member(MANIFEST, "day-19"),
+            Ok(MANIFEST.replace("    \"day-03\",\n", "    \"day-03\",\n    \"day-19\",\n"))
+        );
+
+        assert!(add_member(MANIFEST, "day-03").is_err());
+        assert!(add_member("[workspace]", "day-19").is_err());
+    }
+
+    #[test]
+    fn test_render() {
+        let rendered = render(include_str!("../templates/day/src/bin/part2.rs"), 19);
+
+        assert!(rendered.contains("common::runner::run::<day_19::Day19>(Part::Two)"));
+
+        let rendered = render(include_str!("../templates/day/src/lib.rs"), 19);
+
+        assert!(rendered.contains("impl Solution for Day19 {"));
+        assert!(rendered.contains("const DAY: u8 = 19;"));
+        assert!(!rendered.contains("{{"));
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        create_dir_all(&root).unwrap();
+        write(root.join(WORKSPACE_MANIFEST), MANIFEST).unwrap();
+
+        let written = new_day(&root, 19).unwrap();
+
+        assert_eq!(written.len(), TEMPLATES.len() + 2);
+        assert!(written.iter().all(|path| path.exists()));
+        assert_eq!(read_to_string(root.join("day-19/input")).unwrap(), "");
+        assert!(read_to_string(root.join("day-19/Cargo.toml"))
+            .unwrap()
+            .contains("name = \"day-19\""));
+        assert!(read_to_string(root.join(WORKSPACE_MANIFEST))
+            .unwrap()
+            .contains("    \"day-19\",\n]"));
+
+        assert!(new_day(&root, 19).unwrap_err().contains("already exists"));
+
+        remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,89 @@
+use std::{collections::BTreeMap, error::Error};
+
+pub use common::{Measurement, Part, Solver};
+
+pub const FIRST_DAY: u8 = 1;
+pub const LAST_DAY: u8 = 18;
+
+/// Every implemented day, keyed by day number.
+pub fn registry() -> BTreeMap<u8, &'static dyn Solver> {
+    BTreeMap::from([
+        (1, &day_01::Day01 as &dyn Solver),
+        (2, &day_02::Day02 as &dyn Solver),
+        (3, &day_03::Day03 as &dyn Solver),
+        (4, &day_04::Day04 as &dyn Solver),
+        (5, &day_05::Day05 as &dyn Solver),
+        (6, &day_06::Day06 as &dyn Solver),
+        (7, &day_07::Day07 as &dyn Solver),
+        (8, &day_08::Day08 as &dyn Solver),
+        (9, &day_09::Day09 as &dyn Solver),
+        (10, &day_10::Day10 as &dyn Solver),
+        (11, &day_11::Day11 as &dyn Solver),
+        (12, &day_12::Day12 as &dyn Solver),
+        (13, &day_13::Day13 as &dyn Solver),
+        (14, &day_14::Day14 as &dyn Solver),
+        (15, &day_15::Day15 as &dyn Solver),
+        (16, &day_16::Day16 as &dyn Solver),
+        (17, &day_17::Day17 as &dyn Solver),
+        (18, &day_18::Day18 as &dyn Solver),
+    ])
+}
+
+/// Runs the solver for the given day and part against `input`, returning the answer formatted
+/// for display or the reason the input could not be parsed, or `None` if there is no solver for
+/// that day.
+pub fn solve(day: u8, part: Part, input: &str) -> Option<Result<String, Box<dyn Error>>> {
+    registry().get(&day).map(|solver| solver.solve(part, input))
+}
+
+/// Like [`solve`], but also times parsing and solving separately and collects solver statistics.
+pub fn measure(day: u8, part: Part, input: &str) -> Option<Result<Measurement, Box<dyn Error>>> {
+    registry()
+        .get(&day)
+        .map(|solver| solver.measure(part, input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry() {
+        let registry = registry();
+
+        assert_eq!(
+            registry.keys().copied().collect::<Vec<u8>>(),
+            (FIRST_DAY..=LAST_DAY).collect::<Vec<u8>>()
+        );
+
+        assert!(registry.iter().all(|(day, solver)| solver.day() == *day));
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(
+            solve(1, Part::One, "1abc2\npqr3stu8vwx").unwrap().unwrap(),
+            "50"
+        );
+
+        assert_eq!(
+            solve(2, Part::One, "Game x: 3 blue")
+                .unwrap()
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 6: invalid game id `x`"
+        );
+
+        assert!(solve(19, Part::One, "").is_none());
+    }
+
+    #[test]
+    fn test_measure() {
+        let measurement = measure(17, Part::One, day_17::EXAMPLE_PART1)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(measurement.answer, "102");
+        assert!(measurement.stats.get("states_popped").unwrap() > 0);
+    }
+}
@@ -0,0 +1,360 @@
+use std::{
+    fmt,
+    fs::{read_to_string, write},
+    io::ErrorKind,
+    path::Path,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    fetch::{Fetcher, YEAR},
+    solvers::Part,
+};
+
+/// Where submissions are recorded, relative to the workspace root.
+pub const HISTORY_FILE: &str = ".submissions.toml";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Response {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl fmt::Display for Response {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::TooHigh => write!(f, "too high"),
+            Self::TooLow => write!(f, "too low"),
+            Self::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub response: Response,
+}
+
+/// A reason not to submit an answer, from what earlier submissions said.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Warning {
+    AlreadySolved(String),
+    AlreadySubmitted(Response),
+    NotBelow(String),
+    NotAbove(String),
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AlreadySolved(answer) => write!(f, "already solved with {answer}"),
+            Self::AlreadySubmitted(response) => {
+                write!(f, "already submitted, and it was {response}")
+            }
+            Self::NotBelow(bound) => write!(f, "not below {bound}, which was too high"),
+            Self::NotAbove(bound) => write!(f, "not above {bound}, which was too low"),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct History {
+    #[serde(default, rename = "submission")]
+    pub submissions: Vec<Submission>,
+}
+
+impl History {
+    /// Loads the history at `path`; a missing file is an empty history.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|e| format!("Invalid submission history {}: {e}", path.display())),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!(
+                "Could not load submission history {}: {e}",
+                path.display()
+            )),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let contents = toml::to_string(self).map_err(|e| e.to_string())?;
+
+        write(path, contents).map_err(|e| format!("Could not write {}: {e}", path.display()))
+    }
+
+    pub fn for_part(&self, day: u8, part: Part) -> impl Iterator<Item = &Submission> {
+        self.submissions
+            .iter()
+            .filter(move |submission| submission.day == day && submission.part == part.number())
+    }
+
+    /// Checks `answer` against earlier submissions for the same part: it should not repeat one,
+    /// and numeric answers should fall strictly between the highest too low and lowest too high.
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> Option<Warning> {
+        for submission in self.for_part(day, part) {
+            if submission.response == Response::Correct {
+                return Some(Warning::AlreadySolved(submission.answer.clone()));
+            }
+
+            if submission.answer == answer {
+                return Some(Warning::AlreadySubmitted(submission.response));
+            }
+        }
+
+        let answer = answer.parse::<i128>().ok()?;
+
+        let bounds = |response: Response| {
+            self.for_part(day, part)
+                .filter(move |submission| submission.response == response)
+                .filter_map(|submission| {
+                    Some((submission.answer.parse::<i128>().ok()?, &submission.answer))
+                })
+        };
+
+        if let Some((_, upper)) = bounds(Response::TooHigh)
+            .filter(|(upper, _)| answer >= *upper)
+            .min()
+        {
+            return Some(Warning::NotBelow(upper.clone()));
+        }
+
+        if let Some((_, lower)) = bounds(Response::TooLow)
+            .filter(|(lower, _)| answer <= *lower)
+            .max()
+        {
+            return Some(Warning::NotAbove(lower.clone()));
+        }
+
+        None
+    }
+
+    pub fn record(&mut self, day: u8, part: Part, answer: &str, response: Response) {
+        self.submissions.push(Submission {
+            day,
+            part: part.number(),
+            answer: answer.to_string(),
+            response,
+        });
+    }
+}
+
+/// Reads the verdict out of the page returned for a submission. Pages that do not judge the
+/// answer, such as when answering too soon or for a part already solved, are errors.
+pub fn parse_response(page: &str) -> Result<Response, String> {
+    if page.contains("That's the right answer") {
+        Ok(Response::Correct)
+    } else if page.contains("your answer is too high") {
+        Ok(Response::TooHigh)
+    } else if page.contains("your answer is too low") {
+        Ok(Response::TooLow)
+    } else if page.contains("That's not the right answer") {
+        Ok(Response::Wrong)
+    } else if page.contains("You gave an answer too recently") {
+        Err(String::from(
+            "An answer was given too recently, try again later",
+        ))
+    } else if page.contains("You don't seem to be solving the right level") {
+        Err(String::from(
+            "Not the right level, either it is already solved or part one is not",
+        ))
+    } else {
+        Err(String::from("Could not make sense of the response"))
+    }
+}
+
+/// Submits `answer` unless the history at `history_path` warns against it (or `force` is set),
+/// and records the response.
+pub fn submit(
+    fetcher: &Fetcher,
+    history_path: &Path,
+    day: u8,
+    part: Part,
+    answer: &str,
+    force: bool,
+) -> Result<Response, String> {
+    let mut history = History::load(history_path)?;
+
+    if let Some(warning) = history.check(day, part, answer) {
+        if !force {
+            return Err(format!(
+                "Not submitting {answer} for day {day} part {part}: {warning}"
+            ));
+        }
+    }
+
+    let page = fetcher.post(
+        &format!("/{YEAR}/day/{day}/answer"),
+        &[("level", &part.to_string()), ("answer", answer)],
+    )?;
+
+    let response = parse_response(&page)?;
+
+    history.record(day, part, answer, response);
+    history.save(history_path)?;
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::{serve, temp_root};
+    use std::{fs::remove_dir_all, time::Duration};
+
+    fn history_of(submissions: &[(&str, Response)]) -> History {
+        let mut history = History::default();
+
+        for (answer, response) in submissions {
+            history.record(7, Part::One, answer, *response);
+        }
+
+        history
+    }
+
+    #[test]
+    fn test_check() {
+        let history = history_of(&[
+            ("100", Response::TooHigh),
+            ("50", Response::TooHigh),
+            ("10", Response::TooLow),
+            ("20", Response::TooLow),
+            ("30", Response::Wrong),
+        ]);
+
+        assert_eq!(history.check(7, Part::One, "25"), None);
+
+        assert_eq!(
+            history.check(7, Part::One, "30"),
+            Some(Warning::AlreadySubmitted(Response::Wrong))
+        );
+
+        assert_eq!(
+            history.check(7, Part::One, "75"),
+            Some(Warning::NotBelow(String::from("50")))
+        );
+
+        assert_eq!(
+            history.check(7, Part::One, "50"),
+            Some(Warning::AlreadySubmitted(Response::TooHigh))
+        );
+
+        assert_eq!(
+            history.check(7, Part::One, "15"),
+            Some(Warning::NotAbove(String::from("20")))
+        );
+
+        assert_eq!(history.check(7, Part::Two, "75"), None);
+        assert_eq!(history.check(8, Part::One, "75"), None);
+
+        // Bounds say nothing about answers that are not numbers.
+        assert_eq!(history.check(7, Part::One, "abc"), None);
+
+        let history = history_with_correct();
+
+        assert_eq!(
+            history.check(7, Part::One, "25"),
+            Some(Warning::AlreadySolved(String::from("42")))
+        );
+    }
+
+    fn history_with_correct() -> History {
+        history_of(&[("10", Response::TooLow), ("42", Response::Correct)])
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response("<p>That's the right answer! You are one gold star closer.</p>"),
+            Ok(Response::Correct)
+        );
+
+        assert_eq!(
+            parse_response("<p>That's not the right answer; your answer is too high.</p>"),
+            Ok(Response::TooHigh)
+        );
+
+        assert_eq!(
+            parse_response("<p>That's not the right answer; your answer is too low.</p>"),
+            Ok(Response::TooLow)
+        );
+
+        assert_eq!(
+            parse_response("<p>That's not the right answer.</p>"),
+            Ok(Response::Wrong)
+        );
+
+        assert!(parse_response("<p>You gave an answer too recently</p>").is_err());
+        assert!(parse_response("<p>Unexpected</p>").is_err());
+    }
+
+    #[test]
+    fn test_history_round_trip() {
+        let root = temp_root("history");
+        let path = root.join(HISTORY_FILE);
+
+        assert_eq!(History::load(&path), Ok(History::default()));
+
+        let history = history_with_correct();
+        history.save(&path).unwrap();
+
+        assert_eq!(History::load(&path), Ok(history));
+
+        remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_submit() {
+        let (address, requests) = serve(vec![(
+            "/2023/day/7/answer",
+            200,
+            "<article><p>That's not the right answer; your answer is too high.</p></article>",
+        )]);
+
+        let root = temp_root("submit");
+        let path = root.join(HISTORY_FILE);
+
+        let mut fetcher = Fetcher::new(&address, Some("secret"), &root);
+        fetcher.min_interval = Duration::ZERO;
+
+        assert_eq!(
+            submit(&fetcher, &path, 7, Part::Two, "6440", false),
+            Ok(Response::TooHigh)
+        );
+
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec!["POST /2023/day/7/answer session=secret level=2&answer=6440"]
+        );
+
+        // The answer is now known to be too high, so it and anything above it are held back.
+        assert!(submit(&fetcher, &path, 7, Part::Two, "6440", false)
+            .unwrap_err()
+            .contains("already submitted, and it was too high"));
+
+        assert!(submit(&fetcher, &path, 7, Part::Two, "7000", false)
+            .unwrap_err()
+            .contains("not below 6440, which was too high"));
+
+        assert_eq!(requests.lock().unwrap().len(), 1);
+
+        assert_eq!(
+            submit(&fetcher, &path, 7, Part::Two, "7000", true),
+            Ok(Response::TooHigh)
+        );
+
+        assert_eq!(History::load(&path).unwrap().submissions.len(), 2);
+
+        remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+/// The fastest, median and slowest of several timings of the same run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Spread {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Spread {
+    /// Summarises `samples`, or returns `None` if there are none. The median of an even number of
+    /// samples is the mean of the middle two.
+    pub fn of(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let middle = sorted.len() / 2;
+
+        let median = if sorted.len().is_multiple_of(2) {
+            (*sorted.get(middle.checked_sub(1)?)? + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+
+        Some(Self {
+            min: *sorted.first()?,
+            median,
+            max: *sorted.last()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spread() {
+        let millis = |values: &[u64]| -> Vec<Duration> {
+            values.iter().copied().map(Duration::from_millis).collect()
+        };
+
+        assert_eq!(Spread::of(&[]), None);
+
+        assert_eq!(
+            Spread::of(&millis(&[7])),
+            Some(Spread {
+                min: Duration::from_millis(7),
+                median: Duration::from_millis(7),
+                max: Duration::from_millis(7),
+            })
+        );
+
+        assert_eq!(
+            Spread::of(&millis(&[9, 1, 5])),
+            Some(Spread {
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(5),
+                max: Duration::from_millis(9),
+            })
+        );
+
+        assert_eq!(
+            Spread::of(&millis(&[4, 10, 2, 6])).map(|spread| spread.median),
+            Some(Duration::from_millis(5))
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use grid::render::Frame;
+
+use crate::solvers::Part;
+
+/// Days that can draw their progress through a puzzle.
+pub const DAYS: [u8; 4] = [10, 14, 16, 17];
+
+/// Parses `input` for the given day and draws the frames showing how it solves `part`, or `None`
+/// if that day has no visualisation.
+pub fn frames(day: u8, part: Part, input: &str) -> Option<Result<Vec<Frame>, String>> {
+    Some(match day {
+        10 => day_10::parse(input)
+            .map(|maze| day_10::visualise::frames(&maze, part))
+            .map_err(|e| e.to_string()),
+        14 => day_14::parse(input)
+            .map(|platform| day_14::visualise::frames(&platform, part))
+            .map_err(|e| e.to_string()),
+        16 => day_16::parse(input)
+            .map(|grid| day_16::visualise::frames(&grid, part))
+            .map_err(|e| e.to_string()),
+        17 => day_17::parse(input)
+            .map(|grid| day_17::visualise::frames(&grid, part))
+            .map_err(|e| e.to_string()),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solvers::registry;
+
+    #[test]
+    fn test_frames() {
+        let registry = registry();
+
+        for day in DAYS {
+            for part in Part::iterator() {
+                let example = registry[&day].example(part);
+                let frames = frames(day, part, example).unwrap().unwrap();
+
+                assert!(frames.len() > 1, "day {day} part {part}");
+            }
+        }
+
+        assert!(frames(1, Part::One, "1abc2").is_none());
+
+        assert_eq!(
+            frames(17, Part::One, "12\n3x").unwrap().unwrap_err(),
+            "line 2, column 2: invalid heat loss `x`"
+        );
+    }
+}
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use common::Part;
+
+fn main() -> ExitCode {
+    common::runner::run::<{{lib}}::{{type}}>(Part::One)
+}
@@ -0,0 +1,111 @@
+mod part1;
+mod part2;
+
+use std::{error::Error, fmt};
+
+use common::{Line, Overflow, Position, Solution};
+use tracing::trace;
+
+pub const EXAMPLE_PART1: &str = "";
+
+pub const EXAMPLE_PART2: &str = EXAMPLE_PART1;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    Empty,
+    EmptyLine(Position),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the input is empty"),
+            Self::EmptyLine(position) => write!(f, "{position}: empty line"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+fn parse_line(line: Line) -> Result<String, ParseError> {
+    trace!(%line.text, "Parsing line");
+
+    if line.text.trim().is_empty() {
+        return Err(ParseError::EmptyLine(line.whole()));
+    }
+
+    Ok(line.text.to_string())
+}
+
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    let lines = common::lines(input)
+        .map(parse_line)
+        .collect::<Result<Vec<String>, ParseError>>()?;
+
+    if lines.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    Ok(lines)
+}
+
+pub fn solve_part1(lines: &[String]) -> usize {
+    part1::solve(lines)
+}
+
+pub fn solve_part2(lines: &[String]) -> usize {
+    part2::solve(lines)
+}
+
+pub struct {{type}};
+
+impl Solution for {{type}} {
+    type Input = Vec<String>;
+    type Error = ParseError;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const DAY: u8 = {{day}};
+
+    const EXAMPLE_PART1: &'static str = EXAMPLE_PART1;
+    const EXAMPLE_PART2: &'static str = EXAMPLE_PART2;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Overflow> {
+        Ok(solve_part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Overflow> {
+        Ok(solve_part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(parse(""), Err(ParseError::Empty));
+
+        let lines = "first
+
+third";
+
+        assert_eq!(
+            parse(lines).unwrap_err().to_string(),
+            "line 2, column 1: empty line"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_never_panics(input in any::<String>()) {
+            let _ = parse(&input);
+        }
+    }
+}
@@ -0,0 +1,18 @@
+pub(crate) fn solve(lines: &[String]) -> usize {
+    lines.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, solve_part1, EXAMPLE_PART1};
+
+    #[test]
+    #[ignore = "fill in EXAMPLE_PART1 and its answer"]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART1;
+
+        let result = solve_part1(&parse(lines).unwrap());
+
+        assert_eq!(result, 0);
+    }
+}
@@ -0,0 +1,18 @@
+pub(crate) fn solve(lines: &[String]) -> usize {
+    lines.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, solve_part2, EXAMPLE_PART2};
+
+    #[test]
+    #[ignore = "fill in EXAMPLE_PART2 and its answer"]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART2;
+
+        let result = solve_part2(&parse(lines).unwrap());
+
+        assert_eq!(result, 0);
+    }
+}
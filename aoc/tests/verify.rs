@@ -0,0 +1,53 @@
+use std::path::Path;
+
+use aoc::manifest::{self, Entry, Outcome, MANIFEST_FILE};
+
+fn verify_all(slow: bool) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(MANIFEST_FILE);
+
+    let entries: Vec<Entry> = manifest::load(&path)
+        .unwrap()
+        .into_iter()
+        .filter(|entry| entry.slow == slow)
+        .collect();
+
+    assert!(!entries.is_empty(), "no answers in {}", path.display());
+
+    let failures: Vec<String> = entries
+        .iter()
+        .map(Entry::verify)
+        .filter_map(|verification| match verification.outcome {
+            Outcome::Pass => None,
+            Outcome::Mismatch(actual) => Some(format!(
+                "day {} part {}: expected {}, got {actual}",
+                verification.entry.day, verification.entry.part, verification.entry.expected
+            )),
+            Outcome::Error(error) => Some(format!(
+                "day {} part {}: expected {}, got error: {error}",
+                verification.entry.day, verification.entry.part, verification.entry.expected
+            )),
+        })
+        .collect();
+
+    assert!(
+        failures.is_empty(),
+        "{} of {} answers do not match {}:\n{}",
+        failures.len(),
+        entries.len(),
+        MANIFEST_FILE,
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn test_answers() {
+    verify_all(false);
+}
+
+#[test]
+#[ignore = "slow solvers, run with `cargo test --release -- --ignored`"]
+fn test_slow_answers() {
+    verify_all(true);
+}
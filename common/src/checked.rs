@@ -0,0 +1,121 @@
+//! Integer arithmetic that reports overflow instead of wrapping, as unchecked arithmetic does in
+//! release builds.
+
+use std::{error::Error, fmt};
+
+/// An answer, or a value on the way to one, too large for the integer type it is computed in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Overflow;
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "arithmetic overflow")
+    }
+}
+
+impl Error for Overflow {}
+
+pub trait Checked: Copy + Sized {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn try_add(self, other: Self) -> Result<Self, Overflow>;
+
+    fn try_sub(self, other: Self) -> Result<Self, Overflow>;
+
+    fn try_mul(self, other: Self) -> Result<Self, Overflow>;
+}
+
+macro_rules! impl_checked {
+    ($($type:ty),*) => {
+        $(
+            impl Checked for $type {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn try_add(self, other: Self) -> Result<Self, Overflow> {
+                    self.checked_add(other).ok_or(Overflow)
+                }
+
+                fn try_sub(self, other: Self) -> Result<Self, Overflow> {
+                    self.checked_sub(other).ok_or(Overflow)
+                }
+
+                fn try_mul(self, other: Self) -> Result<Self, Overflow> {
+                    self.checked_mul(other).ok_or(Overflow)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked!(u32, u64, u128, usize, i64, i128);
+
+pub fn sum<T: Checked>(values: impl IntoIterator<Item = T>) -> Result<T, Overflow> {
+    values.into_iter().try_fold(T::ZERO, T::try_add)
+}
+
+/// Sums values that may themselves have overflowed.
+pub fn try_sum<T: Checked>(
+    values: impl IntoIterator<Item = Result<T, Overflow>>,
+) -> Result<T, Overflow> {
+    values
+        .into_iter()
+        .try_fold(T::ZERO, |total, value| total.try_add(value?))
+}
+
+pub fn product<T: Checked>(values: impl IntoIterator<Item = T>) -> Result<T, Overflow> {
+    values.into_iter().try_fold(T::ONE, T::try_mul)
+}
+
+/// Converts between integer types, failing when `value` does not fit in `T`.
+pub fn convert<T: TryFrom<U>, U>(value: U) -> Result<T, Overflow> {
+    T::try_from(value).map_err(|_| Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sum() {
+        assert_eq!(sum([1_u32, 2, 3]), Ok(6));
+        assert_eq!(sum(Vec::<u64>::new()), Ok(0));
+        assert_eq!(sum([u32::MAX, 1]), Err(Overflow));
+        assert_eq!(sum([u64::from(u32::MAX), 1]), Ok(1 << 32));
+        assert_eq!(sum([i64::MIN, -1]), Err(Overflow));
+    }
+
+    #[test]
+    fn test_try_sum() {
+        assert_eq!(try_sum([Ok(1_u128), Ok(2)]), Ok(3));
+        assert_eq!(try_sum([Ok(1_u128), Err(Overflow)]), Err(Overflow));
+        assert_eq!(try_sum([Ok(u128::MAX), Ok(1)]), Err(Overflow));
+    }
+
+    #[test]
+    fn test_product() {
+        assert_eq!(product([2_u64, 3, 7]), Ok(42));
+        assert_eq!(product(Vec::<u64>::new()), Ok(1));
+        assert_eq!(product([u64::MAX, 2]), Err(Overflow));
+        assert_eq!(
+            product([u128::from(u64::MAX), 2]),
+            Ok(u128::from(u64::MAX) * 2)
+        );
+    }
+
+    #[test]
+    fn test_operations() {
+        assert_eq!(5_usize.try_sub(3), Ok(2));
+        assert_eq!(3_usize.try_sub(5), Err(Overflow));
+        assert_eq!(i128::MAX.try_add(1), Err(Overflow));
+        assert_eq!(i128::MIN.try_mul(-1), Err(Overflow));
+    }
+
+    #[test]
+    fn test_convert() {
+        assert_eq!(convert::<u32, u64>(7), Ok(7));
+        assert_eq!(convert::<u32, u64>(1 << 32), Err(Overflow));
+        assert_eq!(convert::<u64, i64>(-1), Err(Overflow));
+    }
+}
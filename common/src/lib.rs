@@ -0,0 +1,494 @@
+pub mod checked;
+pub mod runner;
+
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt,
+    io::{self, IsTerminal},
+    time::{Duration, Instant},
+};
+
+use tracing::{debug, debug_span, info_span};
+use tracing_subscriber::EnvFilter;
+
+pub use checked::Overflow;
+
+/// Environment variable holding the default tracing filter, e.g. `AOC_LOG=day_17=trace`.
+pub const LOG_ENV: &str = "AOC_LOG";
+
+/// Installs a subscriber writing to standard error. `filter` takes precedence over [`LOG_ENV`],
+/// and only warnings are shown when neither is set. Does nothing if a subscriber is already set.
+pub fn init_tracing(filter: Option<&str>) -> Result<(), String> {
+    let filter = match filter {
+        Some(filter) => EnvFilter::try_new(filter),
+        None => EnvFilter::try_from_env(LOG_ENV).or_else(|_| EnvFilter::try_new("warn")),
+    }
+    .map_err(|e| format!("Invalid log filter: {e}"))?;
+
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .try_init();
+
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const fn from_number(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(Self::One),
+            2 => Some(Self::Two),
+            _ => None,
+        }
+    }
+
+    pub const fn number(self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+
+    pub fn iterator() -> impl Iterator<Item = Self> {
+        [Self::One, Self::Two].iter().copied()
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::One => f.pad("1"),
+            Self::Two => f.pad("2"),
+        }
+    }
+}
+
+/// A line of puzzle input together with its 1-based line number, used to point parse errors at
+/// the offending text.
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub const fn new(number: usize, text: &'a str) -> Self {
+        Self { number, text }
+    }
+
+    /// 1-based column at which `part` starts, where `part` is a slice of this line. Anything
+    /// else is reported as column 1.
+    pub fn column(&self, part: &str) -> usize {
+        let offset = (part.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+
+        self.text
+            .get(..offset)
+            .map_or(1, |before| before.chars().count() + 1)
+    }
+
+    pub fn position(&self, part: &str) -> Position {
+        Position {
+            line: self.number,
+            column: self.column(part),
+            text: part.to_string(),
+        }
+    }
+
+    /// Position of the whole line, for errors that are not about any one part of it.
+    pub fn whole(&self) -> Position {
+        self.position(self.text)
+    }
+}
+
+/// Numbers the lines of `input` from 1.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, text)| Line::new(index + 1, text))
+}
+
+/// Where a parse error happened and the text that caused it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// Named counters a solver reports alongside its answer, such as cache hits or states explored.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Stats(BTreeMap<&'static str, u64>);
+
+impl Stats {
+    pub fn add(&mut self, name: &'static str, value: u64) {
+        *self.0.entry(name).or_default() += value;
+    }
+
+    pub fn set(&mut self, name: &'static str, value: u64) {
+        self.0.insert(name, value);
+    }
+
+    pub fn get(&self, name: &str) -> Option<u64> {
+        self.0.get(name).copied()
+    }
+
+    pub fn merge(&mut self, other: &Self) {
+        for (name, value) in &other.0 {
+            self.add(name, *value);
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, u64)> + '_ {
+        self.0.iter().map(|(name, value)| (*name, *value))
+    }
+}
+
+/// The answer to one part along with how long parsing and solving took.
+#[derive(Clone, Debug)]
+pub struct Measurement {
+    pub answer: String,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+    pub stats: Stats,
+}
+
+/// Whole nanoseconds for JSON output, saturating rather than failing on absurdly long runs.
+pub fn nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+impl Measurement {
+    /// A single line JSON object describing this measurement, for dashboards and other tooling.
+    pub fn to_json(&self, day: u8, part: Part, input: &str) -> String {
+        self.to_json_value(day, part, input).to_string()
+    }
+
+    /// The object written by [`Self::to_json`], for callers adding fields of their own.
+    pub fn to_json_value(&self, day: u8, part: Part, input: &str) -> serde_json::Value {
+        serde_json::json!({
+            "day": day,
+            "part": part.number(),
+            "input": input,
+            "answer": self.answer,
+            "parse_time_ns": nanos(self.parse_time),
+            "solve_time_ns": nanos(self.solve_time),
+            "stats": self.stats.0,
+        })
+    }
+}
+
+/// A single day's puzzle: how to parse its input and how to answer both parts from it.
+pub trait Solution {
+    type Input;
+    type Error: Error + 'static;
+    type Part1: fmt::Display;
+    type Part2: fmt::Display;
+
+    const DAY: u8;
+
+    /// Worked examples from the puzzle text, used by `--example` and the benchmarks.
+    const EXAMPLE_PART1: &'static str;
+    const EXAMPLE_PART2: &'static str;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error>;
+
+    /// Rejects parsed input that only `part` cannot be solved for, such as a network without the
+    /// nodes part one walks between, as part of parsing.
+    fn validate(_part: Part, _input: &Self::Input) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Solves part one, failing rather than wrapping if the answer does not fit its type.
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Overflow>;
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Overflow>;
+
+    /// Solves part one, recording any solver specific counters in `stats`.
+    fn part1_with_stats(input: &Self::Input, _stats: &mut Stats) -> Result<Self::Part1, Overflow> {
+        Self::part1(input)
+    }
+
+    /// Solves part two, recording any solver specific counters in `stats`.
+    fn part2_with_stats(input: &Self::Input, _stats: &mut Stats) -> Result<Self::Part2, Overflow> {
+        Self::part2(input)
+    }
+}
+
+/// Why a part could not be answered: either its input did not parse or the answer overflowed.
+#[derive(Debug, PartialEq, Eq)]
+pub enum SolveError<E> {
+    Parse(E),
+    Overflow(Overflow),
+}
+
+impl<E: fmt::Display> fmt::Display for SolveError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(e) => write!(f, "{e}"),
+            Self::Overflow(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl<E: Error> Error for SolveError<E> {}
+
+/// Parses `input` and solves `part`, timing each phase separately.
+pub fn measure<S: Solution>(part: Part, input: &str) -> Result<Measurement, SolveError<S::Error>> {
+    let _span = info_span!("solve", day = S::DAY, part = part.number()).entered();
+
+    let start = Instant::now();
+
+    let parsed = debug_span!("parse", bytes = input.len())
+        .in_scope(|| {
+            let parsed = S::parse(input)?;
+            S::validate(part, &parsed)?;
+            Ok(parsed)
+        })
+        .map_err(SolveError::Parse)?;
+
+    let parse_time = start.elapsed();
+
+    let mut stats = Stats::default();
+
+    let start = Instant::now();
+
+    let answer = debug_span!("part")
+        .in_scope(|| match part {
+            Part::One => S::part1_with_stats(&parsed, &mut stats).map(|answer| answer.to_string()),
+            Part::Two => S::part2_with_stats(&parsed, &mut stats).map(|answer| answer.to_string()),
+        })
+        .map_err(SolveError::Overflow)?;
+
+    let solve_time = start.elapsed();
+
+    debug!(
+        %answer,
+        parse_time = ?parse_time,
+        solve_time = ?solve_time,
+        "Solved"
+    );
+
+    Ok(Measurement {
+        answer,
+        parse_time,
+        solve_time,
+        stats,
+    })
+}
+
+/// Object safe view of a [`Solution`], so days with different input and answer types can be
+/// stored side by side and driven generically.
+pub trait Solver: Sync {
+    fn solve(&self, part: Part, input: &str) -> Result<String, Box<dyn Error>>;
+
+    fn measure(&self, part: Part, input: &str) -> Result<Measurement, Box<dyn Error>>;
+
+    fn day(&self) -> u8;
+
+    fn example(&self, part: Part) -> &'static str;
+}
+
+impl<T: Solution + Sync> Solver for T {
+    fn solve(&self, part: Part, input: &str) -> Result<String, Box<dyn Error>> {
+        Ok(measure::<T>(part, input)?.answer)
+    }
+
+    fn measure(&self, part: Part, input: &str) -> Result<Measurement, Box<dyn Error>> {
+        Ok(measure::<T>(part, input)?)
+    }
+
+    fn day(&self) -> u8 {
+        T::DAY
+    }
+
+    fn example(&self, part: Part) -> &'static str {
+        match part {
+            Part::One => T::EXAMPLE_PART1,
+            Part::Two => T::EXAMPLE_PART2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub(crate) struct Sum;
+
+    #[derive(Debug, PartialEq, Eq)]
+    pub(crate) struct InvalidNumber(Position);
+
+    impl fmt::Display for InvalidNumber {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}: invalid number `{}`", self.0, self.0.text)
+        }
+    }
+
+    impl Error for InvalidNumber {}
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+        type Error = InvalidNumber;
+        type Part1 = u32;
+        type Part2 = usize;
+
+        const DAY: u8 = 0;
+
+        const EXAMPLE_PART1: &'static str = "1\n2\n3";
+        const EXAMPLE_PART2: &'static str = "4\n5";
+
+        fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+            lines(input)
+                .map(|line| {
+                    line.text
+                        .trim()
+                        .parse()
+                        .map_err(|_| InvalidNumber(line.position(line.text.trim())))
+                })
+                .collect()
+        }
+
+        /// Part two counts the numbers, so needs at least one.
+        fn validate(part: Part, input: &Self::Input) -> Result<(), Self::Error> {
+            if part == Part::Two && input.is_empty() {
+                return Err(InvalidNumber(Line::new(1, "").whole()));
+            }
+
+            Ok(())
+        }
+
+        fn part1(input: &Self::Input) -> Result<Self::Part1, Overflow> {
+            checked::sum(input.iter().copied())
+        }
+
+        fn part2(input: &Self::Input) -> Result<Self::Part2, Overflow> {
+            Ok(input.len())
+        }
+
+        fn part1_with_stats(
+            input: &Self::Input,
+            stats: &mut Stats,
+        ) -> Result<Self::Part1, Overflow> {
+            stats.set("numbers", input.len() as u64);
+
+            Self::part1(input)
+        }
+    }
+
+    #[test]
+    fn test_solver() {
+        let solver: &dyn Solver = &Sum;
+
+        assert_eq!(solver.solve(Part::One, "1\n2\n3").unwrap(), "6");
+        assert_eq!(solver.solve(Part::Two, "1\n2\n3").unwrap(), "3");
+
+        let error = solver.solve(Part::One, "1\n  two").unwrap_err();
+
+        assert_eq!(error.to_string(), "line 2, column 3: invalid number `two`");
+
+        assert_eq!(solver.example(Part::Two), "4\n5");
+
+        assert_eq!(solver.solve(Part::One, "").unwrap(), "0");
+        assert!(solver.solve(Part::Two, "").is_err());
+
+        let error = solver.solve(Part::One, "4294967295\n1").unwrap_err();
+
+        assert_eq!(error.to_string(), "arithmetic overflow");
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut stats = Stats::default();
+
+        stats.add("hits", 2);
+        stats.add("hits", 3);
+        stats.set("length", 7);
+
+        let mut other = Stats::default();
+
+        other.add("hits", 1);
+        other.add("misses", 4);
+
+        stats.merge(&other);
+
+        assert_eq!(
+            stats.iter().collect::<Vec<_>>(),
+            vec![("hits", 6), ("length", 7), ("misses", 4)]
+        );
+    }
+
+    #[test]
+    fn test_measure() {
+        let solver: &dyn Solver = &Sum;
+
+        let measurement = solver.measure(Part::One, "1\n2\n3").unwrap();
+
+        assert_eq!(measurement.answer, "6");
+        assert_eq!(measurement.stats.get("numbers"), Some(3));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&measurement.to_json(3, Part::One, "input")).unwrap();
+
+        assert_eq!(json["day"], 3);
+        assert_eq!(json["part"], 1);
+        assert_eq!(json["input"], "input");
+        assert_eq!(json["answer"], "6");
+        assert_eq!(json["stats"]["numbers"], 3);
+        assert!(json["parse_time_ns"].is_u64());
+
+        assert!(solver.measure(Part::Two, "x").is_err());
+        assert_eq!(
+            measure::<Sum>(Part::One, "4294967295\n1").unwrap_err(),
+            SolveError::Overflow(Overflow)
+        );
+        assert!(measure::<Sum>(Part::Two, "4\n5")
+            .unwrap()
+            .stats
+            .iter()
+            .next()
+            .is_none());
+    }
+
+    #[test]
+    fn test_line_position() {
+        let line = Line::new(4, "Game 12: 3 blue");
+
+        let (game, sets) = line.text.split_once(':').unwrap();
+
+        assert_eq!(line.column(game), 1);
+        assert_eq!(line.column(sets.trim()), 10);
+
+        assert_eq!(
+            line.position(&game[5..]),
+            Position {
+                line: 4,
+                column: 6,
+                text: String::from("12"),
+            }
+        );
+
+        assert_eq!(line.column("not from this line"), 1);
+    }
+
+    #[test]
+    fn test_part_from_number() {
+        assert_eq!(Part::from_number(1), Some(Part::One));
+        assert_eq!(Part::from_number(2), Some(Part::Two));
+        assert_eq!(Part::from_number(3), None);
+    }
+}
@@ -0,0 +1,315 @@
+use std::{
+    fs::read_to_string,
+    io::{self, IsTerminal, Read, Write},
+    process::ExitCode,
+};
+
+use crate::{init_tracing, measure, Part, Solution, SolveError};
+
+pub const EXIT_MISSING_INPUT: u8 = 2;
+pub const EXIT_UNREADABLE_INPUT: u8 = 3;
+pub const EXIT_PARSE_FAILURE: u8 = 4;
+pub const EXIT_OVERFLOW: u8 = 5;
+
+const USAGE: &str = "Usage: partN [--format text|json] [--log FILTER] [--example] [-] [FILE]...
+
+Solves the puzzle for each input in turn. `-` reads standard input, as does passing no inputs
+when standard input is not a terminal. `--example` uses the worked example from the puzzle.
+`--format json` prints one JSON object per input with the answer, timings and solver statistics.
+`--log` sets the tracing filter written to standard error, e.g. `debug` or `day_17=trace`, and
+overrides the `AOC_LOG` environment variable.";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Source {
+    Example,
+    Stdin,
+    File(String),
+}
+
+impl Source {
+    fn name(&self) -> &str {
+        match self {
+            Self::Example => "example",
+            Self::Stdin => "-",
+            Self::File(path) => path,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Options {
+    sources: Vec<Source>,
+    format: Format,
+    log: Option<String>,
+}
+
+fn parse_args(args: &[String], stdin_is_terminal: bool) -> Result<Options, String> {
+    let mut sources: Vec<Source> = Vec::default();
+    let mut format = Format::Text;
+    let mut log: Option<String> = None;
+
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        sources.push(match arg.as_str() {
+            "--format" => {
+                format = match args.next().map(String::as_str) {
+                    Some("text") => Format::Text,
+                    Some("json") => Format::Json,
+                    Some(other) => return Err(format!("Unknown format `{other}`")),
+                    None => return Err(String::from("Missing value for `--format`")),
+                };
+
+                continue;
+            }
+            "--log" => {
+                log = Some(
+                    args.next()
+                        .ok_or_else(|| String::from("Missing value for `--log`"))?
+                        .clone(),
+                );
+
+                continue;
+            }
+            "--example" => Source::Example,
+            "-" => Source::Stdin,
+            option if option.starts_with('-') => return Err(format!("Unknown option `{option}`")),
+            path => Source::File(path.to_string()),
+        });
+    }
+
+    if sources.is_empty() {
+        if stdin_is_terminal {
+            return Err(String::from("No input provided"));
+        }
+
+        sources.push(Source::Stdin);
+    }
+
+    Ok(Options {
+        sources,
+        format,
+        log,
+    })
+}
+
+/// Solves `part` for every input named in `args`, writing one answer per input to `out`, and
+/// returns the exit code of the first failure, or 0 if every input was solved.
+pub fn run_with<S: Solution>(
+    part: Part,
+    args: &[String],
+    stdin: &mut dyn Read,
+    stdin_is_terminal: bool,
+    out: &mut dyn Write,
+    err: &mut dyn Write,
+) -> u8 {
+    let Options {
+        sources,
+        format,
+        log,
+    } = match parse_args(args, stdin_is_terminal) {
+        Ok(options) => options,
+        Err(e) => {
+            let _ = writeln!(err, "{e}\n\n{USAGE}");
+            return EXIT_MISSING_INPUT;
+        }
+    };
+
+    if let Err(e) = init_tracing(log.as_deref()) {
+        let _ = writeln!(err, "{e}\n\n{USAGE}");
+        return EXIT_MISSING_INPUT;
+    }
+
+    let labelled = sources.len() > 1;
+
+    let mut exit_code = 0;
+
+    for source in &sources {
+        let input = match source {
+            Source::Example => Ok(String::from(match part {
+                Part::One => S::EXAMPLE_PART1,
+                Part::Two => S::EXAMPLE_PART2,
+            })),
+            Source::Stdin => {
+                let mut input = String::new();
+                stdin.read_to_string(&mut input).map(|_| input)
+            }
+            Source::File(path) => read_to_string(path),
+        };
+
+        let result = match input {
+            Ok(input) => measure::<S>(part, &input).map_err(|e| match e {
+                SolveError::Parse(e) => (
+                    EXIT_PARSE_FAILURE,
+                    format!("Could not parse input {}: {e}", source.name()),
+                ),
+                SolveError::Overflow(e) => (
+                    EXIT_OVERFLOW,
+                    format!("Could not solve input {}: {e}", source.name()),
+                ),
+            }),
+            Err(e) => Err((
+                EXIT_UNREADABLE_INPUT,
+                format!("Could not load input {}: {e}", source.name()),
+            )),
+        };
+
+        match result {
+            Ok(measurement) if format == Format::Json => {
+                let _ = writeln!(out, "{}", measurement.to_json(S::DAY, part, source.name()));
+            }
+            Ok(measurement) if labelled => {
+                let _ = writeln!(out, "{}: {}", source.name(), measurement.answer);
+            }
+            Ok(measurement) => {
+                let _ = writeln!(out, "{}", measurement.answer);
+            }
+            Err((code, message)) => {
+                let _ = writeln!(err, "{message}");
+
+                if exit_code == 0 {
+                    exit_code = code;
+                }
+            }
+        }
+    }
+
+    exit_code
+}
+
+/// Entry point shared by every day's `partN` binaries.
+pub fn run<S: Solution>(part: Part) -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let stdin = io::stdin();
+    let stdin_is_terminal = stdin.is_terminal();
+
+    ExitCode::from(run_with::<S>(
+        part,
+        &args,
+        &mut stdin.lock(),
+        stdin_is_terminal,
+        &mut io::stdout(),
+        &mut io::stderr(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::Sum;
+
+    fn run(args: &[&str], stdin: &str, stdin_is_terminal: bool) -> (u8, String, String) {
+        let args: Vec<String> = args.iter().map(ToString::to_string).collect();
+
+        let mut out: Vec<u8> = Vec::default();
+        let mut err: Vec<u8> = Vec::default();
+
+        let code = run_with::<Sum>(
+            Part::One,
+            &args,
+            &mut stdin.as_bytes(),
+            stdin_is_terminal,
+            &mut out,
+            &mut err,
+        );
+
+        (
+            code,
+            String::from_utf8(out).unwrap(),
+            String::from_utf8(err).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let args = |args: &[&str]| {
+            args.iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>()
+        };
+
+        assert_eq!(
+            parse_args(&args(&["--example", "-"]), true),
+            Ok(Options {
+                sources: vec![Source::Example, Source::Stdin],
+                format: Format::Text,
+                log: None,
+            })
+        );
+
+        assert_eq!(
+            parse_args(&args(&["--format", "json", "--log", "debug"]), false),
+            Ok(Options {
+                sources: vec![Source::Stdin],
+                format: Format::Json,
+                log: Some(String::from("debug")),
+            })
+        );
+
+        assert!(parse_args(&args(&["--format", "yaml"]), false).is_err());
+        assert!(parse_args(&args(&["--format"]), false).is_err());
+        assert!(parse_args(&args(&["--log"]), false).is_err());
+
+        assert!(parse_args(&[], true).is_err());
+        assert!(parse_args(&[String::from("--sample")], false).is_err());
+    }
+
+    #[test]
+    fn test_run_with() {
+        assert_eq!(
+            run(&[], "1\n2", false),
+            (0, String::from("3\n"), String::new())
+        );
+
+        assert_eq!(
+            run(&["--example", "-"], "4", true),
+            (0, String::from("example: 6\n-: 4\n"), String::new())
+        );
+
+        assert_eq!(run(&[], "", true).0, EXIT_MISSING_INPUT);
+        assert_eq!(
+            run(&["--log", "day_01=bogus", "--example"], "", true).0,
+            EXIT_MISSING_INPUT
+        );
+
+        let (code, out, _) = run(&["--format", "json", "--example"], "", true);
+
+        let json: serde_json::Value = serde_json::from_str(&out).unwrap();
+
+        assert_eq!(code, 0);
+        assert_eq!(json["input"], "example");
+        assert_eq!(json["answer"], "6");
+        assert_eq!(json["stats"]["numbers"], 3);
+
+        let (code, out, err) = run(&["-", "/nonexistent/input"], "x", false);
+
+        assert_eq!(code, EXIT_PARSE_FAILURE);
+        assert_eq!(out, "");
+        assert_eq!(
+            err.lines().next(),
+            Some("Could not parse input -: line 1, column 1: invalid number `x`")
+        );
+        assert!(err.contains("Could not load input /nonexistent/input"));
+
+        assert_eq!(
+            run(&["/nonexistent/input"], "", true).0,
+            EXIT_UNREADABLE_INPUT
+        );
+
+        assert_eq!(
+            run(&["-"], "4294967295\n1", false),
+            (
+                EXIT_OVERFLOW,
+                String::new(),
+                String::from("Could not solve input -: arithmetic overflow\n")
+            )
+        );
+    }
+}
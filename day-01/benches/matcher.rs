@@ -0,0 +1,277 @@
+use std::{fmt, num::ParseIntError};
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use day_01::{vocabulary::LANGUAGES, Matcher, Vocabulary};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+/// Day 01's digits as they were before the automaton, kept along with the functions below as the
+/// baseline it is measured against.
+#[derive(Clone, Copy)]
+enum Number {
+    One,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+}
+
+impl Number {
+    fn iterator() -> impl Iterator<Item = Self> {
+        [
+            Self::One,
+            Self::Two,
+            Self::Three,
+            Self::Four,
+            Self::Five,
+            Self::Six,
+            Self::Seven,
+            Self::Eight,
+            Self::Nine,
+        ]
+        .iter()
+        .copied()
+    }
+
+    const fn to_char(self) -> char {
+        match self {
+            Self::One => '1',
+            Self::Two => '2',
+            Self::Three => '3',
+            Self::Four => '4',
+            Self::Five => '5',
+            Self::Six => '6',
+            Self::Seven => '7',
+            Self::Eight => '8',
+            Self::Nine => '9',
+        }
+    }
+
+    const fn from_char(value: char) -> Option<Self> {
+        match value {
+            '1' => Some(Self::One),
+            '2' => Some(Self::Two),
+            '3' => Some(Self::Three),
+            '4' => Some(Self::Four),
+            '5' => Some(Self::Five),
+            '6' => Some(Self::Six),
+            '7' => Some(Self::Seven),
+            '8' => Some(Self::Eight),
+            '9' => Some(Self::Nine),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::One => write!(f, "one"),
+            Self::Two => write!(f, "two"),
+            Self::Three => write!(f, "three"),
+            Self::Four => write!(f, "four"),
+            Self::Five => write!(f, "five"),
+            Self::Six => write!(f, "six"),
+            Self::Seven => write!(f, "seven"),
+            Self::Eight => write!(f, "eight"),
+            Self::Nine => write!(f, "nine"),
+        }
+    }
+}
+
+/// Splits `source` into runs of letters at each digit and finds the words in every run with
+/// `numbers_from_token`, then joins the first and last digit into a string to parse, as day 01
+/// did before the automaton.
+fn number_from_string<T: Copy>(
+    source: &str,
+    numbers_from_token: impl Fn(&str) -> Vec<T>,
+    from_char: impl Fn(char) -> Option<T>,
+    to_char: impl Fn(T) -> char,
+) -> Result<u32, ParseIntError> {
+    let mut numbers: Vec<T> = Vec::default();
+
+    let mut current_token = String::default();
+
+    for c in source.chars() {
+        if c.is_numeric() {
+            numbers.append(&mut numbers_from_token(current_token.as_str()));
+
+            current_token = String::default();
+
+            if let Some(number) = from_char(c) {
+                numbers.push(number);
+            }
+        } else {
+            current_token.push(c);
+        }
+    }
+
+    if !current_token.is_empty() {
+        numbers.append(&mut numbers_from_token(current_token.as_str()));
+    }
+
+    let mut number_string = String::default();
+
+    if let Some(first_digit) = numbers.first() {
+        number_string.push(to_char(*first_digit));
+    }
+
+    if let Some(last_digit) = numbers.last() {
+        number_string.push(to_char(*last_digit));
+    }
+
+    number_string.parse::<u32>()
+}
+
+/// The English words in `token`, spelling each out afresh and searching for it with
+/// `match_indices`.
+fn english_from_token(token: &str) -> Vec<Number> {
+    let mut vec: Vec<(usize, Number)> = Number::iterator()
+        .flat_map(|number| {
+            let indices: Vec<(usize, &str)> = token.match_indices(&number.to_string()).collect();
+            indices
+                .iter()
+                .map(|(index, _)| (*index, number))
+                .collect::<Vec<(usize, Number)>>()
+        })
+        .collect();
+
+    vec.sort_by_key(|a| a.0);
+
+    vec.iter().map(|(_, b)| *b).collect()
+}
+
+/// The words of `vocabulary` in `token`, with one `match_indices` pass per word.
+fn vocabulary_from_token(token: &str, vocabulary: &Vocabulary) -> Vec<u32> {
+    let mut vec: Vec<(usize, u32)> = vocabulary
+        .words()
+        .flat_map(|(word, number)| {
+            token
+                .match_indices(word)
+                .map(move |(index, _)| (index, number))
+        })
+        .collect();
+
+    vec.sort_by_key(|a| a.0);
+
+    vec.iter().map(|(_, b)| *b).collect()
+}
+
+/// Part two before the automaton: English only, as the puzzle has it.
+fn english_baseline(line: &str) -> Option<u32> {
+    number_from_string(line, english_from_token, Number::from_char, Number::to_char).ok()
+}
+
+/// Part two before the automaton with any vocabulary of the digits 1 to 9.
+fn vocabulary_baseline(line: &str, vocabulary: &Vocabulary) -> Option<u32> {
+    number_from_string(
+        line,
+        |token| vocabulary_from_token(token, vocabulary),
+        |c| c.to_digit(10).filter(|digit| *digit > 0),
+        |digit| char::from_digit(digit, 10).unwrap_or_default(),
+    )
+    .ok()
+}
+
+/// Roughly `bytes` of lines like the puzzle's: a few dozen letters, digits and spelled-out words.
+fn document(vocabulary: &Vocabulary, bytes: usize) -> Vec<String> {
+    let mut rng = StdRng::seed_from_u64(1);
+    let words: Vec<&str> = vocabulary.words().map(|(word, _)| word).collect();
+
+    let mut lines = Vec::default();
+    let mut total = 0;
+
+    while total < bytes {
+        let mut line = String::default();
+
+        for _ in 0..rng.gen_range(4..=16) {
+            match rng.gen_range(0..6) {
+                0 => line.push(char::from(b'1' + rng.gen_range(0..9))),
+                1 => line.push_str(words.choose(&mut rng).unwrap()),
+                _ => line.push(char::from(b'a' + rng.gen_range(0..26))),
+            }
+        }
+
+        total += line.len() + 1;
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// Finds a line's calibration value, if it has one.
+type Value<'a> = dyn Fn(&str) -> Option<u32> + 'a;
+
+fn matcher(c: &mut Criterion) {
+    // Words worth more than 9 came with the automaton, so the baseline cannot find them.
+    let mut every_language = Vocabulary::empty();
+
+    for language in LANGUAGES
+        .iter()
+        .filter(|language| **language != "english-extended")
+    {
+        every_language
+            .extend(&Vocabulary::builtin(language).unwrap())
+            .unwrap();
+    }
+
+    let english = Vocabulary::english();
+
+    let baselines: [(&str, &Vocabulary, &Value); 2] = [
+        ("english", &english, &english_baseline),
+        ("every-language", &every_language, &|line| {
+            vocabulary_baseline(line, &every_language)
+        }),
+    ];
+
+    let mut group = c.benchmark_group("day-01/matcher");
+
+    group.sample_size(10);
+
+    for (name, vocabulary, baseline) in baselines {
+        let matcher = Matcher::new(vocabulary);
+
+        let sum = |lines: &[String], value: &Value| -> u64 {
+            lines
+                .iter()
+                .filter_map(|line| value(black_box(line)))
+                .map(u64::from)
+                .sum()
+        };
+
+        for megabytes in [1, 8] {
+            let lines = document(vocabulary, megabytes << 20);
+            let parameter = format!("{name}/{megabytes}MB");
+
+            assert_eq!(
+                sum(&lines, baseline),
+                sum(&lines, &|line| matcher.calibration_value(line)),
+                "the baseline and the automaton disagree on {parameter}"
+            );
+
+            group.throughput(Throughput::Bytes(
+                lines.iter().map(|line| line.len() as u64 + 1).sum(),
+            ));
+
+            group.bench_with_input(
+                BenchmarkId::new("match_indices", &parameter),
+                &lines,
+                |b, lines| b.iter(|| sum(lines, baseline)),
+            );
+
+            group.bench_with_input(
+                BenchmarkId::new("automaton", &parameter),
+                &lines,
+                |b, lines| b.iter(|| sum(lines, &|line| matcher.calibration_value(line))),
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, matcher);
+criterion_main!(benches);
@@ -0,0 +1,454 @@
+use std::{
+    fs::{read_to_string, File},
+    io::{self, BufReader, IsTerminal, Read, Write},
+    process::ExitCode,
+};
+
+use common::runner::{
+    EXIT_MISSING_INPUT, EXIT_OVERFLOW, EXIT_PARSE_FAILURE, EXIT_UNREADABLE_INPUT,
+};
+
+use crate::{
+    parse_with,
+    report::Report,
+    solve_part2_with,
+    stream::{calibration_sum, Progress, StreamError, StreamOptions},
+    Matcher, Overlap, Vocabulary,
+};
+
+const USAGE: &str =
+    "Usage: calibrate [--language NAME]... [--vocabulary FILE]... [--overlap MODE] [--report] \
+                     [--stream] [--threads N] [--chunk-size BYTES] [--progress] [--log FILTER] [-] \
+                     [FILE]...
+
+Sums the calibration values of each input in turn, reading digits spelled out in the words of
+every `--language` (english, english-extended, french, german, spanish, italian or dutch) and
+every `--vocabulary` file, or in English if given neither. A vocabulary file has a `word = value`
+pair per line, or `include = language` for a built-in set, with `#` starting a comment. Words
+worth more than 9 give their leading digit at the start of a line and their last at the end.
+`--overlap` is `overlapping` (the default, where every word counts), `leftmost-longest` or
+`leftmost-first` (where words are taken from left to right and cannot overlap). `--report` lists
+the first and last digit of every line with their byte offsets and whether each was written as a
+digit or a word, and why any line without them was rejected, before the sum. `--stream` sums
+inputs a chunk of lines at a time rather than loading them whole, stopping at the first line
+without digits, and `--threads` (which implies it) sums that many chunks at once.
+`--chunk-size` sets roughly how many bytes each chunk holds, 1 MiB by default, and `--progress`
+shows how much of each input has been summed on standard error. `-` reads standard input, as does
+passing no inputs when standard input is not a terminal.";
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Options {
+    languages: Vec<String>,
+    vocabularies: Vec<String>,
+    overlap: Overlap,
+    report: bool,
+    stream: Option<StreamOptions>,
+    progress: bool,
+    inputs: Vec<String>,
+    log: Option<String>,
+}
+
+fn parse_args(args: &[String], stdin_is_terminal: bool) -> Result<Options, String> {
+    let mut options = Options::default();
+
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .cloned()
+                .ok_or_else(|| format!("Missing value for `{arg}`"))
+        };
+
+        match arg.as_str() {
+            "--language" => options.languages.push(value()?),
+            "--vocabulary" => options.vocabularies.push(value()?),
+            "--overlap" => options.overlap = value()?.parse()?,
+            "--report" => options.report = true,
+            "--stream" => {
+                options.stream.get_or_insert_with(StreamOptions::default);
+            }
+            "--threads" => {
+                options
+                    .stream
+                    .get_or_insert_with(StreamOptions::default)
+                    .threads = positive(arg, &value()?)?;
+            }
+            "--chunk-size" => {
+                options
+                    .stream
+                    .get_or_insert_with(StreamOptions::default)
+                    .chunk_size = positive(arg, &value()?)?;
+            }
+            "--progress" => options.progress = true,
+            "--log" => options.log = Some(value()?),
+            "-" => options.inputs.push(arg.clone()),
+            option if option.starts_with('-') => return Err(format!("Unknown option `{option}`")),
+            path => options.inputs.push(path.to_string()),
+        }
+    }
+
+    if options.report && options.stream.is_some() {
+        return Err(String::from("`--report` cannot be combined with streaming"));
+    }
+
+    if options.progress && options.stream.is_none() {
+        return Err(String::from("`--progress` needs `--stream` or `--threads`"));
+    }
+
+    if options.inputs.is_empty() {
+        if stdin_is_terminal {
+            return Err(String::from("No input provided"));
+        }
+
+        options.inputs.push(String::from("-"));
+    }
+
+    Ok(options)
+}
+
+fn positive(option: &str, value: &str) -> Result<usize, String> {
+    value
+        .parse()
+        .ok()
+        .filter(|value| *value > 0)
+        .ok_or_else(|| format!("Invalid value `{value}` for `{option}`"))
+}
+
+/// Sums one input a chunk at a time, showing how far it has got on `err` if asked to.
+fn stream(
+    name: &str,
+    stdin: &mut dyn Read,
+    matcher: &Matcher,
+    options: StreamOptions,
+    progress: bool,
+    err: &mut dyn Write,
+) -> Result<String, (u8, String)> {
+    let mut show = |done: Progress| {
+        if progress {
+            let _ = write!(
+                err,
+                "\r{name}: {:.1} MiB, {} lines",
+                done.bytes as f64 / f64::from(1 << 20),
+                done.lines
+            );
+        }
+    };
+
+    let sum = if name == "-" {
+        calibration_sum(BufReader::new(stdin), matcher, options, &mut show)
+    } else {
+        File::open(name)
+            .map_err(StreamError::from)
+            .and_then(|file| calibration_sum(BufReader::new(file), matcher, options, &mut show))
+    };
+
+    if progress {
+        let _ = writeln!(err);
+    }
+
+    sum.map(|sum| sum.to_string()).map_err(|e| match e {
+        StreamError::Parse(_) => (
+            EXIT_PARSE_FAILURE,
+            format!("Could not parse input {name}: {e}"),
+        ),
+        StreamError::Overflow(_) => (EXIT_OVERFLOW, format!("Could not solve input {name}: {e}")),
+        StreamError::Io(_) | StreamError::InvalidUtf8 { .. } => (
+            EXIT_UNREADABLE_INPUT,
+            format!("Could not load input {name}: {e}"),
+        ),
+    })
+}
+
+/// Sums the calibration values of every input named in `args` with the vocabulary they ask for,
+/// returning the exit code of the first failure, or 0 if every input was summed.
+pub fn run_with(
+    args: &[String],
+    stdin: &mut dyn Read,
+    stdin_is_terminal: bool,
+    out: &mut dyn Write,
+    err: &mut dyn Write,
+) -> u8 {
+    let options = match parse_args(args, stdin_is_terminal) {
+        Ok(options) => options,
+        Err(e) => {
+            let _ = writeln!(err, "{e}\n\n{USAGE}");
+            return EXIT_MISSING_INPUT;
+        }
+    };
+
+    if let Err(e) = common::init_tracing(options.log.as_deref()) {
+        let _ = writeln!(err, "{e}\n\n{USAGE}");
+        return EXIT_MISSING_INPUT;
+    }
+
+    let vocabulary = match Vocabulary::load(&options.languages, &options.vocabularies) {
+        Ok(vocabulary) => vocabulary,
+        Err(e) => {
+            let _ = writeln!(err, "{e}");
+            return EXIT_MISSING_INPUT;
+        }
+    };
+
+    let matcher = Matcher::new(&vocabulary).with_overlap(options.overlap);
+
+    let labelled = options.inputs.len() > 1;
+
+    let mut exit_code = 0;
+
+    for name in &options.inputs {
+        let result = if let Some(stream_options) = options.stream {
+            stream(name, stdin, &matcher, stream_options, options.progress, err)
+        } else {
+            let input = if name == "-" {
+                let mut input = String::new();
+                stdin.read_to_string(&mut input).map(|_| input)
+            } else {
+                read_to_string(name)
+            };
+
+            match input {
+                Ok(input) if options.report => {
+                    let report = Report::new(&input, &matcher);
+
+                    report
+                        .sum()
+                        .map(|_| report.to_string())
+                        .map_err(|e| (EXIT_OVERFLOW, format!("Could not solve input {name}: {e}")))
+                }
+                Ok(input) => parse_with(&input, &vocabulary)
+                    .map_err(|e| {
+                        (
+                            EXIT_PARSE_FAILURE,
+                            format!("Could not parse input {name}: {e}"),
+                        )
+                    })
+                    .and_then(|lines| {
+                        solve_part2_with(&lines, &matcher)
+                            .map(|sum| sum.to_string())
+                            .map_err(|e| {
+                                (EXIT_OVERFLOW, format!("Could not solve input {name}: {e}"))
+                            })
+                    }),
+                Err(e) => Err((
+                    EXIT_UNREADABLE_INPUT,
+                    format!("Could not load input {name}: {e}"),
+                )),
+            }
+        };
+
+        match result {
+            Ok(report) if labelled && options.report => {
+                let _ = writeln!(out, "{name}:\n{report}\n");
+            }
+            Ok(sum) if labelled => {
+                let _ = writeln!(out, "{name}: {sum}");
+            }
+            Ok(output) => {
+                let _ = writeln!(out, "{output}");
+            }
+            Err((code, message)) => {
+                let _ = writeln!(err, "{message}");
+
+                if exit_code == 0 {
+                    exit_code = code;
+                }
+            }
+        }
+    }
+
+    exit_code
+}
+
+/// Entry point for the `calibrate` binary.
+pub fn run() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let stdin = io::stdin();
+    let stdin_is_terminal = stdin.is_terminal();
+
+    ExitCode::from(run_with(
+        &args,
+        &mut stdin.lock(),
+        stdin_is_terminal,
+        &mut io::stdout(),
+        &mut io::stderr(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(args: &[&str], stdin: &str) -> (u8, String, String) {
+        let args: Vec<String> = args.iter().map(ToString::to_string).collect();
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+
+        let code = run_with(&args, &mut stdin.as_bytes(), false, &mut out, &mut err);
+
+        (
+            code,
+            String::from_utf8(out).unwrap(),
+            String::from_utf8(err).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let args = |args: &[&str]| {
+            args.iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>()
+        };
+
+        assert_eq!(
+            parse_args(
+                &args(&["--language", "french", "--language", "german", "input"]),
+                true
+            ),
+            Ok(Options {
+                languages: vec![String::from("french"), String::from("german")],
+                inputs: vec![String::from("input")],
+                ..Options::default()
+            })
+        );
+
+        assert_eq!(
+            parse_args(&args(&["--vocabulary", "words"]), false),
+            Ok(Options {
+                vocabularies: vec![String::from("words")],
+                inputs: vec![String::from("-")],
+                ..Options::default()
+            })
+        );
+
+        assert_eq!(
+            parse_args(&args(&["--overlap", "leftmost-longest", "-"]), true).map(|o| o.overlap),
+            Ok(Overlap::LeftmostLongest)
+        );
+
+        assert_eq!(
+            parse_args(&args(&["--threads", "4", "--progress", "-"]), true),
+            Ok(Options {
+                stream: Some(StreamOptions {
+                    threads: 4,
+                    ..StreamOptions::default()
+                }),
+                progress: true,
+                inputs: vec![String::from("-")],
+                ..Options::default()
+            })
+        );
+
+        assert_eq!(
+            parse_args(&args(&["--stream"]), false).map(|o| o.stream),
+            Ok(Some(StreamOptions::default()))
+        );
+
+        assert!(parse_args(&args(&["--threads", "0"]), false).is_err());
+        assert!(parse_args(&args(&["--chunk-size", "big"]), false).is_err());
+        assert!(parse_args(&args(&["--stream", "--report"]), false).is_err());
+        assert!(parse_args(&args(&["--progress"]), false).is_err());
+        assert!(parse_args(&args(&["--overlap", "longest"]), false).is_err());
+        assert!(parse_args(&args(&["--language"]), false).is_err());
+        assert!(parse_args(&args(&["--lang", "french"]), false).is_err());
+        assert!(parse_args(&[], true).is_err());
+    }
+
+    #[test]
+    fn test_run() {
+        assert_eq!(
+            run(&["-"], crate::EXAMPLE_PART2),
+            (0, String::from("281\n"), String::new())
+        );
+
+        assert_eq!(
+            run(&["--language", "german"], "drei1vier\nzweisieben"),
+            (0, String::from("61\n"), String::new())
+        );
+
+        let (code, out, err) = run(&["--language", "english"], "drei1vier\nzweisieben");
+
+        assert_eq!((code, out.as_str()), (EXIT_PARSE_FAILURE, ""));
+        assert_eq!(
+            err,
+            "Could not parse input -: line 2, column 1: no digits in `zweisieben`\n"
+        );
+
+        assert_eq!(
+            run(&["--language", "english-extended"], "twentyone\nxeightwo"),
+            (0, String::from("103\n"), String::new())
+        );
+
+        assert_eq!(
+            run(
+                &[
+                    "--language",
+                    "english-extended",
+                    "--overlap",
+                    "leftmost-longest"
+                ],
+                "twentyone\nxeightwo"
+            ),
+            (0, String::from("109\n"), String::new())
+        );
+
+        // The report lists lines the sum would reject as unparseable.
+        assert_eq!(
+            run(&["--report"], "two1\nzero\n"),
+            (
+                0,
+                String::from(
+                    "Line  Value  First                  Last
+   1     21  2, word `two` at 0..3  1, digit at 3..4
+   2      -  rejected: no digits or number words
+Sum 21 from 1 of 2 lines, 1 rejected
+"
+                ),
+                String::new()
+            )
+        );
+
+        // Streaming rejects a line without digits just as summing the whole input does.
+        let rejected = (
+            EXIT_PARSE_FAILURE,
+            String::new(),
+            String::from("Could not parse input -: line 3, column 1: no digits in `null`\n"),
+        );
+
+        for args in [
+            &["--language", "german"][..],
+            &["--stream", "--language", "german"],
+            &[
+                "--threads",
+                "2",
+                "--chunk-size",
+                "8",
+                "--language",
+                "german",
+            ],
+        ] {
+            assert_eq!(run(args, "drei1vier\nzweisieben\nnull\n12\n"), rejected);
+        }
+
+        assert_eq!(
+            run(&["--stream", "--progress"], "two1\n"),
+            (
+                0,
+                String::from("21\n"),
+                String::from("\r-: 0.0 MiB, 1 lines\n")
+            )
+        );
+
+        let (code, out, err) = run(&["--stream", "missing-input"], "");
+
+        assert_eq!((code, out.as_str()), (EXIT_UNREADABLE_INPUT, ""));
+        assert!(err.starts_with("Could not load input missing-input: "));
+
+        let (code, _, err) = run(&["--language", "klingon"], "1");
+
+        assert_eq!(code, EXIT_MISSING_INPUT);
+        assert!(err.starts_with("Unknown language `klingon`"));
+    }
+}
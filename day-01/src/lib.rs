@@ -0,0 +1,165 @@
+pub mod calibrate;
+pub mod matcher;
+mod part1;
+mod part2;
+pub mod report;
+pub mod stream;
+pub mod vocabulary;
+
+use common::{checked, Overflow, Position, Solution};
+
+use std::{error::Error, fmt};
+use tracing::trace;
+
+pub const EXAMPLE_PART1: &str = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+pub const EXAMPLE_PART2: &str = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+pub use matcher::{Matcher, Overlap};
+pub use vocabulary::Vocabulary;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    NoDigits(Position),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoDigits(position) => {
+                write!(f, "{position}: no digits in `{}`", position.text)
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    parse_with(input, &Vocabulary::english())
+}
+
+/// Like `parse`, but also accepts lines whose only digits are spelled out in `vocabulary`.
+pub fn parse_with(input: &str, vocabulary: &Vocabulary) -> Result<Vec<String>, ParseError> {
+    common::lines(input)
+        .map(|line| {
+            let has_digits =
+                line.text.chars().any(char::is_numeric) || vocabulary.appears_in(line.text);
+
+            if has_digits {
+                Ok(line.text.to_string())
+            } else {
+                Err(ParseError::NoDigits(line.whole()))
+            }
+        })
+        .collect()
+}
+
+fn calculate_calibration_values_sum(
+    lines: &[String],
+    number_from_string: impl Fn(&str) -> Option<u32>,
+) -> Result<u64, Overflow> {
+    checked::sum(lines.iter().filter_map(|line| {
+        trace!(%line, "Parsing");
+        let result = number_from_string(line);
+
+        trace!(?result, "Got");
+
+        result.map(u64::from)
+    }))
+}
+
+pub fn solve_part1(lines: &[String]) -> Result<u64, Overflow> {
+    calculate_calibration_values_sum(lines, |line| part1::number_from_string(line).ok())
+}
+
+pub fn solve_part2(lines: &[String]) -> Result<u64, Overflow> {
+    solve_part2_with(lines, &Matcher::default())
+}
+
+/// Part two with the digits and words found by `matcher`, which sets the vocabulary and how
+/// overlapping words count.
+pub fn solve_part2_with(lines: &[String], matcher: &Matcher) -> Result<u64, Overflow> {
+    calculate_calibration_values_sum(lines, |line| part2::number_from_string(line, matcher))
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<String>;
+    type Error = ParseError;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    const DAY: u8 = 1;
+
+    const EXAMPLE_PART1: &'static str = EXAMPLE_PART1;
+    const EXAMPLE_PART2: &'static str = EXAMPLE_PART2;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Overflow> {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Overflow> {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_error() {
+        let lines = "1abc2
+xyz
+treb7uchet";
+
+        let result = parse(lines);
+
+        assert_eq!(
+            result,
+            Err(ParseError::NoDigits(Position {
+                line: 2,
+                column: 1,
+                text: String::from("xyz"),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_calibration_values_sum_does_not_wrap() {
+        let lines = vec![String::from("9"); 3];
+
+        assert_eq!(
+            calculate_calibration_values_sum(&lines, |_| Some(u32::MAX)),
+            Ok(3 * u64::from(u32::MAX))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_never_panics(
+            input in prop_oneof![
+                any::<String>(),
+                r"([a-z0-9]|one|two|three|four|five|six|seven|eight|nine|\n){0,40}",
+            ]
+        ) {
+            let _ = parse(&input);
+        }
+    }
+}
@@ -0,0 +1,641 @@
+use std::{cmp::Reverse, collections::VecDeque, fmt, str::FromStr};
+
+use crate::Vocabulary;
+
+/// Names accepted by `Overlap::from_str`, in the order of the variants.
+pub const OVERLAPS: [&str; 3] = ["overlapping", "leftmost-longest", "leftmost-first"];
+
+/// Which digits and words count where they overlap or start at the same place.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Overlap {
+    /// Every word counts, so `eightwo` is eight then two, as in the puzzle.
+    #[default]
+    Overlapping,
+    /// Words are taken from left to right without overlapping, the longest where several start
+    /// at the same place, so `eightwo` is just eight and `twentyone` is 21 rather than 20.
+    LeftmostLongest,
+    /// Like `LeftmostLongest`, except that where several words start at the same place the one
+    /// added to the vocabulary first wins.
+    LeftmostFirst,
+}
+
+impl fmt::Display for Overlap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(OVERLAPS[*self as usize])
+    }
+}
+
+impl FromStr for Overlap {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "overlapping" => Ok(Self::Overlapping),
+            "leftmost-longest" => Ok(Self::LeftmostLongest),
+            "leftmost-first" => Ok(Self::LeftmostFirst),
+            _ => Err(format!(
+                "Unknown overlap `{s}`, expected one of {}",
+                OVERLAPS.join(", ")
+            )),
+        }
+    }
+}
+
+/// A digit or spelled-out word found in a line, with the byte offsets it spans.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Match {
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+}
+
+impl Match {
+    /// The leading digit of the value, as when the match is the first in its line.
+    pub const fn first_digit(&self) -> u32 {
+        let mut value = self.value;
+
+        while value >= 10 {
+            value /= 10;
+        }
+
+        value
+    }
+
+    /// The trailing digit of the value, as when the match is the last in its line.
+    pub const fn last_digit(&self) -> u32 {
+        self.value % 10
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Output {
+    length: usize,
+    value: u32,
+    /// Where the pattern came in the vocabulary, for `Overlap::LeftmostFirst`.
+    priority: usize,
+}
+
+/// An Aho–Corasick automaton with every transition filled in, so each byte of the text costs a
+/// single table lookup. Bytes that appear in no pattern share one class to keep the table small.
+#[derive(Clone, Debug)]
+struct Automaton {
+    classes: [u8; 256],
+    class_count: usize,
+    transitions: Vec<u32>,
+    /// Every pattern ending at each state, longest first, including those reached through
+    /// failure links.
+    outputs: Vec<Vec<Output>>,
+}
+
+impl Automaton {
+    fn new(patterns: &[(Vec<u8>, u32)]) -> Self {
+        let mut classes = [0; 256];
+        let mut class_count = 1;
+
+        for byte in patterns.iter().flat_map(|(pattern, _)| pattern) {
+            if classes[usize::from(*byte)] == 0 {
+                classes[usize::from(*byte)] =
+                    u8::try_from(class_count).expect("at most 255 distinct bytes");
+                class_count += 1;
+            }
+        }
+
+        let mut automaton = Self {
+            classes,
+            class_count,
+            transitions: vec![u32::MAX; class_count],
+            outputs: vec![Vec::default()],
+        };
+
+        for (priority, (pattern, value)) in patterns.iter().enumerate() {
+            let mut state = 0;
+
+            for byte in pattern {
+                let index = automaton.index(state, *byte);
+
+                if automaton.transitions[index] == u32::MAX {
+                    let next = u32::try_from(automaton.outputs.len()).expect("too many states");
+
+                    automaton.transitions[index] = next;
+                    automaton
+                        .transitions
+                        .extend(std::iter::repeat_n(u32::MAX, class_count));
+                    automaton.outputs.push(Vec::default());
+                }
+
+                state = automaton.transitions[index];
+            }
+
+            automaton.outputs[state as usize].push(Output {
+                length: pattern.len(),
+                value: *value,
+                priority,
+            });
+        }
+
+        // Breadth first, so every state's failure link is complete before its children need it.
+        let mut failures = vec![0; automaton.outputs.len()];
+        let mut queue = VecDeque::from([0]);
+
+        while let Some(state) = queue.pop_front() {
+            let offset = state as usize * class_count;
+
+            for class in 0..class_count {
+                let child = automaton.transitions[offset + class];
+                let fallback = if state == 0 {
+                    0
+                } else {
+                    automaton.transitions[failures[state as usize] as usize * class_count + class]
+                };
+
+                if child == u32::MAX {
+                    automaton.transitions[offset + class] = fallback;
+                } else {
+                    failures[child as usize] = fallback;
+
+                    let inherited = automaton.outputs[fallback as usize].clone();
+                    let outputs = &mut automaton.outputs[child as usize];
+
+                    outputs.extend(inherited);
+                    outputs.sort_by_key(|output| Reverse(output.length));
+
+                    queue.push_back(child);
+                }
+            }
+        }
+
+        automaton
+    }
+
+    fn index(&self, state: u32, byte: u8) -> usize {
+        state as usize * self.class_count + usize::from(self.classes[usize::from(byte)])
+    }
+
+    fn next(&self, state: u32, byte: u8) -> u32 {
+        self.transitions[self.index(state, byte)]
+    }
+
+    fn outputs(&self, state: u32) -> &[Output] {
+        &self.outputs[state as usize]
+    }
+}
+
+/// Finds the digits `1` to `9` and the words of a vocabulary in a line in one pass, built once
+/// and reused for every line. The first match is the one starting earliest and the last the one
+/// starting latest among those that count under the matcher's `Overlap`. Where several start at
+/// the same place, the longest wins, or for `Overlap::LeftmostFirst` the first in the vocabulary.
+#[derive(Clone, Debug)]
+pub struct Matcher {
+    forward: Automaton,
+    /// Matches the patterns reversed, to scan lines from the end.
+    backward: Automaton,
+    longest: usize,
+    overlap: Overlap,
+}
+
+impl Default for Matcher {
+    fn default() -> Self {
+        Self::new(&Vocabulary::english())
+    }
+}
+
+impl Matcher {
+    pub fn new(vocabulary: &Vocabulary) -> Self {
+        let patterns: Vec<(Vec<u8>, u32)> = (1..=9)
+            .map(|digit| (vec![b'0' + digit], u32::from(digit)))
+            .chain(
+                vocabulary
+                    .words()
+                    .map(|(word, value)| (word.as_bytes().to_vec(), value)),
+            )
+            .collect();
+
+        let reversed: Vec<(Vec<u8>, u32)> = patterns
+            .iter()
+            .map(|(pattern, value)| (pattern.iter().rev().copied().collect(), *value))
+            .collect();
+
+        Self {
+            forward: Automaton::new(&patterns),
+            backward: Automaton::new(&reversed),
+            longest: patterns
+                .iter()
+                .map(|(pattern, _)| pattern.len())
+                .max()
+                .unwrap_or(1),
+            overlap: Overlap::default(),
+        }
+    }
+
+    #[must_use]
+    pub const fn with_overlap(mut self, overlap: Overlap) -> Self {
+        self.overlap = overlap;
+        self
+    }
+
+    pub const fn overlap(&self) -> Overlap {
+        self.overlap
+    }
+
+    /// The match starting nearest to `from`, scanning forwards and stopping once nothing still to
+    /// come could start any earlier.
+    fn leftmost(&self, text: &[u8], from: usize) -> Option<Match> {
+        let mut state = 0;
+        let mut best: Option<(Match, usize)> = None;
+
+        for (index, byte) in text.iter().enumerate().skip(from) {
+            if best.is_some_and(|(best, _)| index >= best.start + self.longest) {
+                break;
+            }
+
+            state = self.forward.next(state, *byte);
+
+            for output in self.forward.outputs(state) {
+                let start = index + 1 - output.length;
+
+                let wins = best.is_none_or(|(best, priority)| {
+                    start < best.start
+                        || start == best.start
+                            && match self.overlap {
+                                Overlap::LeftmostFirst => output.priority < priority,
+                                _ => output.length > best.end - best.start,
+                            }
+                });
+
+                if wins {
+                    best = Some((
+                        Match {
+                            start,
+                            end: index + 1,
+                            value: output.value,
+                        },
+                        output.priority,
+                    ));
+                }
+            }
+        }
+
+        best.map(|(best, _)| best)
+    }
+
+    pub fn first(&self, text: &str) -> Option<Match> {
+        self.leftmost(text.as_bytes(), 0)
+    }
+
+    /// Scans backwards when every match counts, as the first one found from the end is then the
+    /// last. Otherwise which words count depends on those before them, so the whole line is
+    /// scanned forwards.
+    pub fn last(&self, text: &str) -> Option<Match> {
+        if self.overlap != Overlap::Overlapping {
+            return self.leftmost_matches(text).last();
+        }
+
+        let mut state = 0;
+
+        for (index, byte) in text.bytes().enumerate().rev() {
+            state = self.backward.next(state, byte);
+
+            if let Some(output) = self.backward.outputs(state).first() {
+                return Some(Match {
+                    start: index,
+                    end: index + output.length,
+                    value: output.value,
+                });
+            }
+        }
+
+        None
+    }
+
+    /// The leading digit of the first match and the trailing digit of the last as a two-digit
+    /// number.
+    pub fn calibration_value(&self, text: &str) -> Option<u32> {
+        let first = self.first(text)?;
+        let last = self.last(text)?;
+
+        Some(first.first_digit() * 10 + last.last_digit())
+    }
+
+    /// Every digit and word in `text`, overlapping or not, in the order they end.
+    pub fn find_overlapping<'a>(&'a self, text: &'a str) -> impl Iterator<Item = Match> + 'a {
+        text.bytes()
+            .enumerate()
+            .scan(0, |state, (index, byte)| {
+                *state = self.forward.next(*state, byte);
+                Some((index, *state))
+            })
+            .flat_map(move |(index, state)| {
+                self.forward.outputs(state).iter().map(move |output| Match {
+                    start: index + 1 - output.length,
+                    end: index + 1,
+                    value: output.value,
+                })
+            })
+    }
+
+    fn leftmost_matches<'a>(&'a self, text: &'a str) -> impl Iterator<Item = Match> + 'a {
+        std::iter::successors(self.first(text), |previous| {
+            self.leftmost(text.as_bytes(), previous.end)
+        })
+    }
+
+    /// Every digit and word in `text` that counts under the matcher's `Overlap`, in the order
+    /// they start.
+    pub fn matches(&self, text: &str) -> Vec<Match> {
+        if self.overlap != Overlap::Overlapping {
+            return self.leftmost_matches(text).collect();
+        }
+
+        let mut matches: Vec<Match> = self.find_overlapping(text).collect();
+
+        matches.sort_by_key(|found| (found.start, Reverse(found.end)));
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn vocabulary(languages: &[&str]) -> Vocabulary {
+        let mut vocabulary = Vocabulary::empty();
+
+        for language in languages {
+            vocabulary
+                .extend(&Vocabulary::builtin(language).unwrap())
+                .unwrap();
+        }
+
+        vocabulary
+    }
+
+    fn values(matcher: &Matcher, text: &str) -> Vec<u32> {
+        matcher
+            .matches(text)
+            .iter()
+            .map(|found| found.value)
+            .collect()
+    }
+
+    /// Every word and digit found by brute force, for checking the automaton against.
+    fn brute_force(text: &str, vocabulary: &Vocabulary) -> Vec<Match> {
+        let digits: Vec<(String, u32)> = (1..=9).map(|value| (value.to_string(), value)).collect();
+
+        let mut matches: Vec<Match> = digits
+            .iter()
+            .map(|(digit, value)| (digit.as_str(), *value))
+            .chain(vocabulary.words())
+            .flat_map(|(word, value)| {
+                (0..text.len())
+                    .filter(move |start| text.as_bytes()[*start..].starts_with(word.as_bytes()))
+                    .map(move |start| Match {
+                        start,
+                        end: start + word.len(),
+                        value,
+                    })
+            })
+            .collect();
+
+        matches.sort_by_key(|found| (found.start, Reverse(found.end)));
+        matches
+    }
+
+    /// The matches left when taking the longest from left to right, by brute force.
+    fn brute_force_leftmost_longest(text: &str, vocabulary: &Vocabulary) -> Vec<Match> {
+        let mut end = 0;
+
+        brute_force(text, vocabulary)
+            .into_iter()
+            .filter(|found| {
+                let counts = found.start >= end;
+
+                if counts {
+                    end = found.end;
+                }
+
+                counts
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_first_and_last() {
+        let matcher = Matcher::default();
+
+        assert_eq!(
+            matcher.first("xtwone3four"),
+            Some(Match {
+                start: 1,
+                end: 4,
+                value: 2
+            })
+        );
+        assert_eq!(
+            matcher.last("xtwone3four"),
+            Some(Match {
+                start: 7,
+                end: 11,
+                value: 4
+            })
+        );
+
+        // The words overlap, and both count.
+        assert_eq!(matcher.first("eightwo").map(|found| found.value), Some(8));
+        assert_eq!(matcher.last("eightwo").map(|found| found.value), Some(2));
+
+        assert_eq!(matcher.first("zero"), None);
+        assert_eq!(matcher.last(""), None);
+    }
+
+    #[test]
+    fn test_calibration_value() {
+        let matcher = Matcher::default();
+
+        let values: Vec<Option<u32>> = crate::EXAMPLE_PART2
+            .lines()
+            .map(|line| matcher.calibration_value(line))
+            .collect();
+
+        assert_eq!(values, [29, 83, 13, 24, 42, 14, 76].map(Some).to_vec());
+
+        assert_eq!(matcher.calibration_value("0abc"), None);
+    }
+
+    #[test]
+    fn test_word_inside_a_longer_word() {
+        // The later ending `neun` starts before the French `un` found inside it.
+        let matcher = Matcher::new(&vocabulary(&["french", "german"]));
+
+        assert_eq!(
+            matcher.first("neun"),
+            Some(Match {
+                start: 0,
+                end: 4,
+                value: 9
+            })
+        );
+        assert_eq!(
+            matcher.last("neun"),
+            Some(Match {
+                start: 2,
+                end: 4,
+                value: 1
+            })
+        );
+
+        // Where two words start together, the longest counts.
+        let matcher = Matcher::new(&vocabulary(&["spanish", "french"]));
+
+        assert_eq!(
+            matcher.last("xuno").map(|found| (found.start, found.end)),
+            Some((1, 4))
+        );
+    }
+
+    #[test]
+    fn test_overlap() {
+        let vocabulary = vocabulary(&["english", "french", "german"]);
+        let matcher = |overlap| Matcher::new(&vocabulary).with_overlap(overlap);
+
+        let overlapping = matcher(Overlap::Overlapping);
+        let longest = matcher(Overlap::LeftmostLongest);
+
+        assert_eq!(values(&overlapping, "eightwone"), vec![8, 2, 1]);
+        assert_eq!(values(&longest, "eightwone"), vec![8, 1]);
+        assert_eq!(longest.calibration_value("eightwo"), Some(88));
+
+        assert_eq!(values(&overlapping, "neun"), vec![9, 1]);
+        assert_eq!(values(&longest, "neun"), vec![9]);
+        assert_eq!(longest.calibration_value("xneuny"), Some(99));
+
+        // Spanish `uno` comes before French `un` here, and the other way round below.
+        let spanish_first = Matcher::new(&self::vocabulary(&["spanish", "french"]))
+            .with_overlap(Overlap::LeftmostFirst);
+        let french_first = Matcher::new(&self::vocabulary(&["french", "spanish"]))
+            .with_overlap(Overlap::LeftmostFirst);
+
+        assert_eq!(
+            spanish_first.matches("unodos"),
+            vec![
+                Match {
+                    start: 0,
+                    end: 3,
+                    value: 1
+                },
+                Match {
+                    start: 3,
+                    end: 6,
+                    value: 2
+                }
+            ]
+        );
+        assert_eq!(
+            french_first
+                .matches("unodos")
+                .first()
+                .map(|found| found.end),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_overlap_names() {
+        for (name, overlap) in OVERLAPS.iter().zip([
+            Overlap::Overlapping,
+            Overlap::LeftmostLongest,
+            Overlap::LeftmostFirst,
+        ]) {
+            assert_eq!(name.parse(), Ok(overlap));
+            assert_eq!(overlap.to_string(), *name);
+        }
+
+        assert!("longest".parse::<Overlap>().is_err());
+    }
+
+    #[test]
+    fn test_extended_words() {
+        let extended = Matcher::new(&Vocabulary::english_extended());
+
+        // `twentyone` also holds `twenty` and `one`: its leading digit counts at the start of a
+        // line, and the `one` inside it at the end.
+        assert_eq!(extended.calibration_value("twentyone"), Some(21));
+        assert_eq!(extended.calibration_value("5twentyx"), Some(50));
+        assert_eq!(extended.calibration_value("seventeenx"), Some(17));
+        assert_eq!(extended.calibration_value("zero3"), Some(3));
+        assert_eq!(extended.calibration_value("twelve"), Some(12));
+        assert_eq!(extended.calibration_value("xthirtyfive"), Some(35));
+
+        // `eighteen` and `eight` start together, so the longer counts even at the end.
+        assert_eq!(extended.calibration_value("1eighteen"), Some(18));
+
+        let longest = extended.clone().with_overlap(Overlap::LeftmostLongest);
+
+        assert_eq!(values(&longest, "twentyoneightwo"), vec![21, 2]);
+
+        // With the tens listed before their compounds, `twenty` wins and `one` follows it.
+        let first = extended.with_overlap(Overlap::LeftmostFirst);
+
+        assert_eq!(values(&first, "twentyoneightwo"), vec![20, 1, 2]);
+    }
+
+    #[test]
+    fn test_matches() {
+        let matcher = Matcher::default();
+
+        assert_eq!(values(&matcher, "eightwothree"), vec![8, 2, 3]);
+        assert_eq!(values(&matcher, "a1sixsix"), vec![1, 6, 6]);
+        assert_eq!(values(&matcher, "qwsdsixsixabx"), vec![6, 6]);
+    }
+
+    #[test]
+    fn test_matches_in_other_languages() {
+        let german = Matcher::new(&vocabulary(&["german"]));
+
+        assert_eq!(values(&german, "zweiundvierzig"), vec![2, 4]);
+        assert_eq!(values(&german, "fünfsechs"), vec![5, 6]);
+
+        // Only words in the vocabulary count.
+        assert!(values(&Matcher::default(), "zweiundvierzig").is_empty());
+
+        let matcher = Matcher::new(&vocabulary(&["english", "french", "german"]));
+
+        // `neun` contains the French `un`, just as `eightwo` runs eight into two.
+        assert_eq!(values(&matcher, "neun"), vec![9, 1]);
+
+        // `six` is the same word in English and French, and counts once.
+        assert_eq!(values(&matcher, "sixtrois"), vec![6, 3]);
+
+        // Spanish `uno` and French `un` both start the text, and agree.
+        let matcher = Matcher::new(&vocabulary(&["spanish", "french"]));
+
+        assert_eq!(values(&matcher, "unocho"), vec![1, 1, 8]);
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_brute_force(
+            text in r"([a-z1-9]|un|uno|neun|eins|sechs|seis|sei|fünf|eightwo|oneight|twentyone){0,30}",
+        ) {
+            let vocabulary = vocabulary(&["english-extended", "french", "german", "spanish"]);
+            let matcher = Matcher::new(&vocabulary);
+            let expected = brute_force(&text, &vocabulary);
+
+            let mut found: Vec<Match> = matcher.find_overlapping(&text).collect();
+            found.sort_by_key(|found| (found.start, Reverse(found.end)));
+
+            prop_assert_eq!(&found, &expected);
+            prop_assert_eq!(matcher.first(&text), expected.first().copied());
+            prop_assert_eq!(
+                matcher.last(&text),
+                expected.iter().copied().max_by_key(|found| (found.start, found.end))
+            );
+
+            let longest = matcher.with_overlap(Overlap::LeftmostLongest);
+            let expected = brute_force_leftmost_longest(&text, &vocabulary);
+
+            prop_assert_eq!(longest.matches(&text), expected.clone());
+            prop_assert_eq!(longest.last(&text), expected.last().copied());
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use std::num::ParseIntError;
+
+pub(crate) fn number_from_string(source: &str) -> Result<u32, ParseIntError> {
+    let numeric_digits: Vec<char> = source.chars().filter(|char| char.is_numeric()).collect();
+
+    let mut number_string = String::default();
+
+    if let Some(first_digit) = numeric_digits.first() {
+        number_string.push(*first_digit);
+    }
+
+    if let Some(last_digit) = numeric_digits.last() {
+        number_string.push(*last_digit);
+    }
+
+    number_string.parse::<u32>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part1, EXAMPLE_PART1};
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART1;
+
+        let result = solve_part1(&parse(lines).unwrap());
+
+        assert_eq!(result, Ok(142));
+    }
+
+    #[test]
+    fn test_number_from_string() {
+        let lines = ["1abc2", "pqr3stu8vwx", "a1b2c3d4e5f", "treb7uchet"];
+
+        let expected = vec![12, 38, 15, 77];
+
+        let result: Vec<u32> = lines
+            .iter()
+            .filter_map(|line| number_from_string(line).ok())
+            .collect();
+
+        assert_eq!(expected, result);
+    }
+}
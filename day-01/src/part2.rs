@@ -0,0 +1,95 @@
+use crate::Matcher;
+
+use tracing::trace;
+
+pub(crate) fn number_from_string(source: &str, matcher: &Matcher) -> Option<u32> {
+    let value = matcher.calibration_value(source);
+
+    trace!(?value);
+
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, parse_with, solve_part2, solve_part2_with, Vocabulary, EXAMPLE_PART2};
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART2;
+
+        let result = solve_part2(&parse(lines).unwrap());
+
+        assert_eq!(result, Ok(281));
+    }
+
+    #[test]
+    fn test_number_from_string() {
+        let lines = [
+            "two1nine",
+            "eightwothree",
+            "abcone2threexyz",
+            "xtwone3four",
+            "4nineeightseven2",
+            "zoneight234",
+            "7pqrstsixteen",
+        ];
+
+        let expected = vec![29, 83, 13, 24, 42, 14, 76];
+
+        let result: Vec<u32> = lines
+            .iter()
+            .filter_map(|line| number_from_string(line, &Matcher::default()))
+            .collect();
+
+        assert_eq!(expected, result);
+    }
+
+    fn vocabulary(languages: &[&str]) -> Vocabulary {
+        let mut vocabulary = Vocabulary::empty();
+
+        for language in languages {
+            vocabulary
+                .extend(&Vocabulary::builtin(language).unwrap())
+                .unwrap();
+        }
+
+        vocabulary
+    }
+
+    #[test]
+    fn test_number_from_string_in_other_languages() {
+        let matcher = Matcher::new(&vocabulary(&["french", "spanish"]));
+
+        let lines = ["deux1neuf", "xcinq7", "tresdosuno", "ochox"];
+
+        let result: Vec<u32> = lines
+            .iter()
+            .filter_map(|line| number_from_string(line, &matcher))
+            .collect();
+
+        assert_eq!(result, vec![29, 57, 31, 88]);
+    }
+
+    #[test]
+    fn test_solve_with_vocabulary() {
+        let lines = "drei1vier
+zweisieben
+fünf";
+
+        let german = vocabulary(&["german"]);
+
+        assert!(parse(lines).is_err());
+        assert_eq!(
+            solve_part2_with(&parse_with(lines, &german).unwrap(), &Matcher::new(&german)),
+            Ok(34 + 27 + 55)
+        );
+
+        // English is the default.
+        assert_eq!(
+            solve_part2_with(&parse(EXAMPLE_PART2).unwrap(), &Matcher::default()),
+            Ok(281)
+        );
+    }
+}
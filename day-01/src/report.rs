@@ -0,0 +1,298 @@
+use std::fmt;
+
+use common::{checked, Overflow};
+
+use crate::{matcher::Match, Matcher};
+
+/// Whether a digit was written as a digit or spelled out, with the word it was spelled as.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    Digit,
+    Word(String),
+}
+
+/// The digit a line contributes from one end, and where it was found, in bytes from the start of
+/// the line, with `end` exclusive.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Found {
+    pub digit: u32,
+    pub start: usize,
+    pub end: usize,
+    pub source: Source,
+}
+
+impl Found {
+    fn new(text: &str, found: Match, digit: u32) -> Self {
+        let source =
+            if found.end - found.start == 1 && text.as_bytes()[found.start].is_ascii_digit() {
+                Source::Digit
+            } else {
+                Source::Word(text[found.start..found.end].to_string())
+            };
+
+        Self {
+            digit,
+            start: found.start,
+            end: found.end,
+            source,
+        }
+    }
+}
+
+impl fmt::Display for Found {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.source {
+            Source::Digit => write!(f, "{}, digit at {}..{}", self.digit, self.start, self.end),
+            Source::Word(word) => write!(
+                f,
+                "{}, word `{word}` at {}..{}",
+                self.digit, self.start, self.end
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Calibration {
+    pub first: Found,
+    pub last: Found,
+}
+
+impl Calibration {
+    pub const fn value(&self) -> u32 {
+        self.first.digit * 10 + self.last.digit
+    }
+}
+
+/// Why a line contributes nothing to the sum.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Rejection {
+    Empty,
+    NoDigits,
+    /// The line has digits, but only ones other than 1 to 9, such as `0`.
+    UncountedDigits(String),
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty line"),
+            Self::NoDigits => write!(f, "no digits or number words"),
+            Self::UncountedDigits(digits) => {
+                write!(f, "only digits that do not count: `{digits}`")
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LineReport {
+    pub line: usize,
+    pub result: Result<Calibration, Rejection>,
+}
+
+/// What each line of a calibration document contributes to the sum, or why it contributes
+/// nothing.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    pub lines: Vec<LineReport>,
+}
+
+impl Report {
+    pub fn new(input: &str, matcher: &Matcher) -> Self {
+        Self {
+            lines: common::lines(input)
+                .map(|line| LineReport {
+                    line: line.number,
+                    result: calibrate(line.text, matcher),
+                })
+                .collect(),
+        }
+    }
+
+    pub fn calibrations(&self) -> impl Iterator<Item = &Calibration> {
+        self.lines
+            .iter()
+            .filter_map(|line| line.result.as_ref().ok())
+    }
+
+    pub fn rejected(&self) -> impl Iterator<Item = (usize, &Rejection)> {
+        self.lines
+            .iter()
+            .filter_map(|line| line.result.as_ref().err().map(|reason| (line.line, reason)))
+    }
+
+    /// The sum `solve_part2_with` gives with the same matcher, as rejected lines add nothing there
+    /// either.
+    pub fn sum(&self) -> Result<u64, Overflow> {
+        checked::sum(
+            self.calibrations()
+                .map(|calibration| u64::from(calibration.value())),
+        )
+    }
+}
+
+/// A line with its first and last digits, or the reason it has none.
+pub fn calibrate(text: &str, matcher: &Matcher) -> Result<Calibration, Rejection> {
+    let (Some(first), Some(last)) = (matcher.first(text), matcher.last(text)) else {
+        let uncounted: String = text.chars().filter(|c| c.is_numeric()).collect();
+
+        return Err(if text.is_empty() {
+            Rejection::Empty
+        } else if uncounted.is_empty() {
+            Rejection::NoDigits
+        } else {
+            Rejection::UncountedDigits(uncounted)
+        });
+    };
+
+    Ok(Calibration {
+        first: Found::new(text, first, first.first_digit()),
+        last: Found::new(text, last, last.last_digit()),
+    })
+}
+
+/// A line per line of the document, then the sum and how many lines were rejected.
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .lines
+            .last()
+            .map_or(1, |line| line.line.to_string().len())
+            .max("Line".len());
+
+        let first_width = self
+            .calibrations()
+            .map(|calibration| calibration.first.to_string().len())
+            .max()
+            .unwrap_or(0)
+            .max("First".len());
+
+        writeln!(
+            f,
+            "{:>width$}  Value  {:first_width$}  Last",
+            "Line", "First"
+        )?;
+
+        for line in &self.lines {
+            match &line.result {
+                Ok(calibration) => writeln!(
+                    f,
+                    "{:>width$}  {:>5}  {:first_width$}  {}",
+                    line.line,
+                    calibration.value(),
+                    calibration.first.to_string(),
+                    calibration.last
+                )?,
+                Err(reason) => writeln!(f, "{:>width$}      -  rejected: {reason}", line.line)?,
+            }
+        }
+
+        let rejected = self.rejected().count();
+
+        match self.sum() {
+            Ok(sum) => write!(f, "Sum {sum}")?,
+            Err(e) => write!(f, "No sum ({e})")?,
+        }
+
+        write!(
+            f,
+            " from {} of {} lines, {rejected} rejected",
+            self.lines.len() - rejected,
+            self.lines.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part2, Vocabulary, EXAMPLE_PART2};
+
+    #[test]
+    fn test_calibrate() {
+        let matcher = Matcher::default();
+
+        assert_eq!(
+            calibrate("xtwone3four", &matcher),
+            Ok(Calibration {
+                first: Found {
+                    digit: 2,
+                    start: 1,
+                    end: 4,
+                    source: Source::Word(String::from("two")),
+                },
+                last: Found {
+                    digit: 4,
+                    start: 7,
+                    end: 11,
+                    source: Source::Word(String::from("four")),
+                },
+            })
+        );
+
+        let calibration = calibrate("7pqrstsixteen", &matcher).unwrap();
+
+        assert_eq!(calibration.first.source, Source::Digit);
+        assert_eq!(calibration.first.to_string(), "7, digit at 0..1");
+        assert_eq!(calibration.last.to_string(), "6, word `six` at 6..9");
+        assert_eq!(calibration.value(), 76);
+
+        assert_eq!(calibrate("", &matcher), Err(Rejection::Empty));
+        assert_eq!(calibrate("zero", &matcher), Err(Rejection::NoDigits));
+        assert_eq!(
+            calibrate("a0b٣", &matcher),
+            Err(Rejection::UncountedDigits(String::from("0٣")))
+        );
+    }
+
+    #[test]
+    fn test_offsets_are_in_bytes() {
+        let matcher = Matcher::new(&Vocabulary::builtin("german").unwrap());
+
+        let calibration = calibrate("fünfxdrei", &matcher).unwrap();
+
+        assert_eq!((calibration.first.start, calibration.first.end), (0, 5));
+        assert_eq!((calibration.last.start, calibration.last.end), (6, 10));
+    }
+
+    #[test]
+    fn test_multi_digit_words() {
+        let matcher = Matcher::new(&Vocabulary::english_extended());
+
+        let calibration = calibrate("twentyone", &matcher).unwrap();
+
+        assert_eq!(calibration.first.to_string(), "2, word `twentyone` at 0..9");
+        assert_eq!(calibration.last.to_string(), "1, word `one` at 6..9");
+    }
+
+    #[test]
+    fn test_report() {
+        let report = Report::new(EXAMPLE_PART2, &Matcher::default());
+
+        assert_eq!(report.sum(), solve_part2(&parse(EXAMPLE_PART2).unwrap()));
+        assert_eq!(report.rejected().count(), 0);
+
+        let report = Report::new("1abc2\n\nzero\nnine0\n0", &Matcher::default());
+
+        assert_eq!(
+            report.rejected().collect::<Vec<_>>(),
+            vec![
+                (2, &Rejection::Empty),
+                (3, &Rejection::NoDigits),
+                (5, &Rejection::UncountedDigits(String::from("0")))
+            ]
+        );
+
+        assert_eq!(
+            report.to_string(),
+            "Line  Value  First                   Last
+   1     12  1, digit at 0..1        2, digit at 4..5
+   2      -  rejected: empty line
+   3      -  rejected: no digits or number words
+   4     99  9, word `nine` at 0..4  9, word `nine` at 0..4
+   5      -  rejected: only digits that do not count: `0`
+Sum 111 from 2 of 5 lines, 3 rejected"
+        );
+    }
+}
@@ -0,0 +1,405 @@
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt,
+    io::{self, BufRead},
+    sync::{
+        mpsc::{channel, sync_channel, Receiver, SyncSender},
+        Mutex, PoisonError,
+    },
+    thread,
+};
+
+use common::{checked::Checked, Line, Overflow};
+
+use crate::{Matcher, ParseError};
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    InvalidUtf8 { line: u64 },
+    Parse(ParseError),
+    Overflow(Overflow),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "{e}"),
+            Self::InvalidUtf8 { line } => write!(f, "line {line} is not valid UTF-8"),
+            Self::Parse(e) => write!(f, "{e}"),
+            Self::Overflow(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(e: ParseError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+impl From<Overflow> for StreamError {
+    fn from(e: Overflow) -> Self {
+        Self::Overflow(e)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StreamOptions {
+    /// Threads summing chunks, or 1 to sum them as they are read.
+    pub threads: usize,
+    /// Roughly how many bytes of whole lines each chunk holds.
+    pub chunk_size: usize,
+}
+
+impl Default for StreamOptions {
+    fn default() -> Self {
+        Self {
+            threads: 1,
+            chunk_size: 1 << 20,
+        }
+    }
+}
+
+/// How much of the input has been summed so far.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Progress {
+    pub bytes: u64,
+    pub lines: u64,
+}
+
+/// Whole lines read together, numbered from `first_line`.
+struct Chunk {
+    first_line: u64,
+    lines: u64,
+    bytes: Vec<u8>,
+}
+
+/// Reads whole lines until the chunk holds at least `chunk_size` bytes or the input ends. A line
+/// longer than that makes a chunk of its own.
+fn read_chunk(
+    reader: &mut impl BufRead,
+    first_line: u64,
+    chunk_size: usize,
+) -> io::Result<Option<Chunk>> {
+    let mut chunk = Chunk {
+        first_line,
+        lines: 0,
+        bytes: Vec::with_capacity(chunk_size),
+    };
+
+    while chunk.bytes.len() < chunk_size.max(1) && reader.read_until(b'\n', &mut chunk.bytes)? > 0 {
+        chunk.lines += 1;
+    }
+
+    Ok((chunk.lines > 0).then_some(chunk))
+}
+
+/// A chunk's sum with where it started and how many lines and bytes it covered.
+struct Summed {
+    first_line: u64,
+    sum: Result<u64, StreamError>,
+    lines: u64,
+    bytes: u64,
+}
+
+/// Checks and sums a chunk the same way as `parse_with` and `solve_part2_with`, which splitting
+/// the input on line breaks cannot change.
+fn summed(chunk: &Chunk, matcher: &Matcher) -> Summed {
+    Summed {
+        first_line: chunk.first_line,
+        sum: sum_chunk(chunk, matcher),
+        lines: chunk.lines,
+        bytes: chunk.bytes.len() as u64,
+    }
+}
+
+fn sum_chunk(chunk: &Chunk, matcher: &Matcher) -> Result<u64, StreamError> {
+    let text = std::str::from_utf8(&chunk.bytes).map_err(|e| StreamError::InvalidUtf8 {
+        line: chunk.first_line
+            + chunk.bytes[..e.valid_up_to()]
+                .iter()
+                .filter(|byte| **byte == b'\n')
+                .count() as u64,
+    })?;
+
+    let first_line = usize::try_from(chunk.first_line).unwrap_or(usize::MAX);
+    let mut sum = 0_u64;
+
+    // Rejects lines without digits as `parse_with` does, so a chunk either sums or fails at its
+    // first such line.
+    for (number, text) in (first_line..).zip(text.lines()) {
+        if !text.chars().any(char::is_numeric) && matcher.first(text).is_none() {
+            return Err(ParseError::NoDigits(Line::new(number, text).whole()).into());
+        }
+
+        if let Some(value) = matcher.calibration_value(text) {
+            sum = sum.try_add(u64::from(value))?;
+        }
+    }
+
+    Ok(sum)
+}
+
+/// Reads chunks onto the queue for the workers until the input ends, folding in the sums of any
+/// they have finished meanwhile.
+fn dispatch(
+    reader: &mut impl BufRead,
+    chunk_size: usize,
+    chunks: &SyncSender<Chunk>,
+    results: &Receiver<Summed>,
+    add: &mut impl FnMut(Summed) -> Result<(), StreamError>,
+) -> Result<(), StreamError> {
+    let mut next_line = 1;
+
+    while let Some(chunk) = read_chunk(reader, next_line, chunk_size)? {
+        next_line += chunk.lines;
+
+        if chunks.send(chunk).is_err() {
+            break;
+        }
+
+        results.try_iter().try_for_each(&mut *add)?;
+    }
+
+    Ok(())
+}
+
+/// Sums the calibration values of every line of `reader` without holding more than a chunk per
+/// thread in memory, calling `progress` after each chunk. The sum is the one `parse_with` and
+/// `solve_part2_with` give over the same lines: the first line without digits is reported as
+/// `ParseError::NoDigits`, and overflow is reported rather than wrapping. Either stops the sum.
+pub fn calibration_sum(
+    mut reader: impl BufRead,
+    matcher: &Matcher,
+    options: StreamOptions,
+    mut progress: impl FnMut(Progress),
+) -> Result<u64, StreamError> {
+    let mut total = 0_u64;
+    let mut done = Progress::default();
+
+    let mut add = |summed: Summed| -> Result<(), StreamError> {
+        total = total.try_add(summed.sum?)?;
+        done.lines += summed.lines;
+        done.bytes += summed.bytes;
+        progress(done);
+        Ok(())
+    };
+
+    if options.threads <= 1 {
+        let mut next_line = 1;
+
+        while let Some(chunk) = read_chunk(&mut reader, next_line, options.chunk_size)? {
+            next_line += chunk.lines;
+            add(summed(&chunk, matcher))?;
+        }
+
+        return Ok(total);
+    }
+
+    // Bounded, so reading never gets more than a chunk per thread ahead of summing.
+    let (chunks, queue) = sync_channel::<Chunk>(options.threads);
+    let queue = Mutex::new(queue);
+    let (sums, results) = channel::<Summed>();
+
+    // Workers can finish chunks out of order, so those read after one still being summed wait
+    // here. Adding them in order fails at the same line as summing them one after another.
+    let mut next_line = 1;
+    let mut waiting = BTreeMap::new();
+
+    let mut add_in_order = |summed: Summed| -> Result<(), StreamError> {
+        waiting.insert(summed.first_line, summed);
+
+        while let Some(summed) = waiting.remove(&next_line) {
+            next_line += summed.lines;
+            add(summed)?;
+        }
+
+        Ok(())
+    };
+
+    thread::scope(|scope| {
+        for _ in 0..options.threads {
+            let (queue, sums) = (&queue, sums.clone());
+
+            scope.spawn(move || loop {
+                // Taken in a statement of its own, so the lock is released before summing.
+                let next = queue.lock().unwrap_or_else(PoisonError::into_inner).recv();
+
+                let Ok(chunk) = next else {
+                    break;
+                };
+
+                if sums.send(summed(&chunk, matcher)).is_err() {
+                    break;
+                }
+            });
+        }
+
+        drop(sums);
+
+        let outcome = dispatch(
+            &mut reader,
+            options.chunk_size,
+            &chunks,
+            &results,
+            &mut add_in_order,
+        );
+
+        // Closing the queue lets the workers finish once it is empty.
+        drop(chunks);
+        outcome?;
+
+        results.iter().try_for_each(&mut add_in_order)
+    })?;
+
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_with, solve_part2_with, Overlap, Vocabulary, EXAMPLE_PART1, EXAMPLE_PART2};
+    use proptest::prelude::*;
+
+    /// The sum of the whole input loaded at once, to check streaming against.
+    fn in_memory(input: &str, vocabulary: &Vocabulary, matcher: &Matcher) -> Result<u64, String> {
+        let lines = parse_with(input, vocabulary).map_err(|e| e.to_string())?;
+
+        solve_part2_with(&lines, matcher).map_err(|e| e.to_string())
+    }
+
+    fn streamed(
+        input: &str,
+        matcher: &Matcher,
+        threads: usize,
+        chunk_size: usize,
+    ) -> Result<u64, String> {
+        calibration_sum(
+            input.as_bytes(),
+            matcher,
+            StreamOptions {
+                threads,
+                chunk_size,
+            },
+            |_| {},
+        )
+        .map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn test_calibration_sum() {
+        let matcher = Matcher::default();
+
+        for threads in [1, 2, 4] {
+            for chunk_size in [1, 10, 1 << 20] {
+                assert_eq!(
+                    streamed(EXAMPLE_PART1, &matcher, threads, chunk_size),
+                    Ok(142)
+                );
+                assert_eq!(
+                    streamed(EXAMPLE_PART2, &matcher, threads, chunk_size),
+                    Ok(281)
+                );
+            }
+        }
+
+        assert_eq!(streamed("", &matcher, 1, 10), Ok(0));
+        assert_eq!(streamed("", &matcher, 2, 10), Ok(0));
+    }
+
+    #[test]
+    fn test_no_digits() {
+        let input = format!("{EXAMPLE_PART2}\n\nno digits\n{EXAMPLE_PART1}\nnone\n");
+
+        // However the lines are split and summed, the first without digits is the one reported.
+        for threads in [1, 2, 4] {
+            for chunk_size in [1, 10, 1 << 20] {
+                assert_eq!(
+                    streamed(&input, &Matcher::default(), threads, chunk_size),
+                    Err(String::from("line 8, column 1: no digits in ``"))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_progress() {
+        let input = format!("{EXAMPLE_PART2}\r\nnine\n");
+        let mut updates = Vec::default();
+
+        let sum = calibration_sum(
+            input.as_bytes(),
+            &Matcher::default(),
+            StreamOptions {
+                threads: 1,
+                chunk_size: 16,
+            },
+            |progress| updates.push(progress),
+        );
+
+        assert_eq!(sum.ok(), Some(380));
+        assert!(updates.len() > 1);
+        assert!(updates.windows(2).all(|pair| pair[0].bytes < pair[1].bytes));
+        assert_eq!(
+            updates.last(),
+            Some(&Progress {
+                bytes: input.len() as u64,
+                lines: 8,
+            })
+        );
+    }
+
+    #[test]
+    fn test_invalid_utf8() {
+        let input = b"1abc2\npqr3stu8vwx\nx\xff1\n";
+
+        for threads in [1, 2] {
+            let error = calibration_sum(
+                &input[..],
+                &Matcher::default(),
+                StreamOptions {
+                    threads,
+                    chunk_size: 4,
+                },
+                |_| {},
+            )
+            .unwrap_err();
+
+            assert_eq!(error.to_string(), "line 3 is not valid UTF-8");
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_in_memory_sum(
+            input in r"(([a-z0-9]|one|two|eight|twentyone|seventeen|neun){1,12}\r?\n){0,40}",
+            threads in 1_usize..4,
+            chunk_size in 1_usize..64,
+            overlap in prop_oneof![
+                Just(Overlap::Overlapping),
+                Just(Overlap::LeftmostLongest),
+                Just(Overlap::LeftmostFirst),
+            ],
+        ) {
+            let mut vocabulary = Vocabulary::english_extended();
+            vocabulary.extend(&Vocabulary::builtin("german").unwrap()).unwrap();
+
+            let matcher = Matcher::new(&vocabulary).with_overlap(overlap);
+
+            prop_assert_eq!(
+                streamed(&input, &matcher, threads, chunk_size),
+                in_memory(&input, &vocabulary, &matcher)
+            );
+        }
+    }
+}
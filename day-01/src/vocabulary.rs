@@ -0,0 +1,447 @@
+use std::{error::Error, fmt, fs::read_to_string, path::Path};
+
+use common::Position;
+
+/// Languages with a built-in set of words for the digits one to nine, plus `english-extended`,
+/// which adds zero, the teens, the tens and compounds such as `twentyone` up to 99.
+pub const LANGUAGES: [&str; 7] = [
+    "english",
+    "english-extended",
+    "french",
+    "german",
+    "spanish",
+    "italian",
+    "dutch",
+];
+
+const ONES: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+const TEENS: [&str; 10] = [
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 8] = [
+    "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+const fn builtin_words(language: &str) -> Option<[&'static str; 9]> {
+    Some(match language.as_bytes() {
+        b"english" => ONES,
+        b"french" => [
+            "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+        ],
+        b"german" => [
+            "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+        ],
+        b"spanish" => [
+            "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+        ],
+        b"italian" => [
+            "uno", "due", "tre", "quattro", "cinque", "sei", "sette", "otto", "nove",
+        ],
+        b"dutch" => [
+            "een", "twee", "drie", "vier", "vijf", "zes", "zeven", "acht", "negen",
+        ],
+        _ => return None,
+    })
+}
+
+/// A word that would stand for two different values.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Conflict {
+    pub word: String,
+    pub existing: u32,
+    pub new: u32,
+}
+
+impl fmt::Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` already stands for {}, not {}",
+            self.word, self.existing, self.new
+        )
+    }
+}
+
+impl Error for Conflict {}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum VocabularyError {
+    InvalidLine(Position),
+    UnknownLanguage(Position),
+    InvalidWord(Position),
+    InvalidValue(Position),
+    ConflictingWord(Position, Conflict),
+}
+
+impl fmt::Display for VocabularyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLine(position) => write!(
+                f,
+                "{position}: expected `word = value` or `include = language`, got `{}`",
+                position.text
+            ),
+            Self::UnknownLanguage(position) => write!(
+                f,
+                "{position}: unknown language `{}`, expected one of {}",
+                position.text,
+                LANGUAGES.join(", ")
+            ),
+            Self::InvalidWord(position) => write!(
+                f,
+                "{position}: invalid word `{}`, words cannot be empty or contain digits",
+                position.text
+            ),
+            Self::InvalidValue(position) => write!(
+                f,
+                "{position}: invalid value `{}`, expected a whole number",
+                position.text
+            ),
+            Self::ConflictingWord(position, conflict) => write!(f, "{position}: {conflict}"),
+        }
+    }
+}
+
+impl Error for VocabularyError {}
+
+/// The words recognised as numbers, possibly drawn from several languages at once. Most stand for
+/// a single digit, but a word such as `twentyone` stands for both of its digits. Words may
+/// overlap or contain one another, as `neun` contains the French `un`; which of them count is up
+/// to the `Matcher`'s `Overlap`. Words keep the order they were added in, which is the order of
+/// preference for `Overlap::LeftmostFirst`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+}
+
+impl Default for Vocabulary {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+impl Vocabulary {
+    /// No words at all, so only digits count.
+    pub const fn empty() -> Self {
+        Self { words: Vec::new() }
+    }
+
+    /// The words from the puzzle.
+    pub fn english() -> Self {
+        Self::from_words(ONES)
+    }
+
+    /// English with zero, the teens, the tens and every compound from `twentyone` to `ninetynine`.
+    pub fn english_extended() -> Self {
+        let ones = Self::english();
+        let mut words = ones.words.clone();
+
+        words.push((String::from("zero"), 0));
+        words.extend(
+            (10..)
+                .zip(TEENS)
+                .map(|(value, word)| (word.to_string(), value)),
+        );
+
+        for (tens, word) in (20..).step_by(10).zip(TENS) {
+            words.push((word.to_string(), tens));
+            words.extend(
+                ones.words()
+                    .map(|(one, value)| (format!("{word}{one}"), tens + value)),
+            );
+        }
+
+        Self { words }
+    }
+
+    pub fn builtin(language: &str) -> Option<Self> {
+        if language == "english-extended" {
+            return Some(Self::english_extended());
+        }
+
+        builtin_words(language).map(Self::from_words)
+    }
+
+    /// The words for one to nine, in that order.
+    fn from_words(words: [&str; 9]) -> Self {
+        Self {
+            words: words
+                .iter()
+                .zip(1..)
+                .map(|(word, value)| ((*word).to_string(), value))
+                .collect(),
+        }
+    }
+
+    /// Adds `word` for `value`, unless it is already there. A word can only stand for one value,
+    /// even across languages.
+    pub fn add(&mut self, word: &str, value: u32) -> Result<(), Conflict> {
+        match self.words.iter().find(|(existing, _)| existing == word) {
+            Some((_, existing)) if *existing == value => Ok(()),
+            Some((_, existing)) => Err(Conflict {
+                word: word.to_string(),
+                existing: *existing,
+                new: value,
+            }),
+            None => {
+                self.words.push((word.to_string(), value));
+                Ok(())
+            }
+        }
+    }
+
+    pub fn extend(&mut self, other: &Self) -> Result<(), Conflict> {
+        other
+            .words()
+            .try_for_each(|(word, value)| self.add(word, value))
+    }
+
+    /// Reads a vocabulary from lines of `word = value`, with `include = language` adding a
+    /// built-in set and `#` starting a comment.
+    pub fn parse(config: &str) -> Result<Self, VocabularyError> {
+        let mut vocabulary = Self::empty();
+
+        for line in common::lines(config) {
+            let text = line.text.split('#').next().unwrap_or_default();
+
+            if text.trim().is_empty() {
+                continue;
+            }
+
+            let (key, value) = text
+                .split_once('=')
+                .map(|(key, value)| (key.trim(), value.trim()))
+                .ok_or_else(|| VocabularyError::InvalidLine(line.whole()))?;
+
+            if key == "include" {
+                let builtin = Self::builtin(value)
+                    .ok_or_else(|| VocabularyError::UnknownLanguage(line.position(value)))?;
+
+                vocabulary.extend(&builtin).map_err(|conflict| {
+                    VocabularyError::ConflictingWord(line.position(value), conflict)
+                })?;
+
+                continue;
+            }
+
+            if key.is_empty() || key.chars().any(char::is_numeric) {
+                return Err(VocabularyError::InvalidWord(line.position(key)));
+            }
+
+            let value = value
+                .parse::<u32>()
+                .map_err(|_| VocabularyError::InvalidValue(line.position(value)))?;
+
+            vocabulary.add(key, value).map_err(|conflict| {
+                VocabularyError::ConflictingWord(line.position(key), conflict)
+            })?;
+        }
+
+        Ok(vocabulary)
+    }
+
+    /// Combines the built-in sets for `languages` with the vocabulary files at `paths`, or
+    /// returns English if given neither.
+    pub fn load(languages: &[String], paths: &[impl AsRef<Path>]) -> Result<Self, String> {
+        if languages.is_empty() && paths.is_empty() {
+            return Ok(Self::english());
+        }
+
+        let mut vocabulary = Self::empty();
+
+        for language in languages {
+            let builtin = Self::builtin(language).ok_or_else(|| {
+                format!(
+                    "Unknown language `{language}`, expected one of {}",
+                    LANGUAGES.join(", ")
+                )
+            })?;
+
+            vocabulary.extend(&builtin).map_err(|e| e.to_string())?;
+        }
+
+        for path in paths {
+            let path = path.as_ref();
+
+            let config = read_to_string(path)
+                .map_err(|e| format!("Could not load vocabulary {}: {e}", path.display()))?;
+
+            let loaded = Self::parse(&config).map_err(|e| format!("{}: {e}", path.display()))?;
+
+            vocabulary
+                .extend(&loaded)
+                .map_err(|e| format!("{}: {e}", path.display()))?;
+        }
+
+        Ok(vocabulary)
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.words
+            .iter()
+            .map(|(word, value)| (word.as_str(), *value))
+    }
+
+    /// Whether any word appears anywhere in `text`.
+    pub fn appears_in(&self, text: &str) -> bool {
+        self.words().any(|(word, _)| text.contains(word))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin() {
+        assert_eq!(Vocabulary::builtin("english"), Some(Vocabulary::english()));
+
+        for language in LANGUAGES
+            .iter()
+            .filter(|language| **language != "english-extended")
+        {
+            assert_eq!(
+                Vocabulary::builtin(language).map(|vocabulary| vocabulary.words().count()),
+                Some(9)
+            );
+        }
+
+        assert_eq!(Vocabulary::builtin("klingon"), None);
+
+        // Every built-in language can be combined with every other.
+        let mut all = Vocabulary::empty();
+
+        for language in LANGUAGES {
+            all.extend(&Vocabulary::builtin(language).unwrap()).unwrap();
+        }
+
+        assert!(all
+            .words()
+            .any(|(word, value)| word == "fünf" && value == 5));
+    }
+
+    #[test]
+    fn test_english_extended() {
+        let extended = Vocabulary::english_extended();
+        let value = |word: &str| {
+            extended
+                .words()
+                .find(|(candidate, _)| *candidate == word)
+                .map(|(_, value)| value)
+        };
+
+        // One to nine, zero, ten to nineteen, and nine words for each of the eight tens.
+        assert_eq!(extended.words().count(), 9 + 1 + 10 + 8 * 10);
+
+        assert_eq!(value("zero"), Some(0));
+        assert_eq!(value("thirteen"), Some(13));
+        assert_eq!(value("forty"), Some(40));
+        assert_eq!(value("twentyone"), Some(21));
+        assert_eq!(value("ninetynine"), Some(99));
+        assert_eq!(value("hundred"), None);
+    }
+
+    #[test]
+    fn test_add() {
+        let mut vocabulary = Vocabulary::builtin("spanish").unwrap();
+
+        assert_eq!(vocabulary.add("uno", 1), Ok(()));
+        assert_eq!(vocabulary.words().count(), 9);
+
+        assert_eq!(
+            vocabulary.add("dos", 3),
+            Err(Conflict {
+                word: String::from("dos"),
+                existing: 2,
+                new: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let config = "# Italian, plus a couple of Welsh words
+include = italian
+
+un = 1 # also French
+dau = 2
+dim = 0
+deuddeg = 12";
+
+        let vocabulary = Vocabulary::parse(config).unwrap();
+
+        assert_eq!(vocabulary.words().count(), 13);
+        assert!(vocabulary
+            .words()
+            .any(|(word, value)| word == "dau" && value == 2));
+        assert!(vocabulary
+            .words()
+            .any(|(word, value)| word == "deuddeg" && value == 12));
+
+        assert_eq!(Vocabulary::parse(""), Ok(Vocabulary::empty()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = |config: &str| Vocabulary::parse(config).unwrap_err().to_string();
+
+        assert_eq!(
+            error("un = 1\ndeux 2"),
+            "line 2, column 1: expected `word = value` or `include = language`, got `deux 2`"
+        );
+        assert_eq!(
+            error("include = klingon"),
+            "line 1, column 11: unknown language `klingon`, expected one of english, \
+             english-extended, french, german, spanish, italian, dutch"
+        );
+        assert_eq!(
+            error("tw0 = 2"),
+            "line 1, column 1: invalid word `tw0`, words cannot be empty or contain digits"
+        );
+        assert_eq!(
+            error("minus = -1"),
+            "line 1, column 9: invalid value `-1`, expected a whole number"
+        );
+        assert_eq!(
+            error("half = 0.5"),
+            "line 1, column 8: invalid value `0.5`, expected a whole number"
+        );
+        assert_eq!(
+            error("include = english\nsix = 7"),
+            "line 2, column 1: `six` already stands for 6, not 7"
+        );
+    }
+
+    #[test]
+    fn test_load() {
+        let no_files: &[&Path] = &[];
+
+        assert_eq!(Vocabulary::load(&[], no_files), Ok(Vocabulary::english()));
+
+        let vocabulary =
+            Vocabulary::load(&[String::from("french"), String::from("german")], no_files).unwrap();
+
+        assert_eq!(vocabulary.words().count(), 18);
+
+        assert!(Vocabulary::load(&[String::from("klingon")], no_files)
+            .unwrap_err()
+            .starts_with("Unknown language `klingon`"));
+
+        assert!(Vocabulary::load(&[], &[Path::new("/nonexistent/words")])
+            .unwrap_err()
+            .starts_with("Could not load vocabulary /nonexistent/words"));
+    }
+}
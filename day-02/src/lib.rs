@@ -0,0 +1,388 @@
+mod part1;
+mod part2;
+
+use common::{Line, Overflow, Position, Solution};
+
+use std::{error::Error, fmt, str::FromStr};
+use tracing::trace;
+
+pub const EXAMPLE_PART1: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+pub const EXAMPLE_PART2: &str = EXAMPLE_PART1;
+
+const DELIMITER_GAME: &str = ":";
+const DELIMITER_SET: &str = ";";
+const DELIMITER_CUBE: &str = ",";
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Colour {
+    Red,
+    Green,
+    Blue,
+}
+
+impl FromStr for Colour {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "red" => Ok(Self::Red),
+            "green" => Ok(Self::Green),
+            "blue" => Ok(Self::Blue),
+            _ => Err(ParseError::UnknownColour(s.to_string())),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct ColouredCube {
+    pub amount: u32,
+    pub colour: Colour,
+}
+
+#[derive(Debug)]
+pub struct CubeSet {
+    pub cubes: Vec<ColouredCube>,
+}
+
+#[derive(Debug)]
+pub struct Game {
+    pub id: u32,
+    pub cube_sets: Vec<CubeSet>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    MissingDelimiter(Position),
+    InvalidGameId(Position),
+    InvalidCube(Position),
+    InvalidAmount(Position),
+    InvalidColour(Position),
+    UnknownColour(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingDelimiter(position) => write!(
+                f,
+                "{position}: expected `{DELIMITER_GAME}` in `{}`",
+                position.text
+            ),
+            Self::InvalidGameId(position) => {
+                write!(f, "{position}: invalid game id `{}`", position.text)
+            }
+            Self::InvalidCube(position) => write!(
+                f,
+                "{position}: expected an amount and a colour, got `{}`",
+                position.text
+            ),
+            Self::InvalidAmount(position) => {
+                write!(f, "{position}: invalid amount `{}`", position.text)
+            }
+            Self::InvalidColour(position) => {
+                write!(f, "{position}: invalid colour `{}`", position.text)
+            }
+            Self::UnknownColour(text) => write!(f, "`{text}` is not a colour"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+fn parse_cube(line: Line, cube: &str) -> Result<ColouredCube, ParseError> {
+    let tokens: Vec<&str> = cube.split_whitespace().collect();
+
+    let [amount_str, colour_str] = tokens[..] else {
+        trace!("Could not parse cube");
+        return Err(ParseError::InvalidCube(line.position(cube.trim())));
+    };
+
+    let amount = amount_str
+        .parse::<u32>()
+        .map_err(|_| ParseError::InvalidAmount(line.position(amount_str)))?;
+
+    let colour = Colour::from_str(colour_str)
+        .map_err(|_| ParseError::InvalidColour(line.position(colour_str)))?;
+
+    Ok(ColouredCube { amount, colour })
+}
+
+fn parse_set(line: Line, set: &str) -> Result<CubeSet, ParseError> {
+    trace!("Parsing set");
+
+    let cubes = set
+        .trim()
+        .split(DELIMITER_CUBE)
+        .map(|cube| parse_cube(line, cube))
+        .collect::<Result<Vec<ColouredCube>, ParseError>>()?;
+
+    Ok(CubeSet { cubes })
+}
+
+fn parse_sets_string(line: Line, sets: &str) -> Result<Vec<CubeSet>, ParseError> {
+    trace!("Parsing sets");
+
+    sets.split(DELIMITER_SET)
+        .map(|set| parse_set(line, set))
+        .collect()
+}
+
+fn parse_game_string(line: Line, game: &str) -> Result<u32, ParseError> {
+    trace!("Parsing game");
+
+    let Some((_, string_id)) = game.trim().split_once(' ') else {
+        return Err(ParseError::InvalidGameId(line.position(game.trim())));
+    };
+
+    string_id
+        .parse::<u32>()
+        .map_err(|_| ParseError::InvalidGameId(line.position(string_id)))
+}
+
+fn parse_game(line: Line) -> Result<Game, ParseError> {
+    trace!(%line.text, "Parsing");
+
+    let Some((game_string, sets_string)) = line.text.split_once(DELIMITER_GAME) else {
+        return Err(ParseError::MissingDelimiter(line.whole()));
+    };
+
+    Ok(Game {
+        id: parse_game_string(line, game_string)?,
+        cube_sets: parse_sets_string(line, sets_string)?,
+    })
+}
+
+pub fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+    common::lines(input).map(parse_game).collect()
+}
+
+pub fn solve_part1(games: &[Game]) -> Result<u64, Overflow> {
+    part1::calculate_game_id_sum(games)
+}
+
+pub fn solve_part2(games: &[Game]) -> Result<u128, Overflow> {
+    part2::calculate_minimum_required_set_power_sum(games)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+    type Error = ParseError;
+    type Part1 = u64;
+    type Part2 = u128;
+
+    const DAY: u8 = 2;
+
+    const EXAMPLE_PART1: &'static str = EXAMPLE_PART1;
+    const EXAMPLE_PART2: &'static str = EXAMPLE_PART2;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Overflow> {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Overflow> {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_game() {
+        let line = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+
+        let expected = Game {
+            id: 1,
+            cube_sets: vec![
+                CubeSet {
+                    cubes: vec![
+                        ColouredCube {
+                            amount: 3,
+                            colour: Colour::Blue,
+                        },
+                        ColouredCube {
+                            amount: 4,
+                            colour: Colour::Red,
+                        },
+                    ],
+                },
+                CubeSet {
+                    cubes: vec![
+                        ColouredCube {
+                            amount: 1,
+                            colour: Colour::Red,
+                        },
+                        ColouredCube {
+                            amount: 2,
+                            colour: Colour::Green,
+                        },
+                        ColouredCube {
+                            amount: 6,
+                            colour: Colour::Blue,
+                        },
+                    ],
+                },
+                CubeSet {
+                    cubes: vec![ColouredCube {
+                        amount: 2,
+                        colour: Colour::Green,
+                    }],
+                },
+            ],
+        };
+
+        let game = parse_game(Line::new(1, line));
+
+        assert!(game.is_ok());
+
+        let game = game.unwrap();
+
+        assert_eq!(expected.id, game.id);
+        assert_eq!(expected.cube_sets.len(), game.cube_sets.len());
+    }
+
+    #[test]
+    fn test_parse_game_string() {
+        let game = "Game 1";
+
+        let game_id = parse_game_string(Line::new(1, game), game);
+
+        assert_eq!(Ok(1), game_id);
+
+        let game = "Game 10";
+
+        let game_id = parse_game_string(Line::new(1, game), game);
+
+        assert_eq!(Ok(10), game_id);
+    }
+
+    #[test]
+    fn test_parse_sets_string() {
+        let sets = " 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+
+        let result = parse_sets_string(Line::new(1, sets), sets);
+
+        let expected = [
+            CubeSet {
+                cubes: vec![
+                    ColouredCube {
+                        amount: 3,
+                        colour: Colour::Blue,
+                    },
+                    ColouredCube {
+                        amount: 4,
+                        colour: Colour::Red,
+                    },
+                ],
+            },
+            CubeSet {
+                cubes: vec![
+                    ColouredCube {
+                        amount: 1,
+                        colour: Colour::Red,
+                    },
+                    ColouredCube {
+                        amount: 2,
+                        colour: Colour::Green,
+                    },
+                    ColouredCube {
+                        amount: 6,
+                        colour: Colour::Blue,
+                    },
+                ],
+            },
+            CubeSet {
+                cubes: vec![ColouredCube {
+                    amount: 2,
+                    colour: Colour::Green,
+                }],
+            },
+        ];
+
+        assert!(result.is_ok());
+
+        assert_eq!(result.unwrap().len(), expected.len());
+    }
+
+    #[test]
+    fn test_parse_set() {
+        let set = "3 blue, 4 red";
+
+        let result = parse_set(Line::new(1, set), set);
+
+        assert!(result.is_ok());
+
+        assert_eq!(result.unwrap().cubes.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_cube() {
+        let cube = "3 blue";
+
+        let result = parse_cube(Line::new(1, cube), cube);
+
+        assert!(result.is_ok());
+
+        let result = result.unwrap();
+
+        assert_eq!(result.amount, 3);
+        assert_eq!(result.colour, Colour::Blue);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let lines = "Game 1: 3 blue, 4 red
+Game 2: 1 blue, 2 purple";
+
+        let result = parse(lines);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ParseError::InvalidColour(Position {
+                line: 2,
+                column: 19,
+                text: String::from("purple"),
+            })
+        );
+
+        let result = parse("Game x: 3 blue");
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 1, column 6: invalid game id `x`"
+        );
+
+        let result = parse("Game 3 3 blue");
+
+        assert!(matches!(result, Err(ParseError::MissingDelimiter(_))));
+
+        let result = parse("Game 4: 3 blue, red");
+
+        assert!(matches!(result, Err(ParseError::InvalidCube(_))));
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_never_panics(
+            input in prop_oneof![
+                any::<String>(),
+                r"(Game [0-9x]{1,4}:( [0-9]{1,4} (red|green|blue|pink)[,;]?){0,6}\n?){0,4}",
+            ]
+        ) {
+            let _ = parse(&input);
+        }
+    }
+}
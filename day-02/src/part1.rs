@@ -0,0 +1,235 @@
+use crate::{Colour, ColouredCube, CubeSet, Game};
+
+use common::{checked, Overflow};
+
+const MAX_RED: u32 = 12;
+const MAX_GREEN: u32 = 13;
+const MAX_BLUE: u32 = 14;
+
+impl Colour {
+    const fn max(self) -> u32 {
+        match self {
+            Self::Red => MAX_RED,
+            Self::Green => MAX_GREEN,
+            Self::Blue => MAX_BLUE,
+        }
+    }
+}
+
+impl ColouredCube {
+    const fn is_valid(self) -> bool {
+        self.amount <= self.colour.max()
+    }
+}
+
+impl CubeSet {
+    fn is_valid(&self) -> bool {
+        self.cubes.iter().filter(|cube| !cube.is_valid()).count() == 0
+    }
+}
+
+impl Game {
+    fn is_possible(&self) -> bool {
+        self.cube_sets.iter().filter(|set| !set.is_valid()).count() == 0
+    }
+}
+
+pub(crate) fn calculate_game_id_sum(games: &[Game]) -> Result<u64, Overflow> {
+    checked::sum(
+        games
+            .iter()
+            .filter(|game| game.is_possible())
+            .map(|game| u64::from(game.id)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part1, EXAMPLE_PART1};
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART1;
+
+        let result = solve_part1(&parse(lines).unwrap());
+
+        assert_eq!(result, Ok(8));
+    }
+
+    #[test]
+    fn test_cube_is_valid() {
+        let red_cube_valid = ColouredCube {
+            amount: 12,
+            colour: Colour::Red,
+        };
+
+        let red_cube_invalid = ColouredCube {
+            amount: 13,
+            colour: Colour::Red,
+        };
+
+        let green_cube_valid = ColouredCube {
+            amount: 13,
+            colour: Colour::Green,
+        };
+
+        let green_cube_invalid = ColouredCube {
+            amount: 14,
+            colour: Colour::Green,
+        };
+
+        let blue_cube_valid = ColouredCube {
+            amount: 14,
+            colour: Colour::Blue,
+        };
+
+        let blue_cube_invalid = ColouredCube {
+            amount: 15,
+            colour: Colour::Blue,
+        };
+
+        assert!(red_cube_valid.is_valid());
+        assert!(!red_cube_invalid.is_valid());
+        assert!(green_cube_valid.is_valid());
+        assert!(!green_cube_invalid.is_valid());
+        assert!(blue_cube_valid.is_valid());
+        assert!(!blue_cube_invalid.is_valid());
+    }
+
+    #[test]
+    fn test_cube_set_is_valid() {
+        let cube_set_valid = CubeSet {
+            cubes: vec![
+                ColouredCube {
+                    amount: 12,
+                    colour: Colour::Red,
+                },
+                ColouredCube {
+                    amount: 1,
+                    colour: Colour::Red,
+                },
+                ColouredCube {
+                    amount: 13,
+                    colour: Colour::Green,
+                },
+            ],
+        };
+
+        let cube_set_invalid = CubeSet {
+            cubes: vec![
+                ColouredCube {
+                    amount: 12,
+                    colour: Colour::Red,
+                },
+                ColouredCube {
+                    amount: 13,
+                    colour: Colour::Red,
+                },
+                ColouredCube {
+                    amount: 13,
+                    colour: Colour::Green,
+                },
+            ],
+        };
+
+        assert!(cube_set_valid.is_valid());
+        assert!(!cube_set_invalid.is_valid());
+    }
+
+    #[test]
+    fn test_game_is_possible() {
+        let game_possible = Game {
+            id: 1,
+            cube_sets: vec![
+                CubeSet {
+                    cubes: vec![
+                        ColouredCube {
+                            amount: 3,
+                            colour: Colour::Blue,
+                        },
+                        ColouredCube {
+                            amount: 4,
+                            colour: Colour::Red,
+                        },
+                    ],
+                },
+                CubeSet {
+                    cubes: vec![
+                        ColouredCube {
+                            amount: 1,
+                            colour: Colour::Red,
+                        },
+                        ColouredCube {
+                            amount: 2,
+                            colour: Colour::Green,
+                        },
+                        ColouredCube {
+                            amount: 6,
+                            colour: Colour::Blue,
+                        },
+                    ],
+                },
+                CubeSet {
+                    cubes: vec![ColouredCube {
+                        amount: 2,
+                        colour: Colour::Green,
+                    }],
+                },
+            ],
+        };
+
+        let game_impossible = Game {
+            id: 1,
+            cube_sets: vec![
+                CubeSet {
+                    cubes: vec![
+                        ColouredCube {
+                            amount: 3,
+                            colour: Colour::Blue,
+                        },
+                        ColouredCube {
+                            amount: 20,
+                            colour: Colour::Red,
+                        },
+                    ],
+                },
+                CubeSet {
+                    cubes: vec![
+                        ColouredCube {
+                            amount: 1,
+                            colour: Colour::Red,
+                        },
+                        ColouredCube {
+                            amount: 2,
+                            colour: Colour::Green,
+                        },
+                        ColouredCube {
+                            amount: 6,
+                            colour: Colour::Blue,
+                        },
+                    ],
+                },
+                CubeSet {
+                    cubes: vec![ColouredCube {
+                        amount: 2,
+                        colour: Colour::Green,
+                    }],
+                },
+            ],
+        };
+
+        assert!(game_possible.is_possible());
+        assert!(!game_impossible.is_possible());
+    }
+
+    #[test]
+    fn test_game_id_sum_does_not_wrap() {
+        let games = parse("Game 4294967295: 1 red\nGame 4294967294: 2 blue").unwrap();
+
+        assert_eq!(
+            calculate_game_id_sum(&games),
+            Ok(2 * u64::from(u32::MAX) - 1)
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use crate::{Colour, ColouredCube, CubeSet, Game};
+
+use common::{checked, Overflow};
+
+impl CubeSet {
+    fn get_power(self) -> Result<u128, Overflow> {
+        checked::product(self.cubes.iter().map(|cube| u128::from(cube.amount)))
+    }
+}
+
+impl Game {
+    fn get_minimum_cube_set(&self) -> Option<CubeSet> {
+        let mut cubes_red: Vec<&ColouredCube> = self
+            .cube_sets
+            .iter()
+            .flat_map(|set| set.cubes.iter().filter(|cube| cube.colour == Colour::Red))
+            .collect();
+
+        cubes_red.sort();
+
+        let mut cubes_green: Vec<&ColouredCube> = self
+            .cube_sets
+            .iter()
+            .flat_map(|set| set.cubes.iter().filter(|cube| cube.colour == Colour::Green))
+            .collect();
+
+        cubes_green.sort();
+
+        let mut cubes_blue: Vec<&ColouredCube> = self
+            .cube_sets
+            .iter()
+            .flat_map(|set| set.cubes.iter().filter(|cube| cube.colour == Colour::Blue))
+            .collect();
+
+        cubes_blue.sort();
+
+        if cubes_red.is_empty() && cubes_green.is_empty() && cubes_blue.is_empty() {
+            return None;
+        }
+
+        let mut cubes: Vec<ColouredCube> = Vec::default();
+
+        if let Some(cube_red) = cubes_red.last() {
+            cubes.push(**cube_red);
+        }
+
+        if let Some(cube_green) = cubes_green.last() {
+            cubes.push(**cube_green);
+        }
+
+        if let Some(cube_blue) = cubes_blue.last() {
+            cubes.push(**cube_blue);
+        }
+
+        Some(CubeSet { cubes })
+    }
+}
+
+pub(crate) fn calculate_minimum_required_set_power_sum(games: &[Game]) -> Result<u128, Overflow> {
+    checked::try_sum(
+        games
+            .iter()
+            .filter_map(Game::get_minimum_cube_set)
+            .map(CubeSet::get_power),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part2, EXAMPLE_PART2};
+    use proptest::prelude::*;
+
+    fn cube_set() -> impl Strategy<Value = CubeSet> {
+        let colour = prop_oneof![Just(Colour::Red), Just(Colour::Green), Just(Colour::Blue)];
+
+        prop::collection::vec(
+            (1..=20u32, colour).prop_map(|(amount, colour)| ColouredCube { amount, colour }),
+            1..=3,
+        )
+        .prop_map(|cubes| CubeSet { cubes })
+    }
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART2;
+
+        let result = solve_part2(&parse(lines).unwrap());
+
+        assert_eq!(result, Ok(2286));
+    }
+
+    #[test]
+    fn test_get_minimum_cube_set() {
+        let cubes_1: Vec<ColouredCube> = vec![
+            ColouredCube {
+                amount: 1,
+                colour: Colour::Red,
+            },
+            ColouredCube {
+                amount: 10,
+                colour: Colour::Green,
+            },
+            ColouredCube {
+                amount: 4,
+                colour: Colour::Blue,
+            },
+        ];
+
+        let cube_set_1 = CubeSet { cubes: cubes_1 };
+
+        let cubes_2: Vec<ColouredCube> = vec![
+            ColouredCube {
+                amount: 11,
+                colour: Colour::Red,
+            },
+            ColouredCube {
+                amount: 2,
+                colour: Colour::Blue,
+            },
+        ];
+
+        let cube_set_2 = CubeSet { cubes: cubes_2 };
+
+        let game = Game {
+            id: 1,
+            cube_sets: vec![cube_set_1, cube_set_2],
+        };
+
+        let result = game.get_minimum_cube_set();
+
+        assert!(result.is_some());
+
+        let result = result.unwrap();
+
+        assert_eq!(result.cubes.len(), 3);
+    }
+
+    #[test]
+    fn test_cube_set_get_power() {
+        let cubes: Vec<ColouredCube> = vec![
+            ColouredCube {
+                amount: 1,
+                colour: Colour::Red,
+            },
+            ColouredCube {
+                amount: 10,
+                colour: Colour::Green,
+            },
+            ColouredCube {
+                amount: 4,
+                colour: Colour::Blue,
+            },
+        ];
+
+        let cube_set = CubeSet { cubes };
+
+        let result = cube_set.get_power();
+
+        assert_eq!(result, Ok(40));
+    }
+
+    #[test]
+    fn test_power_sum_does_not_wrap() {
+        let games = parse(
+            "Game 1: 4000000000 red, 4000000000 green; 4000000000 blue
+Game 2: 1 red, 2 green, 3 blue",
+        )
+        .unwrap();
+
+        assert_eq!(solve_part2(&games), Ok(4_000_000_000_u128.pow(3) + 6));
+    }
+
+    proptest! {
+        #[test]
+        fn test_minimum_cube_set_dominates_every_set(
+            cube_sets in prop::collection::vec(cube_set(), 1..6)
+        ) {
+            let game = Game { id: 1, cube_sets };
+
+            let minimum = game.get_minimum_cube_set().unwrap();
+
+            for cube in game.cube_sets.iter().flat_map(|set| &set.cubes) {
+                prop_assert!(minimum
+                    .cubes
+                    .iter()
+                    .any(|min| min.colour == cube.colour && min.amount >= cube.amount));
+            }
+
+            // Each colour in the minimum set is needed by at least one set, so it can't be smaller.
+            for min in &minimum.cubes {
+                prop_assert!(game
+                    .cube_sets
+                    .iter()
+                    .flat_map(|set| &set.cubes)
+                    .any(|cube| cube == min));
+            }
+        }
+    }
+}
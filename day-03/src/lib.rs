@@ -0,0 +1,125 @@
+mod part1;
+mod part2;
+
+use std::{error::Error, fmt};
+
+use common::{Overflow, Position, Solution};
+use grid::Grid;
+
+pub const EXAMPLE_PART1: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+pub const EXAMPLE_PART2: &str = EXAMPLE_PART1;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    Empty,
+    RaggedLine(Position, usize),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the schematic is empty"),
+            Self::RaggedLine(position, width) => write!(
+                f,
+                "{position}: expected {width} characters, got {} in `{}`",
+                position.text.chars().count(),
+                position.text
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, Some).map_err(|error| match error {
+        grid::ParseError::Empty => ParseError::Empty,
+        grid::ParseError::RaggedLine(position, width) => ParseError::RaggedLine(position, width),
+        grid::ParseError::InvalidCell(_) => unreachable!("any character is a valid cell"),
+    })
+}
+
+pub fn solve_part1(schematic: &Grid<char>) -> Result<u128, Overflow> {
+    part1::calculate_engine_part_id_sum(schematic)
+}
+
+pub fn solve_part2(schematic: &Grid<char>) -> Result<u128, Overflow> {
+    part2::calculate_engine_part_id_sum(schematic)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Grid<char>;
+    type Error = ParseError;
+    type Part1 = u128;
+    type Part2 = u128;
+
+    const DAY: u8 = 3;
+
+    const EXAMPLE_PART1: &'static str = EXAMPLE_PART1;
+    const EXAMPLE_PART2: &'static str = EXAMPLE_PART2;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Overflow> {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Overflow> {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(parse(""), Err(ParseError::Empty));
+
+        let lines = "467..114..
+...*......
+..35..633";
+
+        let result = parse(lines);
+
+        assert_eq!(
+            result,
+            Err(ParseError::RaggedLine(
+                Position {
+                    line: 3,
+                    column: 1,
+                    text: String::from("..35..633"),
+                },
+                10
+            ))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_never_panics(
+            input in prop_oneof![
+                any::<String>(),
+                r"[0-9.*#+$/\n]{0,100}",
+            ]
+        ) {
+            let _ = parse(&input);
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use std::{iter::once, num::IntErrorKind};
+
+use common::{checked, Overflow};
+use grid::{Grid, Point};
+use tracing::trace;
+
+#[derive(Clone, Default, Debug)]
+struct Number {
+    digits: String,
+    valid: bool,
+}
+
+impl Number {
+    fn add_digit(&mut self, digit: char) {
+        self.digits.push(digit);
+    }
+
+    /// The number's value, or `None` for numeric characters that are not ASCII digits.
+    fn value(&self) -> Result<Option<u128>, Overflow> {
+        match self.digits.parse::<u128>() {
+            Ok(value) => Ok(Some(value)),
+            Err(e) if *e.kind() == IntErrorKind::PosOverflow => Err(Overflow),
+            Err(_) => Ok(None),
+        }
+    }
+}
+
+#[allow(clippy::too_many_lines, clippy::cognitive_complexity)]
+pub(crate) fn calculate_engine_part_id_sum(schematic: &Grid<char>) -> Result<u128, Overflow> {
+    let mut part_numbers: Vec<Number> = Vec::default();
+
+    let mut current_number: Option<Number> = None;
+
+    let mut number_would_be_valid = false;
+
+    for (index_line, line) in schematic.rows().enumerate() {
+        if current_number.is_some() {
+            let mut number = current_number.clone().unwrap();
+
+            number.valid = number.valid || number_would_be_valid;
+
+            if !number.digits.is_empty() {
+                part_numbers.push(number);
+            }
+
+            current_number = None;
+        }
+
+        for (index_ch, ch) in line.iter().enumerate() {
+            if current_number.is_some() {
+                let mut number = current_number.clone().unwrap();
+                number.valid = number.valid || number_would_be_valid;
+
+                current_number = Some(number);
+            }
+
+            match ch {
+                '.' => {
+                    if current_number.is_some() {
+                        let number = current_number.clone().unwrap();
+
+                        if !number.digits.is_empty() {
+                            part_numbers.push(number);
+                        }
+
+                        current_number = None;
+                    }
+                }
+                _ => {
+                    if ch.is_numeric() {
+                        if current_number.is_none() {
+                            current_number = Some(Number::default());
+                        }
+
+                        let mut number = current_number.clone().unwrap();
+                        number.add_digit(*ch);
+
+                        current_number = Some(number);
+                    } else if current_number.is_some() {
+                        let number = current_number.clone().unwrap();
+
+                        if !number.digits.is_empty() {
+                            part_numbers.push(current_number.unwrap());
+                        }
+
+                        current_number = None;
+                    }
+                }
+            }
+
+            let point = Point::new(index_ch, index_line);
+
+            trace!(%ch, "Checking Char");
+
+            number_would_be_valid = schematic
+                .neighbours8(point)
+                .chain(once(point))
+                .map(|adjacent| schematic[adjacent])
+                .any(|adjacent| adjacent != '.' && !adjacent.is_numeric());
+        }
+    }
+
+    if let Some(number) = current_number {
+        if !number.digits.is_empty() {
+            part_numbers.push(number);
+        }
+    }
+
+    trace!(?part_numbers);
+
+    let values = part_numbers
+        .iter()
+        .filter(|number| number.valid)
+        .map(Number::value)
+        .collect::<Result<Vec<Option<u128>>, Overflow>>()?;
+
+    checked::sum(values.into_iter().flatten())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, solve_part1, EXAMPLE_PART1};
+    use common::Overflow;
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART1;
+
+        let result = solve_part1(&parse(lines).unwrap());
+
+        assert_eq!(result, Ok(4361));
+    }
+
+    #[test]
+    fn test_wide_part_numbers() {
+        let schematic = parse("99999999999*\n..........1.").unwrap();
+
+        assert_eq!(solve_part1(&schematic), Ok(100_000_000_000));
+
+        let schematic = parse(&format!("{}*", "9".repeat(40))).unwrap();
+
+        assert_eq!(solve_part1(&schematic), Err(Overflow));
+    }
+}
@@ -0,0 +1,166 @@
+use std::{cmp::Reverse, iter::once, num::IntErrorKind};
+
+use common::{checked, checked::Checked, Overflow};
+use grid::{Grid, Point};
+use tracing::trace;
+
+#[derive(Clone, Default, Debug)]
+struct Number {
+    digits: String,
+    gear: Option<Point>,
+}
+
+impl Number {
+    fn add_digit(&mut self, digit: char) {
+        self.digits.push(digit);
+    }
+
+    /// The number's value, or `None` for numeric characters that are not ASCII digits.
+    fn value(&self) -> Result<Option<u128>, Overflow> {
+        match self.digits.parse::<u128>() {
+            Ok(value) => Ok(Some(value)),
+            Err(e) if *e.kind() == IntErrorKind::PosOverflow => Err(Overflow),
+            Err(_) => Ok(None),
+        }
+    }
+}
+
+#[allow(clippy::too_many_lines, clippy::cognitive_complexity)]
+pub(crate) fn calculate_engine_part_id_sum(schematic: &Grid<char>) -> Result<u128, Overflow> {
+    let mut part_numbers: Vec<Number> = Vec::default();
+    let mut gears: Vec<Point> = Vec::default();
+
+    let mut current_number: Option<Number> = None;
+
+    let mut current_gear: Option<Point> = None;
+
+    for (index_line, line) in schematic.rows().enumerate() {
+        if current_number.is_some() {
+            let mut number = current_number.clone().unwrap();
+
+            if number.gear.is_none() {
+                number.gear = current_gear;
+            }
+
+            if !number.digits.is_empty() {
+                part_numbers.push(number);
+            }
+
+            current_number = None;
+        }
+
+        for (index_ch, ch) in line.iter().enumerate() {
+            let point = Point::new(index_ch, index_line);
+
+            if current_number.is_some() {
+                let mut number = current_number.clone().unwrap();
+
+                if number.gear.is_none() {
+                    number.gear = current_gear;
+                }
+
+                current_number = Some(number);
+            }
+
+            match ch {
+                '*' => {
+                    gears.push(point);
+
+                    if current_number.is_some() {
+                        let number = current_number.clone().unwrap();
+
+                        if !number.digits.is_empty() {
+                            part_numbers.push(number);
+                        }
+
+                        current_number = None;
+                    }
+                }
+                _ => {
+                    if ch.is_numeric() {
+                        if current_number.is_none() {
+                            current_number = Some(Number::default());
+                        }
+
+                        let mut number = current_number.clone().unwrap();
+                        number.add_digit(*ch);
+
+                        current_number = Some(number);
+                    } else if current_number.is_some() {
+                        let number = current_number.clone().unwrap();
+
+                        if !number.digits.is_empty() {
+                            part_numbers.push(current_number.unwrap());
+                        }
+
+                        current_number = None;
+                    }
+                }
+            }
+
+            trace!(%ch, "Checking Char");
+
+            // When several gears touch this character, the one on the lowest row wins, and then
+            // the leftmost on that row.
+            current_gear = schematic
+                .neighbours8(point)
+                .chain(once(point))
+                .filter(|adjacent| schematic[*adjacent] == '*')
+                .max_by_key(|adjacent| (adjacent.y, Reverse(adjacent.x)));
+        }
+    }
+
+    if let Some(number) = current_number {
+        if !number.digits.is_empty() {
+            part_numbers.push(number);
+        }
+    }
+
+    trace!(?part_numbers);
+
+    let mut gear_ratios: Vec<u128> = Vec::default();
+
+    for gear in gears {
+        let gear_numbers: Vec<Number> = part_numbers
+            .iter()
+            .filter(|number| number.gear == Some(gear))
+            .cloned()
+            .collect();
+
+        if let [gear_1, gear_2] = &gear_numbers[..] {
+            if let (Some(gear_1), Some(gear_2)) = (gear_1.value()?, gear_2.value()?) {
+                gear_ratios.push(gear_1.try_mul(gear_2)?);
+            }
+        }
+    }
+
+    trace!(?gear_ratios);
+
+    checked::sum(gear_ratios)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, solve_part2, EXAMPLE_PART2};
+    use common::Overflow;
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART2;
+
+        let result = solve_part2(&parse(lines).unwrap());
+
+        assert_eq!(result, Ok(467_835));
+    }
+
+    #[test]
+    fn test_wide_gear_ratios() {
+        let schematic = parse("99999999999*99999999999").unwrap();
+
+        assert_eq!(solve_part2(&schematic), Ok(99_999_999_999 * 99_999_999_999));
+
+        let schematic = parse(&format!("{0}*{0}", "9".repeat(20))).unwrap();
+
+        assert_eq!(solve_part2(&schematic), Err(Overflow));
+    }
+}
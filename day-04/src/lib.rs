@@ -0,0 +1,216 @@
+mod part1;
+mod part2;
+
+use std::{error::Error, fmt};
+
+use common::{Line, Overflow, Position, Solution};
+use tracing::trace;
+
+pub const EXAMPLE_PART1: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+pub const EXAMPLE_PART2: &str = EXAMPLE_PART1;
+
+const DELIMITER_CARD_ID: &str = ":";
+const DELIMITER_CARD: &str = "|";
+
+#[derive(Debug, PartialEq)]
+pub struct Card {
+    pub id: usize,
+    pub winning_numbers: Vec<u8>,
+    pub owned_numbers: Vec<u8>,
+}
+
+impl Card {
+    pub fn count_matching(&self) -> usize {
+        self.owned_numbers
+            .iter()
+            .filter(|number| self.winning_numbers.contains(number))
+            .count()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    MissingDelimiter(Position, &'static str),
+    InvalidCardId(Position),
+    InvalidNumber(Position),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingDelimiter(position, delimiter) => write!(
+                f,
+                "{position}: expected `{delimiter}` in `{}`",
+                position.text
+            ),
+            Self::InvalidCardId(position) => {
+                write!(f, "{position}: invalid card id `{}`", position.text)
+            }
+            Self::InvalidNumber(position) => {
+                write!(f, "{position}: invalid number `{}`", position.text)
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+fn parse_numbers(line: Line, numbers: &str) -> Result<Vec<u8>, ParseError> {
+    numbers
+        .split_whitespace()
+        .map(|number| {
+            number
+                .parse::<u8>()
+                .map_err(|_| ParseError::InvalidNumber(line.position(number)))
+        })
+        .collect()
+}
+
+fn parse_card(line: Line) -> Result<Card, ParseError> {
+    trace!(%line.text, "Parsing line");
+
+    let Some((card_id_string, cards_string)) = line.text.split_once(DELIMITER_CARD_ID) else {
+        return Err(ParseError::MissingDelimiter(
+            line.whole(),
+            DELIMITER_CARD_ID,
+        ));
+    };
+
+    let Some(id) = card_id_string.split_whitespace().last() else {
+        trace!("Card ID string empty");
+        return Err(ParseError::InvalidCardId(line.position(card_id_string)));
+    };
+
+    let card_id = id
+        .parse::<usize>()
+        .map_err(|_| ParseError::InvalidCardId(line.position(id)))?;
+
+    let Some((winning_card, owned_card)) = cards_string.split_once(DELIMITER_CARD) else {
+        trace!("Could not split card string");
+        return Err(ParseError::MissingDelimiter(
+            line.position(cards_string),
+            DELIMITER_CARD,
+        ));
+    };
+
+    Ok(Card {
+        id: card_id,
+        winning_numbers: parse_numbers(line, winning_card)?,
+        owned_numbers: parse_numbers(line, owned_card)?,
+    })
+}
+
+pub fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+    common::lines(input).map(parse_card).collect()
+}
+
+pub fn solve_part1(cards: &[Card]) -> Result<u128, Overflow> {
+    part1::calculate_card_value(cards)
+}
+
+pub fn solve_part2(cards: &[Card]) -> Result<u128, Overflow> {
+    part2::count_total_cards(cards)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+    type Error = ParseError;
+    type Part1 = u128;
+    type Part2 = u128;
+
+    const DAY: u8 = 4;
+
+    const EXAMPLE_PART1: &'static str = EXAMPLE_PART1;
+    const EXAMPLE_PART2: &'static str = EXAMPLE_PART2;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Overflow> {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Overflow> {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// `count` cards where each one wins a copy of every card after it.
+    pub(crate) fn cascading_cards(count: usize) -> Vec<Card> {
+        let input = (1..=count)
+            .map(|id| format!("Card {id}: 1 | {}", "1 ".repeat(count - id)))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        parse(&input).unwrap()
+    }
+
+    #[test]
+    fn test_parse_card() {
+        let line = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
+
+        let result = parse_card(Line::new(1, line));
+
+        let expected = Card {
+            id: 1,
+            winning_numbers: vec![41, 48, 83, 86, 17],
+            owned_numbers: vec![83, 86, 6, 31, 17, 9, 48, 53],
+        };
+
+        assert!(result.is_ok());
+
+        let result = result.unwrap();
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let lines = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 1x 24 19";
+
+        let result = parse(lines);
+
+        assert_eq!(
+            result,
+            Err(ParseError::InvalidNumber(Position {
+                line: 2,
+                column: 38,
+                text: String::from("1x"),
+            }))
+        );
+
+        let result = parse("Card 3: 1 21 53 59 44  69 82 63 72 16");
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 1, column 8: expected `|` in ` 1 21 53 59 44  69 82 63 72 16`"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_never_panics(
+            input in prop_oneof![
+                any::<String>(),
+                r"(Card +[0-9x]{1,3}:( +[0-9]{1,3}){0,5} \|?( +[0-9]{1,3}){0,5}\n?){0,5}",
+            ]
+        ) {
+            let _ = parse(&input);
+        }
+    }
+}
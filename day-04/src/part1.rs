@@ -0,0 +1,42 @@
+use crate::Card;
+
+use common::{checked, Overflow};
+
+impl Card {
+    fn calculate_value(&self) -> Result<u128, Overflow> {
+        let winning_numbers_in_owned_side = self.count_matching();
+
+        if winning_numbers_in_owned_side > 0 {
+            2_u128
+                .checked_pow(checked::convert(winning_numbers_in_owned_side - 1)?)
+                .ok_or(Overflow)
+        } else {
+            Ok(0)
+        }
+    }
+}
+
+pub(crate) fn calculate_card_value(cards: &[Card]) -> Result<u128, Overflow> {
+    checked::try_sum(cards.iter().map(Card::calculate_value))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, solve_part1, tests::cascading_cards, EXAMPLE_PART1};
+    use common::Overflow;
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART1;
+
+        let result = solve_part1(&parse(lines).unwrap());
+
+        assert_eq!(result, Ok(13));
+    }
+
+    #[test]
+    fn test_wide_card_values() {
+        assert_eq!(solve_part1(&cascading_cards(41)), Ok((1 << 40) - 1));
+        assert_eq!(solve_part1(&cascading_cards(130)), Err(Overflow));
+    }
+}
@@ -0,0 +1,40 @@
+use crate::Card;
+
+use common::{checked, checked::Checked, Overflow};
+
+pub(crate) fn count_total_cards(cards: &[Card]) -> Result<u128, Overflow> {
+    let mut copies: Vec<u128> = vec![1; cards.len()];
+
+    for (index, card) in cards.iter().enumerate() {
+        let current_copies = copies[index];
+
+        for n in 0..card.count_matching() {
+            if let Some(copy) = copies.get_mut(card.id + n) {
+                *copy = copy.try_add(current_copies)?;
+            }
+        }
+    }
+
+    checked::sum(copies)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, solve_part2, tests::cascading_cards, EXAMPLE_PART2};
+    use common::Overflow;
+
+    #[test]
+    fn test_given_example() {
+        let input = EXAMPLE_PART2;
+
+        let result = solve_part2(&parse(input).unwrap());
+
+        assert_eq!(result, Ok(30));
+    }
+
+    #[test]
+    fn test_wide_card_counts() {
+        assert_eq!(solve_part2(&cascading_cards(70)), Ok((1 << 70) - 1));
+        assert_eq!(solve_part2(&cascading_cards(130)), Err(Overflow));
+    }
+}
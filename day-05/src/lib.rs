@@ -0,0 +1,299 @@
+mod part1;
+mod part2;
+
+use std::{error::Error, fmt};
+
+use common::{Line, Overflow, Position, Solution};
+use tracing::trace;
+
+pub const EXAMPLE_PART1: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+pub const EXAMPLE_PART2: &str = EXAMPLE_PART1;
+
+const DELIMITER_SEEDS: &str = "seeds:";
+const HEADER_SUFFIX: &str = "map:";
+
+const STAGES: [&str; 7] = [
+    "Soil",
+    "Fertiliser",
+    "Water",
+    "Light",
+    "Temperature",
+    "Humidity",
+    "Location",
+];
+
+#[derive(Debug, PartialEq)]
+pub struct Map {
+    pub destination_range_start: u64,
+    pub source_range_start: u64,
+    pub range: u64,
+}
+
+impl Map {
+    /// Maps `value`, in 128 bits since a destination near `u64::MAX` can map values past it.
+    pub fn get(&self, value: u128) -> Option<u128> {
+        let offset = value.checked_sub(u128::from(self.source_range_start))?;
+
+        if offset <= u128::from(self.range) {
+            Some(u128::from(self.destination_range_start) + offset)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub maps: Vec<Vec<Map>>,
+}
+
+impl Almanac {
+    pub fn locations(&self, seed: u128) -> Vec<u128> {
+        trace!(%seed, "Seed");
+
+        let mut values: Vec<u128> = vec![seed];
+
+        for (stage, maps) in STAGES.iter().zip(&self.maps) {
+            let next_values: Vec<u128> = maps
+                .iter()
+                .flat_map(|map| values.iter().map(|value| map.get(*value)))
+                .flatten()
+                .collect();
+
+            if !next_values.is_empty() {
+                values = next_values;
+            }
+
+            trace!(%stage, ?values);
+        }
+
+        values
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    Empty,
+    MissingSeeds(Position),
+    MissingHeader(Position),
+    InvalidMap(Position),
+    InvalidNumber(Position),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the almanac is empty"),
+            Self::MissingSeeds(position) => write!(
+                f,
+                "{position}: expected `{DELIMITER_SEEDS}` in `{}`",
+                position.text
+            ),
+            Self::MissingHeader(position) => write!(
+                f,
+                "{position}: expected a `... {HEADER_SUFFIX}` header, got `{}`",
+                position.text
+            ),
+            Self::InvalidMap(position) => write!(
+                f,
+                "{position}: expected destination, source and range, got `{}`",
+                position.text
+            ),
+            Self::InvalidNumber(position) => {
+                write!(f, "{position}: invalid number `{}`", position.text)
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+fn parse_numbers(line: Line, numbers: &str) -> Result<Vec<u64>, ParseError> {
+    numbers
+        .split_whitespace()
+        .map(|value| {
+            value
+                .parse::<u64>()
+                .map_err(|_| ParseError::InvalidNumber(line.position(value)))
+        })
+        .collect()
+}
+
+fn parse_seeds(line: Line) -> Result<Vec<u64>, ParseError> {
+    let Some((_, seeds)) = line.text.split_once(DELIMITER_SEEDS) else {
+        return Err(ParseError::MissingSeeds(line.whole()));
+    };
+
+    parse_numbers(line, seeds)
+}
+
+fn parse_line(line: Line) -> Result<Map, ParseError> {
+    trace!(%line.text, "Parsing line");
+
+    let line_values = parse_numbers(line, line.text)?;
+
+    let [destination_range_start, source_range_start, range] = line_values[..] else {
+        return Err(ParseError::InvalidMap(line.whole()));
+    };
+
+    Ok(Map {
+        destination_range_start,
+        source_range_start,
+        range,
+    })
+}
+
+pub fn parse(input: &str) -> Result<Almanac, ParseError> {
+    let mut lines = common::lines(input);
+
+    let Some(first_line) = lines.next() else {
+        return Err(ParseError::Empty);
+    };
+
+    let seeds = parse_seeds(first_line)?;
+
+    trace!(?seeds, "Seeds");
+
+    let mut maps: Vec<Vec<Map>> = Vec::default();
+
+    let mut in_grouping = false;
+
+    for line in lines {
+        if line.text.trim().is_empty() {
+            in_grouping = false;
+            continue;
+        }
+
+        if in_grouping {
+            maps.last_mut().unwrap().push(parse_line(line)?);
+        } else if line.text.trim_end().ends_with(HEADER_SUFFIX) {
+            in_grouping = true;
+            maps.push(Vec::default());
+        } else {
+            return Err(ParseError::MissingHeader(line.whole()));
+        }
+    }
+
+    trace!(?maps, "Maps");
+
+    Ok(Almanac { seeds, maps })
+}
+
+pub fn solve_part1(almanac: &Almanac) -> u128 {
+    part1::find_lowest_location_number(almanac)
+}
+
+pub fn solve_part2(almanac: &Almanac) -> u128 {
+    part2::find_lowest_location_number(almanac)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+    type Error = ParseError;
+    type Part1 = u128;
+    type Part2 = u128;
+
+    const DAY: u8 = 5;
+
+    const EXAMPLE_PART1: &'static str = EXAMPLE_PART1;
+    const EXAMPLE_PART2: &'static str = EXAMPLE_PART2;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Overflow> {
+        Ok(solve_part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Overflow> {
+        Ok(solve_part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(parse(""), Err(ParseError::Empty));
+
+        let lines = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50";
+
+        assert_eq!(
+            parse(lines),
+            Err(ParseError::InvalidMap(Position {
+                line: 5,
+                column: 1,
+                text: String::from("52 50"),
+            }))
+        );
+
+        let lines = "seeds: 79 14 -55 13";
+
+        assert_eq!(
+            parse(lines).unwrap_err().to_string(),
+            "line 1, column 14: invalid number `-55`"
+        );
+
+        let lines = "seeds: 79
+
+50 98 2";
+
+        assert!(matches!(parse(lines), Err(ParseError::MissingHeader(_))));
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_never_panics(
+            input in prop_oneof![
+                any::<String>(),
+                r"(seeds:( [0-9]{1,4}){0,4}\n\n)?([a-z]{1,5}-to-[a-z]{1,5} map:\n([0-9]{1,4} [0-9]{1,4} [0-9]{1,4}\n){0,3}\n?){0,3}",
+            ]
+        ) {
+            let _ = parse(&input);
+        }
+    }
+}
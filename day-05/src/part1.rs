@@ -0,0 +1,37 @@
+use crate::Almanac;
+
+pub(crate) fn find_lowest_location_number(almanac: &Almanac) -> u128 {
+    almanac
+        .seeds
+        .iter()
+        .flat_map(|seed| almanac.locations(u128::from(*seed)))
+        .min()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, solve_part1, EXAMPLE_PART1};
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART1;
+
+        let result = solve_part1(&parse(lines).unwrap());
+
+        assert_eq!(result, 35);
+    }
+
+    #[test]
+    fn test_locations_past_u64() {
+        let almanac = parse(
+            "seeds: 5
+
+seed-to-soil map:
+18446744073709551615 0 10",
+        )
+        .unwrap();
+
+        assert_eq!(solve_part1(&almanac), u128::from(u64::MAX) + 5);
+    }
+}
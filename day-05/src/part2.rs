@@ -0,0 +1,45 @@
+use crate::Almanac;
+use tracing::trace;
+
+pub(crate) fn find_lowest_location_number(almanac: &Almanac) -> u128 {
+    let seeds: Vec<(u128, u128)> = almanac
+        .seeds
+        .chunks(2)
+        .map(|chunk| (u128::from(chunk[0]), u128::from(chunk[1])))
+        .collect();
+
+    trace!(?seeds, "Seeds");
+
+    seeds
+        .iter()
+        .flat_map(|(seed, range)| (*seed..(seed + range)).flat_map(|n| almanac.locations(n)))
+        .min()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, solve_part2, EXAMPLE_PART2};
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART2;
+
+        let result = solve_part2(&parse(lines).unwrap());
+
+        assert_eq!(result, 46);
+    }
+
+    #[test]
+    fn test_seed_ranges_past_u64() {
+        let almanac = parse(
+            "seeds: 18446744073709551614 2
+
+seed-to-soil map:
+0 18446744073709551613 10",
+        )
+        .unwrap();
+
+        assert_eq!(solve_part2(&almanac), 1);
+    }
+}
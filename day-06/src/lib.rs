@@ -0,0 +1,234 @@
+mod part1;
+mod part2;
+
+use std::{error::Error, fmt};
+
+use common::{Line, Overflow, Position, Solution};
+use tracing::trace;
+
+pub const EXAMPLE_PART1: &str = "Time:      7  15   30
+Distance:  9  40  200";
+
+pub const EXAMPLE_PART2: &str = EXAMPLE_PART1;
+
+const LABEL_TIME: &str = "Time";
+const LABEL_DISTANCE: &str = "Distance";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    MissingLines,
+    MissingLabel(Position, &'static str),
+    InvalidNumber(Position),
+    LengthMismatch(Position, usize),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingLines => write!(f, "expected a line of times and a line of distances"),
+            Self::MissingLabel(position, label) => write!(
+                f,
+                "{position}: expected `{label}:`, got `{}`",
+                position.text
+            ),
+            Self::InvalidNumber(position) => {
+                write!(f, "{position}: invalid number `{}`", position.text)
+            }
+            Self::LengthMismatch(position, expected) => write!(
+                f,
+                "{position}: expected {expected} distances to match the times in `{}`",
+                position.text
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Race {
+    pub time: usize,
+    pub distance: usize,
+}
+
+impl Race {
+    pub fn ways_to_beat_record(&self) -> usize {
+        let maximum_non_win = binary_search(
+            &(1..self.time).collect::<Vec<usize>>(),
+            self.distance,
+            self.time,
+        );
+
+        trace!(?maximum_non_win, "Maximum non win");
+
+        // add 1 for time + 0
+        // subtract 2 for holding 0 and holding the whole time
+        let ways_of_beating_record = (self.time + 1) - 2 - (maximum_non_win * 2);
+
+        trace!(?ways_of_beating_record, "Ways of beating record");
+
+        ways_of_beating_record
+    }
+}
+
+fn binary_search(list: &[usize], value: usize, max: usize) -> usize {
+    trace!(?list, "Checking list");
+
+    if list.len() == 1 {
+        return *list.first().unwrap();
+    }
+
+    let index = list.len() / 2;
+
+    trace!(?index, "Checking index");
+
+    let list_value = list.get(index).unwrap();
+
+    trace!(list_value, max, "Checking calculation");
+
+    // A distance too large for a `usize` is certainly longer than the record.
+    if list_value
+        .checked_mul(max - list_value)
+        .is_some_and(|distance| distance <= value)
+    {
+        binary_search(&list[index..], value, max)
+    } else {
+        binary_search(&list[0..index], value, max)
+    }
+}
+
+fn parse_numbers(line: Line, label: &'static str) -> Result<Vec<usize>, ParseError> {
+    let Some((line_label, numbers)) = line.text.split_once(':') else {
+        return Err(ParseError::MissingLabel(line.whole(), label));
+    };
+
+    if line_label.trim() != label {
+        return Err(ParseError::MissingLabel(line.position(line_label), label));
+    }
+
+    numbers
+        .split_whitespace()
+        .map(|number| {
+            number
+                .parse::<usize>()
+                .map_err(|_| ParseError::InvalidNumber(line.position(number)))
+        })
+        .collect()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Race>, ParseError> {
+    let mut lines = common::lines(input);
+
+    let (Some(times_line), Some(distances_line)) = (lines.next(), lines.next()) else {
+        trace!("Could not split input into 2 lines");
+        return Err(ParseError::MissingLines);
+    };
+
+    let times = parse_numbers(times_line, LABEL_TIME)?;
+    let distances = parse_numbers(distances_line, LABEL_DISTANCE)?;
+
+    if times.len() != distances.len() {
+        trace!("Length of times and distances doesn't match");
+        return Err(ParseError::LengthMismatch(
+            distances_line.whole(),
+            times.len(),
+        ));
+    }
+
+    Ok(times
+        .iter()
+        .zip(distances.iter())
+        .map(|(time, distance)| Race {
+            time: *time,
+            distance: *distance,
+        })
+        .collect())
+}
+
+pub fn solve_part1(races: &[Race]) -> Result<u128, Overflow> {
+    part1::power_ways_to_win_races(races)
+}
+
+pub fn solve_part2(races: &[Race]) -> Result<usize, Overflow> {
+    part2::ways_to_win_race(races)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<Race>;
+    type Error = ParseError;
+    type Part1 = u128;
+    type Part2 = usize;
+
+    const DAY: u8 = 6;
+
+    const EXAMPLE_PART1: &'static str = EXAMPLE_PART1;
+    const EXAMPLE_PART2: &'static str = EXAMPLE_PART2;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Overflow> {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Overflow> {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("Time:      7  15   30"),
+            Err(ParseError::MissingLines)
+        );
+
+        let lines = "Time:      7  15   30
+Distance:  9  40";
+
+        assert!(matches!(
+            parse(lines),
+            Err(ParseError::LengthMismatch(_, 3))
+        ));
+
+        let lines = "Time:      7  15   30
+Distance:  9  4O  200";
+
+        assert_eq!(
+            parse(lines),
+            Err(ParseError::InvalidNumber(Position {
+                line: 2,
+                column: 15,
+                text: String::from("4O"),
+            }))
+        );
+
+        let lines = "Time:      7  15   30
+Record:  9  40  200";
+
+        assert_eq!(
+            parse(lines).unwrap_err().to_string(),
+            "line 2, column 1: expected `Distance:`, got `Record`"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_never_panics(
+            input in prop_oneof![
+                any::<String>(),
+                r"(Time:( +[0-9]{1,5}){0,4}\n)?(Distance:( +[0-9]{1,5}){0,4}\n?)?",
+            ]
+        ) {
+            let _ = parse(&input);
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::Race;
+
+use common::{checked, Overflow};
+
+pub(crate) fn power_ways_to_win_races(races: &[Race]) -> Result<u128, Overflow> {
+    if races.is_empty() {
+        return Ok(0);
+    }
+
+    races
+        .iter()
+        .map(|race| checked::convert(race.ways_to_beat_record()))
+        .collect::<Result<Vec<u128>, Overflow>>()
+        .and_then(checked::product)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, solve_part1, EXAMPLE_PART1};
+    use common::Overflow;
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART1;
+
+        let result = solve_part1(&parse(lines).unwrap());
+
+        assert_eq!(result, Ok(288));
+    }
+
+    #[test]
+    fn test_wide_product() {
+        // Each race can be won by holding the button for 2 to 1000001 milliseconds.
+        let races = |count: usize| {
+            let numbers = " 1000003".repeat(count);
+
+            parse(&format!("Time:{numbers}\nDistance:{numbers}")).unwrap()
+        };
+
+        assert_eq!(solve_part1(&races(6)), Ok(1_000_000_u128.pow(6)));
+        assert_eq!(solve_part1(&races(7)), Err(Overflow));
+    }
+}
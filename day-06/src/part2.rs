@@ -0,0 +1,52 @@
+use std::num::IntErrorKind;
+
+use crate::Race;
+
+use common::Overflow;
+
+/// Joins the digits of `numbers` into one number, or `None` if there are none.
+fn join_numbers(numbers: impl Iterator<Item = usize>) -> Result<Option<usize>, Overflow> {
+    match numbers
+        .map(|number| number.to_string())
+        .collect::<String>()
+        .parse::<usize>()
+    {
+        Ok(number) => Ok(Some(number)),
+        Err(e) if *e.kind() == IntErrorKind::PosOverflow => Err(Overflow),
+        Err(_) => Ok(None),
+    }
+}
+
+pub(crate) fn ways_to_win_race(races: &[Race]) -> Result<usize, Overflow> {
+    let Some(time) = join_numbers(races.iter().map(|race| race.time))? else {
+        return Ok(0);
+    };
+
+    let Some(distance) = join_numbers(races.iter().map(|race| race.distance))? else {
+        return Ok(0);
+    };
+
+    Ok(Race { time, distance }.ways_to_beat_record())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, solve_part2, EXAMPLE_PART2};
+    use common::Overflow;
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART2;
+
+        let result = solve_part2(&parse(lines).unwrap());
+
+        assert_eq!(result, Ok(71503));
+    }
+
+    #[test]
+    fn test_joined_race_overflow() {
+        let races = parse("Time: 99999999999 99999999999\nDistance: 1 1").unwrap();
+
+        assert_eq!(solve_part2(&races), Err(Overflow));
+    }
+}
@@ -0,0 +1,455 @@
+mod part1;
+mod part2;
+
+use std::{cmp::Ordering, error::Error, fmt};
+
+use common::{checked, checked::Checked, Line, Overflow, Position, Solution};
+use tracing::trace;
+
+pub const EXAMPLE_PART1: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+pub const EXAMPLE_PART2: &str = EXAMPLE_PART1;
+
+const HAND_SIZE: usize = 5;
+
+#[derive(Ord, PartialOrd, PartialEq, Eq, Debug, Clone, Copy)]
+pub enum CardValue {
+    Joker,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl CardValue {
+    pub const fn from_char(c: char) -> Option<Self> {
+        match c {
+            '2' => Some(Self::Two),
+            '3' => Some(Self::Three),
+            '4' => Some(Self::Four),
+            '5' => Some(Self::Five),
+            '6' => Some(Self::Six),
+            '7' => Some(Self::Seven),
+            '8' => Some(Self::Eight),
+            '9' => Some(Self::Nine),
+            'T' => Some(Self::Ten),
+            'J' => Some(Self::Jack),
+            'Q' => Some(Self::Queen),
+            'K' => Some(Self::King),
+            'A' => Some(Self::Ace),
+            _ => None,
+        }
+    }
+
+    pub fn iterator() -> impl Iterator<Item = Self> {
+        [
+            Self::Joker,
+            Self::Two,
+            Self::Three,
+            Self::Four,
+            Self::Five,
+            Self::Six,
+            Self::Seven,
+            Self::Eight,
+            Self::Nine,
+            Self::Ten,
+            Self::Jack,
+            Self::Queen,
+            Self::King,
+            Self::Ace,
+        ]
+        .iter()
+        .copied()
+    }
+}
+
+#[derive(Ord, PartialOrd, PartialEq, Eq, Debug, Clone, Copy)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+#[derive(Ord, PartialOrd, PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Card {
+    pub value: CardValue,
+}
+
+#[derive(Debug, Clone)]
+pub struct Hand {
+    pub cards: Vec<Card>,
+    pub hand_type: HandType,
+    pub bid: u64,
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.hand_type == other.hand_type {
+            for n in 0..self.cards.len() {
+                let self_card = self.cards.get(n).unwrap();
+                let other_card = other.cards.get(n).unwrap();
+
+                if self_card == other_card {
+                    continue;
+                }
+
+                if self_card < other_card {
+                    return Ordering::Less;
+                }
+
+                if self_card > other_card {
+                    return Ordering::Greater;
+                }
+            }
+
+            return Ordering::Equal;
+        }
+
+        self.hand_type.cmp(&other.hand_type)
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Hand {}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    MissingBid(Position),
+    InvalidCard(Position),
+    InvalidHandSize(Position),
+    InvalidBid(Position),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingBid(position) => {
+                write!(
+                    f,
+                    "{position}: expected a hand and a bid, got `{}`",
+                    position.text
+                )
+            }
+            Self::InvalidCard(position) => {
+                write!(f, "{position}: invalid card `{}`", position.text)
+            }
+            Self::InvalidHandSize(position) => write!(
+                f,
+                "{position}: expected {HAND_SIZE} cards, got `{}`",
+                position.text
+            ),
+            Self::InvalidBid(position) => {
+                write!(f, "{position}: invalid bid `{}`", position.text)
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+#[derive(Debug)]
+struct CardCount {
+    card: CardValue,
+    count: usize,
+}
+
+#[allow(clippy::useless_let_if_seq)]
+pub fn get_hand_type(cards: &[Card]) -> HandType {
+    let mut found_cards: Vec<CardCount> = CardValue::iterator()
+        .map(|card| CardCount { card, count: 0 })
+        .collect();
+
+    #[allow(clippy::needless_range_loop)]
+    for n in 0..found_cards.len() {
+        found_cards[n].count += cards
+            .iter()
+            .filter(|card| card.value == found_cards[n].card)
+            .count();
+    }
+
+    let mut count_fives = 0;
+    let mut count_fours = 0;
+    let mut count_threes = 0;
+    let mut count_twos = 0;
+
+    for found in &found_cards {
+        match found.count {
+            2 => count_twos += 1,
+            3 => count_threes += 1,
+            4 => count_fours += 1,
+            5 => count_fives += 1,
+            _ => {}
+        }
+    }
+
+    let mut hand_type = HandType::HighCard;
+
+    if count_twos > 0 {
+        hand_type = if count_twos == 1 {
+            HandType::OnePair
+        } else {
+            HandType::TwoPair
+        };
+    }
+
+    if count_threes > 0 {
+        hand_type = if count_twos > 0 {
+            HandType::FullHouse
+        } else {
+            HandType::ThreeOfAKind
+        };
+    }
+
+    if count_fours > 0 {
+        hand_type = HandType::FourOfAKind;
+    }
+
+    if count_fives > 0 {
+        hand_type = HandType::FiveOfAKind;
+    }
+
+    if hand_type != HandType::FiveOfAKind
+        && cards.contains(&Card {
+            value: CardValue::Joker,
+        })
+    {
+        for card_value in CardValue::iterator() {
+            if card_value == CardValue::Joker {
+                continue;
+            }
+
+            let new_cards: Vec<Card> = cards
+                .iter()
+                .map(|card| {
+                    if card.value == CardValue::Joker {
+                        Card { value: card_value }
+                    } else {
+                        *card
+                    }
+                })
+                .collect();
+
+            let new_hand_type = get_hand_type(&new_cards);
+
+            if new_hand_type > hand_type {
+                hand_type = new_hand_type;
+            }
+
+            if hand_type == HandType::FiveOfAKind {
+                break;
+            }
+        }
+    }
+
+    hand_type
+}
+
+fn parse_hand(line: Line) -> Result<Hand, ParseError> {
+    trace!(%line.text, "Parsing line");
+
+    let Some((hand_string, bid_string)) = line.text.split_once(' ') else {
+        return Err(ParseError::MissingBid(line.whole()));
+    };
+
+    let cards = hand_string
+        .char_indices()
+        .map(|(index, c)| {
+            CardValue::from_char(c)
+                .map(|value| Card { value })
+                .ok_or_else(|| {
+                    ParseError::InvalidCard(
+                        line.position(&hand_string[index..index + c.len_utf8()]),
+                    )
+                })
+        })
+        .collect::<Result<Vec<Card>, ParseError>>()?;
+
+    if cards.len() != HAND_SIZE {
+        return Err(ParseError::InvalidHandSize(line.position(hand_string)));
+    }
+
+    let bid = bid_string
+        .trim()
+        .parse::<u64>()
+        .map_err(|_| ParseError::InvalidBid(line.position(bid_string.trim())))?;
+
+    let hand_type = get_hand_type(&cards);
+
+    let hand = Hand {
+        cards,
+        hand_type,
+        bid,
+    };
+
+    trace!(?hand, "Got hand");
+
+    Ok(hand)
+}
+
+fn calculate_winnings(mut hands: Vec<&Hand>) -> Result<u128, Overflow> {
+    trace!(?hands);
+
+    hands.sort();
+
+    trace!(?hands, "Sorted Hands");
+
+    let mut value: u128 = 0;
+
+    for (index, hand) in hands.iter().enumerate() {
+        let rank: u128 = checked::convert(index + 1)?;
+
+        value = value.try_add(rank.try_mul(u128::from(hand.bid))?)?;
+    }
+
+    Ok(value)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Hand>, ParseError> {
+    common::lines(input).map(parse_hand).collect()
+}
+
+pub fn solve_part1(hands: &[Hand]) -> Result<u128, Overflow> {
+    calculate_winnings(hands.iter().collect())
+}
+
+pub fn solve_part2(hands: &[Hand]) -> Result<u128, Overflow> {
+    let hands: Vec<Hand> = hands.iter().map(part2::with_jokers).collect();
+
+    calculate_winnings(hands.iter().collect())
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Hand>;
+    type Error = ParseError;
+    type Part1 = u128;
+    type Part2 = u128;
+
+    const DAY: u8 = 7;
+
+    const EXAMPLE_PART1: &'static str = EXAMPLE_PART1;
+    const EXAMPLE_PART2: &'static str = EXAMPLE_PART2;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Overflow> {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Overflow> {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn hand() -> impl Strategy<Value = Hand> {
+        let values: Vec<CardValue> = CardValue::iterator().collect();
+
+        prop::collection::vec(prop::sample::select(values), HAND_SIZE).prop_map(|values| {
+            let cards: Vec<Card> = values.into_iter().map(|value| Card { value }).collect();
+
+            Hand {
+                hand_type: get_hand_type(&cards),
+                cards,
+                bid: 1,
+            }
+        })
+    }
+
+    #[test]
+    fn test_card_value_order() {
+        assert!(CardValue::Two < CardValue::Three);
+    }
+
+    #[test]
+    fn test_hand_type_order() {
+        assert!(HandType::FourOfAKind < HandType::FiveOfAKind);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let lines = "32T3K 765
+T55X5 684";
+
+        assert_eq!(
+            parse(lines),
+            Err(ParseError::InvalidCard(Position {
+                line: 2,
+                column: 4,
+                text: String::from("X"),
+            }))
+        );
+
+        assert_eq!(
+            parse("KK677").unwrap_err().to_string(),
+            "line 1, column 1: expected a hand and a bid, got `KK677`"
+        );
+
+        assert!(matches!(
+            parse("KTJJ 220"),
+            Err(ParseError::InvalidHandSize(_))
+        ));
+
+        assert!(matches!(
+            parse("QQQJA 483a"),
+            Err(ParseError::InvalidBid(_))
+        ));
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_never_panics(
+            input in prop_oneof![
+                any::<String>(),
+                r"([2-9TJQKAX]{0,6} [0-9x]{1,4}\n?){0,5}",
+            ]
+        ) {
+            let _ = parse(&input);
+        }
+
+        #[test]
+        fn test_hand_ordering_is_total(a in hand(), b in hand(), c in hand()) {
+            prop_assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+            prop_assert_eq!(a == b, a.cards == b.cards);
+
+            if a <= b && b <= c {
+                prop_assert!(a <= c);
+            }
+        }
+    }
+}
@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use crate::{parse, solve_part1, Card, CardValue, Hand, HandType, EXAMPLE_PART1};
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART1;
+
+        let result = solve_part1(&parse(lines).unwrap());
+
+        assert_eq!(result, Ok(6440));
+    }
+
+    #[test]
+    fn test_wide_winnings() {
+        let hands = parse("32T3K 18446744073709551615\nKK677 18446744073709551615").unwrap();
+
+        assert_eq!(solve_part1(&hands), Ok(3 * u128::from(u64::MAX)));
+    }
+
+    #[test]
+    fn test_card_order() {
+        assert!(
+            Card {
+                value: CardValue::Jack
+            } < Card {
+                value: CardValue::King
+            }
+        );
+    }
+
+    #[test]
+    fn test_hand_order() {
+        assert!(
+            Hand {
+                cards: vec![
+                    Card {
+                        value: CardValue::Jack
+                    },
+                    Card {
+                        value: CardValue::Jack
+                    },
+                    Card {
+                        value: CardValue::Jack
+                    },
+                    Card {
+                        value: CardValue::Jack
+                    },
+                    Card {
+                        value: CardValue::Jack
+                    }
+                ],
+                hand_type: HandType::FourOfAKind,
+                bid: 100
+            } < Hand {
+                cards: vec![
+                    Card {
+                        value: CardValue::King
+                    },
+                    Card {
+                        value: CardValue::King
+                    },
+                    Card {
+                        value: CardValue::Queen
+                    },
+                    Card {
+                        value: CardValue::Jack
+                    },
+                    Card {
+                        value: CardValue::Jack
+                    }
+                ],
+                hand_type: HandType::FiveOfAKind,
+                bid: 50
+            }
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{get_hand_type, Card, CardValue, Hand};
+
+pub(crate) fn with_jokers(hand: &Hand) -> Hand {
+    let cards: Vec<Card> = hand
+        .cards
+        .iter()
+        .map(|card| {
+            if card.value == CardValue::Jack {
+                Card {
+                    value: CardValue::Joker,
+                }
+            } else {
+                *card
+            }
+        })
+        .collect();
+
+    let hand_type = get_hand_type(&cards);
+
+    Hand {
+        cards,
+        hand_type,
+        bid: hand.bid,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part2, HandType, EXAMPLE_PART2};
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART2;
+
+        let result = solve_part2(&parse(lines).unwrap());
+
+        assert_eq!(result, Ok(5905));
+    }
+
+    #[test]
+    fn test_card_order() {
+        assert!(
+            Card {
+                value: CardValue::Joker
+            } < Card {
+                value: CardValue::Two
+            }
+        );
+    }
+
+    #[test]
+    fn test_hand_order() {
+        assert!(
+            Hand {
+                cards: vec![
+                    Card {
+                        value: CardValue::King
+                    },
+                    Card {
+                        value: CardValue::Joker
+                    },
+                    Card {
+                        value: CardValue::Joker
+                    },
+                    Card {
+                        value: CardValue::Joker
+                    },
+                    Card {
+                        value: CardValue::Joker
+                    }
+                ],
+                hand_type: HandType::FourOfAKind,
+                bid: 100
+            } < Hand {
+                cards: vec![
+                    Card {
+                        value: CardValue::King
+                    },
+                    Card {
+                        value: CardValue::King
+                    },
+                    Card {
+                        value: CardValue::Queen
+                    },
+                    Card {
+                        value: CardValue::Joker
+                    },
+                    Card {
+                        value: CardValue::Joker
+                    }
+                ],
+                hand_type: HandType::FiveOfAKind,
+                bid: 50
+            }
+        );
+    }
+}
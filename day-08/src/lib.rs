@@ -0,0 +1,347 @@
+mod part1;
+mod part2;
+
+use std::{error::Error, fmt};
+
+use common::{Line, Overflow, Part, Position, Solution};
+use tracing::trace;
+
+pub const EXAMPLE_PART1: &str = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+pub const EXAMPLE_PART2: &str = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+    Left,
+    Right,
+}
+
+impl Instruction {
+    pub const fn from_char(c: char) -> Option<Self> {
+        match c {
+            'L' => Some(Self::Left),
+            'R' => Some(Self::Right),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Node {
+    pub value: String,
+    pub left: String,
+    pub right: String,
+}
+
+impl Node {
+    pub fn is_starting(&self) -> bool {
+        self.value.ends_with('A')
+    }
+
+    pub fn is_ending(&self) -> bool {
+        self.value.ends_with('Z')
+    }
+
+    pub fn next(&self, instruction: Instruction) -> &str {
+        match instruction {
+            Instruction::Left => &self.left,
+            Instruction::Right => &self.right,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Network {
+    pub instructions: Vec<Instruction>,
+    pub nodes: Vec<Node>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    Empty,
+    NoInstructions(Position),
+    InvalidInstruction(Position),
+    InvalidNode(Position),
+    InvalidEdges(Position),
+    UnknownNode(Position),
+    /// No node ends in `A`, so no ghost has anywhere to start.
+    NoStartNode,
+    MissingNode(String),
+    /// Following the instructions from `AAA` goes round in a loop that never reaches `ZZZ`.
+    Unreachable,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the network is empty"),
+            Self::NoInstructions(position) => write!(f, "{position}: expected instructions"),
+            Self::InvalidInstruction(position) => write!(
+                f,
+                "{position}: invalid instruction `{}`, expected `L` or `R`",
+                position.text
+            ),
+            Self::InvalidNode(position) => write!(
+                f,
+                "{position}: expected `NODE = (LEFT, RIGHT)`, got `{}`",
+                position.text
+            ),
+            Self::InvalidEdges(position) => write!(
+                f,
+                "{position}: expected `(LEFT, RIGHT)`, got `{}`",
+                position.text
+            ),
+            Self::UnknownNode(position) => {
+                write!(f, "{position}: unknown node `{}`", position.text)
+            }
+            Self::NoStartNode => write!(f, "no node ends in `A`"),
+            Self::MissingNode(node) => write!(f, "the network has no node `{node}`"),
+            Self::Unreachable => write!(f, "`ZZZ` cannot be reached from `AAA`"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+fn parse_node(line: Line) -> Result<Node, ParseError> {
+    trace!(%line.text, "Parsing");
+
+    let Some((node_value_string, node_edges_string)) = line.text.split_once('=') else {
+        return Err(ParseError::InvalidNode(line.whole()));
+    };
+
+    let node_value = node_value_string.trim();
+
+    let node_edges_string = node_edges_string.trim();
+
+    let Some((left_edge, right_edge)) = node_edges_string
+        .strip_prefix('(')
+        .and_then(|edges| edges.strip_suffix(')'))
+        .and_then(|edges| edges.split_once(", "))
+    else {
+        return Err(ParseError::InvalidEdges(line.position(node_edges_string)));
+    };
+
+    Ok(Node {
+        value: node_value.to_string(),
+        left: left_edge.to_string(),
+        right: right_edge.to_string(),
+    })
+}
+
+fn parse_instructions(line: Line) -> Result<Vec<Instruction>, ParseError> {
+    line.text
+        .trim()
+        .char_indices()
+        .map(|(index, c)| {
+            Instruction::from_char(c).ok_or_else(|| {
+                let instructions = line.text.trim();
+
+                ParseError::InvalidInstruction(
+                    line.position(&instructions[index..index + c.len_utf8()]),
+                )
+            })
+        })
+        .collect()
+}
+
+pub fn parse(input: &str) -> Result<Network, ParseError> {
+    trace!("Parsing Input");
+
+    let mut lines = common::lines(input);
+
+    let first = lines.next().ok_or(ParseError::Empty)?;
+
+    let instructions = parse_instructions(first)?;
+
+    if instructions.is_empty() {
+        return Err(ParseError::NoInstructions(first.whole()));
+    }
+
+    trace!(?instructions, "Parsed instructions");
+
+    let lines: Vec<Line> = lines.filter(|line| !line.text.trim().is_empty()).collect();
+
+    let nodes = lines
+        .iter()
+        .map(|line| parse_node(*line))
+        .collect::<Result<Vec<Node>, ParseError>>()?;
+
+    for (line, node) in lines.iter().zip(&nodes) {
+        for edge in [&node.left, &node.right] {
+            if !nodes.iter().any(|other| other.value == *edge) {
+                let edges = line
+                    .text
+                    .split_once('=')
+                    .map_or(line.text, |(_, edges)| edges);
+
+                return Err(ParseError::UnknownNode(
+                    edges.find(edge.as_str()).map_or_else(
+                        || line.whole(),
+                        |index| line.position(&edges[index..index + edge.len()]),
+                    ),
+                ));
+            }
+        }
+    }
+
+    if !nodes.iter().any(Node::is_starting) {
+        return Err(ParseError::NoStartNode);
+    }
+
+    trace!(?nodes, "Parsed nodes");
+
+    Ok(Network {
+        instructions,
+        nodes,
+    })
+}
+
+/// Steps from `AAA` to `ZZZ`, or why the network has no such route.
+pub fn solve_part1(network: &Network) -> Result<u64, ParseError> {
+    part1::compute_path_steps(network)
+}
+
+pub fn solve_part2(network: &Network) -> Result<u128, Overflow> {
+    part2::compute_path_steps(network)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Network;
+    type Error = ParseError;
+    type Part1 = u64;
+    type Part2 = u128;
+
+    const DAY: u8 = 8;
+
+    const EXAMPLE_PART1: &'static str = EXAMPLE_PART1;
+    const EXAMPLE_PART2: &'static str = EXAMPLE_PART2;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    /// Part two's networks need not have `AAA` and `ZZZ`, so only part one checks for its route.
+    fn validate(part: Part, input: &Self::Input) -> Result<(), Self::Error> {
+        match part {
+            Part::One => solve_part1(input).map(|_| ()),
+            Part::Two => Ok(()),
+        }
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Overflow> {
+        // `validate` has already rejected networks without a route.
+        Ok(solve_part1(input).unwrap_or_default())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Overflow> {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(parse("").unwrap_err(), ParseError::Empty);
+
+        let lines = "LLX
+
+AAA = (BBB, BBB)";
+
+        assert_eq!(
+            parse(lines).unwrap_err(),
+            ParseError::InvalidInstruction(Position {
+                line: 1,
+                column: 3,
+                text: String::from("X"),
+            })
+        );
+
+        let lines = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)";
+
+        assert_eq!(
+            parse(lines).unwrap_err().to_string(),
+            "line 4, column 13: unknown node `ZZZ`"
+        );
+
+        let lines = "LLR
+
+AAA = BBB, BBB";
+
+        assert!(matches!(parse(lines), Err(ParseError::InvalidEdges(_))));
+
+        let lines = "
+
+AAA = (AAA, AAA)";
+
+        assert_eq!(
+            parse(lines).unwrap_err().to_string(),
+            "line 1, column 1: expected instructions"
+        );
+
+        assert_eq!(
+            parse("L\n\nBBB = (BBB, BBB)").unwrap_err(),
+            ParseError::NoStartNode
+        );
+    }
+
+    #[test]
+    fn test_part1_route() {
+        let network = parse(EXAMPLE_PART2).unwrap();
+
+        assert_eq!(
+            solve_part1(&network),
+            Err(ParseError::MissingNode(String::from("AAA")))
+        );
+        assert_eq!(
+            Day08::validate(Part::One, &network),
+            Err(ParseError::MissingNode(String::from("AAA")))
+        );
+        assert_eq!(Day08::validate(Part::Two, &network), Ok(()));
+
+        let network = parse("L\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)").unwrap();
+
+        assert_eq!(solve_part1(&network), Err(ParseError::Unreachable));
+
+        let network = Network {
+            instructions: Vec::default(),
+            nodes: network.nodes,
+        };
+
+        assert_eq!(solve_part1(&network), Err(ParseError::Unreachable));
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_never_panics(
+            input in prop_oneof![
+                any::<String>(),
+                r"[LRX]{0,5}\n\n([A-Z0-9]{3} = \([A-Z0-9]{3}, [A-Z0-9]{3}\)\n?){0,5}",
+            ]
+        ) {
+            let _ = parse(&input);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+
+use crate::{Network, ParseError};
+
+pub(crate) fn compute_path_steps(network: &Network) -> Result<u64, ParseError> {
+    let nodes = &network.nodes;
+
+    let indices: HashMap<&str, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(index, node)| (node.value.as_str(), index))
+        .collect();
+
+    let find = |value: &str| {
+        indices
+            .get(value)
+            .copied()
+            .ok_or_else(|| ParseError::MissingNode(value.to_string()))
+    };
+
+    let mut current_node = find("AAA")?;
+    let final_node = find("ZZZ")?;
+
+    // Where in the instructions each node was left from, as arriving at the same node at the same
+    // point twice means going round the same loop forever.
+    let mut seen = vec![false; nodes.len() * network.instructions.len()];
+
+    let instructions = network.instructions.iter().enumerate().cycle();
+
+    for (steps, (index, instruction)) in (0..).zip(instructions) {
+        if current_node == final_node {
+            return Ok(steps);
+        }
+
+        let state = current_node * network.instructions.len() + index;
+
+        if std::mem::replace(&mut seen[state], true) {
+            break;
+        }
+
+        current_node = find(nodes[current_node].next(*instruction))?;
+    }
+
+    Err(ParseError::Unreachable)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, solve_part1, EXAMPLE_PART1};
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART1;
+
+        let result = solve_part1(&parse(lines).unwrap());
+
+        assert_eq!(result, Ok(6));
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{Network, Node};
+use common::{checked, checked::Checked, Overflow};
+use tracing::trace;
+
+/// Least common multiple in 128 bits, since the ghosts can take more than `usize::MAX` steps to
+/// line up even though each path is short.
+fn lcm(a: u128, b: u128) -> Result<u128, Overflow> {
+    if a == 0 || b == 0 {
+        return Ok(0);
+    }
+
+    (a / num::integer::gcd(a, b)).try_mul(b)
+}
+
+/// Least common multiple of every path length, or 1 for no paths at all.
+fn calculate_lcm(lengths: &[usize]) -> Result<u128, Overflow> {
+    lengths.iter().try_fold(1, |multiple, length| {
+        lcm(multiple, checked::convert(*length)?)
+    })
+}
+
+pub(crate) fn compute_path_steps(network: &Network) -> Result<u128, Overflow> {
+    let nodes = &network.nodes;
+
+    let mut steps = 0;
+
+    let mut current_nodes: Vec<Node> = nodes
+        .iter()
+        .filter(|node| node.is_starting())
+        .cloned()
+        .collect();
+
+    trace!(?current_nodes, "Starting nodes");
+
+    let mut current_nodes_length = current_nodes.len();
+
+    let mut path_lengths: Vec<usize> = Vec::default();
+
+    'outer: loop {
+        for instruction in &network.instructions {
+            let ending_nodes: Vec<Node> = current_nodes
+                .clone()
+                .into_iter()
+                .filter(Node::is_ending)
+                .collect();
+
+            for _ in &ending_nodes {
+                path_lengths.push(steps);
+            }
+
+            if ending_nodes.len() == current_nodes_length {
+                break 'outer;
+            }
+
+            current_nodes.retain(|current_node| {
+                !ending_nodes
+                    .iter()
+                    .any(|ending_node| ending_node.value == current_node.value)
+            });
+
+            current_nodes_length = current_nodes.len();
+
+            steps += 1;
+
+            let mut new_nodes: Vec<Node> = Vec::default();
+
+            for current_node in &current_nodes {
+                let comparison_value = current_node.next(*instruction);
+
+                let new_node = nodes
+                    .iter()
+                    .find(|node| node.value == comparison_value)
+                    .unwrap();
+
+                new_nodes.push(new_node.clone());
+            }
+
+            current_nodes = new_nodes;
+        }
+    }
+
+    calculate_lcm(&path_lengths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part2, EXAMPLE_PART2};
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART2;
+
+        let result = solve_part2(&parse(lines).unwrap());
+
+        assert_eq!(result, Ok(6));
+    }
+
+    #[test]
+    fn test_calculate_lcm() {
+        assert_eq!(calculate_lcm(&[4, 6, 10]), Ok(60));
+        assert_eq!(calculate_lcm(&[7]), Ok(7));
+        assert_eq!(calculate_lcm(&[]), Ok(1));
+
+        // Coprime lengths whose product overflows 64 bits but not 128.
+        assert_eq!(
+            calculate_lcm(&[usize::MAX, usize::MAX - 1]),
+            Ok(u128::from(u64::MAX) * u128::from(u64::MAX - 1))
+        );
+
+        assert_eq!(
+            calculate_lcm(&[usize::MAX, usize::MAX - 1, usize::MAX - 2]),
+            Err(Overflow)
+        );
+    }
+}
@@ -0,0 +1,169 @@
+mod part1;
+mod part2;
+
+use std::{error::Error, fmt};
+
+use common::{checked, checked::Checked, Line, Overflow, Position, Solution};
+use tracing::trace;
+
+pub const EXAMPLE_PART1: &str = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+pub const EXAMPLE_PART2: &str = EXAMPLE_PART1;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    EmptyHistory(Position),
+    InvalidNumber(Position),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyHistory(position) => write!(f, "{position}: empty history"),
+            Self::InvalidNumber(position) => {
+                write!(f, "{position}: invalid number `{}`", position.text)
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Differences are taken in 128 bits: each level can be up to twice as large as the one above,
+/// so even the first can overflow the `i64` values being extrapolated.
+fn calculate_differences(values: &[i128]) -> Result<Vec<i128>, Overflow> {
+    trace!(?values, "Calculating differences");
+
+    let mut differences: Vec<i128> = Vec::default();
+
+    for n in 1..values.len() {
+        differences.push(values[n].try_sub(values[n - 1])?);
+    }
+
+    trace!(?differences, "Calculated differences");
+
+    Ok(differences)
+}
+
+fn calculate_list_of_differences(values: &[i128]) -> Result<Vec<Vec<i128>>, Overflow> {
+    let mut list_of_differences: Vec<Vec<i128>> = Vec::default();
+
+    let mut differences = calculate_differences(values)?;
+
+    list_of_differences.push(differences.clone());
+
+    while !differences.iter().all(|difference| *difference == 0) {
+        differences = calculate_differences(&differences)?;
+
+        list_of_differences.push(differences.clone());
+    }
+
+    Ok(list_of_differences)
+}
+
+fn parse_line(line: Line) -> Result<Vec<i64>, ParseError> {
+    trace!(%line.text, "Parsing line");
+
+    if line.text.trim().is_empty() {
+        return Err(ParseError::EmptyHistory(line.whole()));
+    }
+
+    line.text
+        .split_whitespace()
+        .map(|number| {
+            number
+                .parse::<i64>()
+                .map_err(|_| ParseError::InvalidNumber(line.position(number)))
+        })
+        .collect()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    common::lines(input).map(parse_line).collect()
+}
+
+pub fn solve_part1(histories: &[Vec<i64>]) -> Result<i128, Overflow> {
+    checked::try_sum(
+        histories
+            .iter()
+            .map(|values| part1::extrapolate_next_value(values)),
+    )
+}
+
+pub fn solve_part2(histories: &[Vec<i64>]) -> Result<i128, Overflow> {
+    checked::try_sum(
+        histories
+            .iter()
+            .map(|values| part2::extrapolate_previous_value(values)),
+    )
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<i64>>;
+    type Error = ParseError;
+    type Part1 = i128;
+    type Part2 = i128;
+
+    const DAY: u8 = 9;
+
+    const EXAMPLE_PART1: &'static str = EXAMPLE_PART1;
+    const EXAMPLE_PART2: &'static str = EXAMPLE_PART2;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Overflow> {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Overflow> {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_error() {
+        let lines = "0 3 6 9 12 15
+1 3 6 10 1.5 21";
+
+        assert_eq!(
+            parse(lines),
+            Err(ParseError::InvalidNumber(Position {
+                line: 2,
+                column: 10,
+                text: String::from("1.5"),
+            }))
+        );
+
+        let lines = "0 3 6 9 12 15
+
+10 13 16 21 30 45";
+
+        assert_eq!(
+            parse(lines).unwrap_err().to_string(),
+            "line 2, column 1: empty history"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_never_panics(
+            input in prop_oneof![
+                any::<String>(),
+                r"(-?[0-9]{1,20}( -?[0-9]{1,3}){0,6}\n?){0,4}",
+            ]
+        ) {
+            let _ = parse(&input);
+        }
+    }
+}
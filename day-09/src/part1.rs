@@ -0,0 +1,76 @@
+use crate::calculate_list_of_differences;
+use common::{checked::Checked, Overflow};
+use tracing::trace;
+
+pub(crate) fn extrapolate_next_value(values: &[i64]) -> Result<i128, Overflow> {
+    trace!(?values, "Extrapolating next value for");
+
+    let values: Vec<i128> = values.iter().copied().map(i128::from).collect();
+
+    let list_of_differences = calculate_list_of_differences(&values)?;
+
+    let mut current_number: i128 = 0;
+
+    for difference_list in list_of_differences.iter().rev() {
+        current_number = current_number.try_add(*difference_list.last().unwrap_or(&0))?;
+    }
+
+    let next_value = values.last().unwrap().try_add(current_number)?;
+
+    trace!(%next_value, "Next value is");
+
+    Ok(next_value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part1, EXAMPLE_PART1};
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART1;
+
+        let result = solve_part1(&parse(lines).unwrap());
+
+        assert_eq!(result, Ok(114));
+    }
+
+    #[test]
+    fn test_extrapolate_next_value() {
+        let values = vec![0, 3, 6, 9, 12, 15];
+
+        let result = extrapolate_next_value(&values);
+
+        assert_eq!(result, Ok(18));
+
+        let values = vec![1, 3, 6, 10, 15, 21];
+
+        let result = extrapolate_next_value(&values);
+
+        assert_eq!(result, Ok(28));
+
+        let values = vec![10, 13, 16, 21, 30, 45];
+
+        let result = extrapolate_next_value(&values);
+
+        assert_eq!(result, Ok(68));
+    }
+
+    #[test]
+    fn test_extrapolate_past_i64() {
+        let values = vec![i64::MAX - 2, i64::MAX - 1, i64::MAX];
+
+        assert_eq!(
+            extrapolate_next_value(&values),
+            Ok(i128::from(i64::MAX) + 1)
+        );
+
+        // Alternating extremes double with every level of differences.
+        let values: Vec<i64> = (0..80)
+            .map(|n| if n % 2 == 0 { i64::MAX } else { i64::MIN })
+            .collect();
+
+        assert_eq!(extrapolate_next_value(&values), Err(Overflow));
+    }
+}
@@ -0,0 +1,79 @@
+use crate::calculate_list_of_differences;
+use common::{checked::Checked, Overflow};
+use tracing::trace;
+
+pub(crate) fn extrapolate_previous_value(values: &[i64]) -> Result<i128, Overflow> {
+    trace!(?values, "Extrapolating previous value for");
+
+    let values: Vec<i128> = values.iter().copied().map(i128::from).collect();
+
+    let list_of_differences = calculate_list_of_differences(&values)?;
+
+    let mut current_number: i128 = 0;
+
+    for difference_list in list_of_differences.iter().rev() {
+        current_number = difference_list
+            .first()
+            .unwrap_or(&0)
+            .try_sub(current_number)?;
+    }
+
+    let previous_value = values.first().unwrap().try_sub(current_number)?;
+
+    trace!(%previous_value, "Previous value is");
+
+    Ok(previous_value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part2, EXAMPLE_PART2};
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART2;
+
+        let result = solve_part2(&parse(lines).unwrap());
+
+        assert_eq!(result, Ok(2));
+    }
+
+    #[test]
+    fn test_extrapolate_previous_value() {
+        let values = vec![0, 3, 6, 9, 12, 15];
+
+        let result = extrapolate_previous_value(&values);
+
+        assert_eq!(result, Ok(-3));
+
+        let values = vec![1, 3, 6, 10, 15, 21];
+
+        let result = extrapolate_previous_value(&values);
+
+        assert_eq!(result, Ok(0));
+
+        let values = vec![10, 13, 16, 21, 30, 45];
+
+        let result = extrapolate_previous_value(&values);
+
+        assert_eq!(result, Ok(5));
+    }
+
+    #[test]
+    fn test_extrapolate_past_i64() {
+        let values = vec![i64::MIN + 2, i64::MIN + 3, i64::MIN + 4];
+
+        assert_eq!(
+            extrapolate_previous_value(&values),
+            Ok(i128::from(i64::MIN) + 1)
+        );
+
+        let values = vec![i64::MIN, i64::MIN + 1];
+
+        assert_eq!(
+            extrapolate_previous_value(&values),
+            Ok(i128::from(i64::MIN) - 1)
+        );
+    }
+}
@@ -0,0 +1,433 @@
+mod part1;
+mod part2;
+pub mod visualise;
+
+use std::{error::Error, fmt};
+
+use common::{Overflow, Position, Solution};
+use grid::{Direction, Grid, Point};
+use tracing::trace;
+
+pub const EXAMPLE_PART1: &str = "-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF";
+
+pub const EXAMPLE_PART2: &str = "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    Empty,
+    InvalidTile(Position),
+    RaggedLine(Position, usize),
+    MissingStart,
+    DuplicateStart(Position, Position),
+    UnconnectedStart(Position),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the maze is empty"),
+            Self::InvalidTile(position) => {
+                write!(f, "{position}: invalid tile `{}`", position.text)
+            }
+            Self::RaggedLine(position, width) => write!(
+                f,
+                "{position}: expected {width} characters, got {} in `{}`",
+                position.text.chars().count(),
+                position.text
+            ),
+            Self::MissingStart => write!(f, "no starting position `S` in the maze"),
+            Self::DuplicateStart(position, first) => {
+                write!(f, "{position}: second starting position, first at {first}")
+            }
+            Self::UnconnectedStart(position) => write!(
+                f,
+                "{position}: starting position is not connected to two pipes"
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<grid::ParseError> for ParseError {
+    fn from(error: grid::ParseError) -> Self {
+        match error {
+            grid::ParseError::Empty => Self::Empty,
+            grid::ParseError::InvalidCell(position) => Self::InvalidTile(position),
+            grid::ParseError::RaggedLine(position, width) => Self::RaggedLine(position, width),
+        }
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub enum TileType {
+    PipeVertical,
+    PipeHorizontal,
+    BendNe,
+    BendNw,
+    BendSw,
+    BendSe,
+    Ground,
+    StartingPosition,
+}
+
+impl TileType {
+    pub const fn from_char(c: char) -> Option<Self> {
+        match c {
+            '|' => Some(Self::PipeVertical),
+            '-' => Some(Self::PipeHorizontal),
+            'L' => Some(Self::BendNe),
+            'J' => Some(Self::BendNw),
+            '7' => Some(Self::BendSw),
+            'F' => Some(Self::BendSe),
+            'S' => Some(Self::StartingPosition),
+            '.' => Some(Self::Ground),
+            _ => None,
+        }
+    }
+
+    pub fn has_north(&self) -> bool {
+        [Self::PipeVertical, Self::BendNe, Self::BendNw].contains(self)
+    }
+
+    pub fn has_south(&self) -> bool {
+        [Self::PipeVertical, Self::BendSe, Self::BendSw].contains(self)
+    }
+
+    pub fn has_east(&self) -> bool {
+        [Self::PipeHorizontal, Self::BendNe, Self::BendSe].contains(self)
+    }
+
+    pub fn has_west(&self) -> bool {
+        [Self::PipeHorizontal, Self::BendNw, Self::BendSw].contains(self)
+    }
+
+    pub const fn next(&self, entry: &Direction) -> Option<Direction> {
+        match self {
+            Self::PipeVertical => match entry {
+                Direction::South => Some(Direction::North),
+                Direction::North => Some(Direction::South),
+                _ => None,
+            },
+            Self::PipeHorizontal => match entry {
+                Direction::East => Some(Direction::West),
+                Direction::West => Some(Direction::East),
+                _ => None,
+            },
+            Self::BendNe => match entry {
+                Direction::North => Some(Direction::East),
+                Direction::East => Some(Direction::North),
+                _ => None,
+            },
+            Self::BendNw => match entry {
+                Direction::North => Some(Direction::West),
+                Direction::West => Some(Direction::North),
+                _ => None,
+            },
+            Self::BendSe => match entry {
+                Direction::South => Some(Direction::East),
+                Direction::East => Some(Direction::South),
+                _ => None,
+            },
+            Self::BendSw => match entry {
+                Direction::South => Some(Direction::West),
+                Direction::West => Some(Direction::South),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    pub const fn initial_direction(&self) -> Direction {
+        match self {
+            Self::PipeVertical | Self::BendSe | Self::BendSw => Direction::South,
+            Self::BendNe | Self::BendNw => Direction::North,
+            _ => Direction::West,
+        }
+    }
+
+    pub const fn can_contain_tiles_below(&self) -> bool {
+        matches!(self, Self::PipeVertical | Self::BendSe | Self::BendSw)
+    }
+}
+
+#[derive(Debug)]
+pub struct Maze {
+    pub tiles: Grid<TileType>,
+    pub starting_point: Point,
+}
+
+fn get_starting_point_tile_type(
+    north: Option<&TileType>,
+    south: Option<&TileType>,
+    east: Option<&TileType>,
+    west: Option<&TileType>,
+) -> Option<TileType> {
+    if let Some(north_tile) = north {
+        if north_tile.has_south() {
+            if let Some(south_tile) = south {
+                if south_tile.has_north() {
+                    return Some(TileType::PipeVertical);
+                }
+            }
+
+            if let Some(east_tile) = east {
+                if east_tile.has_west() {
+                    return Some(TileType::BendNe);
+                }
+            }
+
+            if let Some(west_tile) = west {
+                if west_tile.has_east() {
+                    return Some(TileType::BendNw);
+                }
+            }
+        }
+    }
+
+    if let Some(south_tile) = south {
+        if south_tile.has_north() {
+            // already checked north-south, skip here
+
+            if let Some(east_tile) = east {
+                if east_tile.has_west() {
+                    return Some(TileType::BendSe);
+                }
+            }
+
+            if let Some(west_tile) = west {
+                if west_tile.has_east() {
+                    return Some(TileType::BendSw);
+                }
+            }
+        }
+    }
+
+    if let Some(east_tile) = east {
+        if east_tile.has_west() {
+            if let Some(west_tile) = west {
+                if west_tile.has_east() {
+                    return Some(TileType::PipeHorizontal);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+pub fn find_loop(maze: &Maze) -> Option<Vec<Point>> {
+    let starting_point = maze.starting_point;
+
+    let mut loop_vec: Vec<Point> = vec![starting_point];
+
+    let mut direction: Direction = maze.tiles[starting_point].initial_direction();
+
+    loop {
+        let current_node_index = *loop_vec.last().unwrap();
+
+        trace!(%current_node_index, "Current Node Index");
+
+        if loop_vec.len() > 1 && current_node_index == starting_point {
+            break;
+        }
+
+        let current_node = &maze.tiles[current_node_index];
+
+        trace!(?current_node, "Current Node Tile");
+
+        trace!(?direction, "Direction Entry");
+
+        let new_direction = current_node.next(&direction);
+
+        if new_direction.is_none() {
+            trace!("Invalid step");
+
+            return None;
+        }
+
+        let new_direction = new_direction.unwrap();
+
+        let Some(next_node_index) = maze.tiles.step(current_node_index, new_direction) else {
+            trace!("Step leaves the maze");
+
+            return None;
+        };
+
+        loop_vec.push(next_node_index);
+
+        direction = new_direction.opposite();
+    }
+
+    Some(loop_vec)
+}
+
+fn start_position(point: Point) -> Position {
+    Position {
+        line: point.y + 1,
+        column: point.x + 1,
+        text: String::from("S"),
+    }
+}
+
+pub fn parse(input: &str) -> Result<Maze, ParseError> {
+    let mut tiles = Grid::parse(input, TileType::from_char)?;
+
+    let starting_points: Vec<Point> = tiles
+        .iter()
+        .filter(|(_, tile)| **tile == TileType::StartingPosition)
+        .map(|(point, _)| point)
+        .take(2)
+        .collect();
+
+    let starting_point = match starting_points[..] {
+        [] => return Err(ParseError::MissingStart),
+        [starting_point] => starting_point,
+        [first, second, ..] => {
+            return Err(ParseError::DuplicateStart(
+                start_position(second),
+                start_position(first),
+            ))
+        }
+    };
+
+    trace!(%starting_point, "Starting Point");
+
+    let neighbour = |direction: Direction| {
+        tiles
+            .step(starting_point, direction)
+            .map(|point| &tiles[point])
+    };
+
+    let new_starting_tile_type = get_starting_point_tile_type(
+        neighbour(Direction::North),
+        neighbour(Direction::South),
+        neighbour(Direction::East),
+        neighbour(Direction::West),
+    );
+
+    trace!(?new_starting_tile_type, "Starting Tile");
+
+    let Some(new_starting_tile_type) = new_starting_tile_type else {
+        return Err(ParseError::UnconnectedStart(start_position(starting_point)));
+    };
+
+    tiles[starting_point] = new_starting_tile_type;
+
+    Ok(Maze {
+        tiles,
+        starting_point,
+    })
+}
+
+pub fn solve_part1(maze: &Maze) -> usize {
+    part1::compute_half_loop_size(maze)
+}
+
+pub fn solve_part2(maze: &Maze) -> usize {
+    part2::count_tiles_contained_within_loop(maze)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Maze;
+    type Error = ParseError;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const DAY: u8 = 10;
+
+    const EXAMPLE_PART1: &'static str = EXAMPLE_PART1;
+    const EXAMPLE_PART2: &'static str = EXAMPLE_PART2;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Overflow> {
+        Ok(solve_part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Overflow> {
+        Ok(solve_part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_error() {
+        let lines = "-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JX";
+
+        assert_eq!(
+            parse(lines).unwrap_err(),
+            ParseError::InvalidTile(Position {
+                line: 5,
+                column: 5,
+                text: String::from("X"),
+            })
+        );
+
+        let lines = ".....
+.F-7.
+.|.|.
+.L-J.
+.....";
+
+        assert_eq!(parse(lines).unwrap_err(), ParseError::MissingStart);
+
+        let lines = ".....
+.S-7.
+.|.|.
+.L-S.
+.....";
+
+        assert_eq!(
+            parse(lines).unwrap_err().to_string(),
+            "line 4, column 4: second starting position, first at line 2, column 2"
+        );
+
+        let lines = ".....
+..S..
+.....";
+
+        assert!(matches!(parse(lines), Err(ParseError::UnconnectedStart(_))));
+
+        assert!(matches!(
+            parse(".S-7.\n.|"),
+            Err(ParseError::RaggedLine(_, 5))
+        ));
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_never_panics(
+            input in prop_oneof![
+                any::<String>(),
+                r"[|\-LJ7FS.\n]{0,60}",
+            ]
+        ) {
+            let _ = parse(&input);
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use grid::Point;
+
+use crate::{find_loop, Maze};
+
+#[derive(Debug)]
+struct Loop {
+    nodes: Vec<Point>,
+}
+
+impl Loop {
+    fn half_size(&self) -> usize {
+        if self.nodes.is_empty() {
+            return 0;
+        }
+
+        self.nodes.len() / 2
+    }
+}
+
+pub(crate) fn compute_half_loop_size(maze: &Maze) -> usize {
+    let Some(loop_vec) = find_loop(maze) else {
+        return 0;
+    };
+
+    let built_loop = Loop { nodes: loop_vec };
+
+    built_loop.half_size()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, solve_part1, EXAMPLE_PART1};
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART1;
+
+        let result = solve_part1(&parse(lines).unwrap());
+
+        assert_eq!(result, 4);
+
+        let lines = "..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...";
+
+        let result = solve_part1(&parse(lines).unwrap());
+
+        assert_eq!(result, 8);
+    }
+}
@@ -0,0 +1,84 @@
+use grid::Point;
+
+use crate::{find_loop, Maze};
+use tracing::trace;
+
+/// The tiles inside `loop_vec`, found by scanning each row and flipping between outside and
+/// inside at every loop tile that continues below.
+pub(crate) fn tiles_within_loop(maze: &Maze, loop_vec: &[Point]) -> Vec<Point> {
+    let mut internal_tiles = Vec::default();
+
+    for (index_row, row) in maze.tiles.rows().enumerate() {
+        let mut inside_tile = false;
+
+        for (index_column, tile) in row.iter().enumerate() {
+            trace!(index_row, index_column, "Scanning");
+
+            let point = Point::new(index_column, index_row);
+
+            if loop_vec.contains(&point) {
+                if tile.can_contain_tiles_below() {
+                    inside_tile = !inside_tile;
+                }
+            } else if inside_tile {
+                trace!(index_row, index_column, "Within the loop");
+
+                internal_tiles.push(point);
+            }
+        }
+    }
+
+    internal_tiles
+}
+
+pub(crate) fn count_tiles_contained_within_loop(maze: &Maze) -> usize {
+    let Some(loop_vec) = find_loop(maze) else {
+        return 0;
+    };
+
+    tiles_within_loop(maze, &loop_vec).len()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, solve_part2, EXAMPLE_PART2};
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART2;
+
+        let result = solve_part2(&parse(lines).unwrap());
+
+        assert_eq!(result, 4);
+
+        let lines = ".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+
+        let result = solve_part2(&parse(lines).unwrap());
+
+        assert_eq!(result, 8);
+
+        let lines = "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+
+        let result = solve_part2(&parse(lines).unwrap());
+
+        assert_eq!(result, 10);
+    }
+}
@@ -0,0 +1,93 @@
+use common::Part;
+use grid::render::{Colour, Frame};
+
+use crate::{find_loop, part2::tiles_within_loop, Maze, TileType};
+
+const fn glyph(tile: &TileType) -> char {
+    match tile {
+        TileType::PipeVertical => '│',
+        TileType::PipeHorizontal => '─',
+        TileType::BendNe => '└',
+        TileType::BendNw => '┘',
+        TileType::BendSw => '┐',
+        TileType::BendSe => '┌',
+        TileType::Ground => '.',
+        TileType::StartingPosition => 'S',
+    }
+}
+
+/// The maze, then the loop through the start traced out and, for part two, the tiles it encloses.
+pub fn frames(maze: &Maze, part: Part) -> Vec<Frame> {
+    let mut start = Frame::new(&maze.tiles, glyph).with_caption("Maze");
+
+    start
+        .highlight([maze.starting_point], Colour::Green)
+        .mark([maze.starting_point], 'S');
+
+    let mut frames = vec![start.clone()];
+
+    let Some(loop_vec) = find_loop(maze) else {
+        frames.push(start.with_caption("No loop through the start"));
+        return frames;
+    };
+
+    let length = loop_vec.len() - 1;
+
+    let mut traced = start.clone().with_caption(format!(
+        "Loop of {length} tiles, {} at the farthest",
+        length / 2
+    ));
+
+    traced.path(&loop_vec, Colour::Green);
+    frames.push(traced);
+
+    if part == Part::Two {
+        let enclosed = tiles_within_loop(maze, &loop_vec);
+
+        let mut filled = start.with_caption(format!("{} tiles enclosed", enclosed.len()));
+
+        filled
+            .mark(
+                maze.tiles
+                    .points()
+                    .filter(|point| !loop_vec.contains(point)),
+                '.',
+            )
+            .region(loop_vec.iter().copied(), Colour::Green)
+            .highlight(enclosed.iter().copied(), Colour::Yellow)
+            .mark(enclosed, 'I');
+
+        frames.push(filled);
+    }
+
+    frames
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, EXAMPLE_PART1, EXAMPLE_PART2};
+
+    #[test]
+    fn test_frames() {
+        let traced = frames(&parse(EXAMPLE_PART1).unwrap(), Part::One);
+
+        assert_eq!(traced.len(), 2);
+        assert_eq!(traced[1].caption, "Loop of 8 tiles, 4 at the farthest");
+        assert_eq!(
+            traced[1].to_plain(),
+            "Loop of 8 tiles, 4 at the farthest
+─└│┌┐
+┐>>v│
+└^┐v│
+─^<<│
+└│─┘┌"
+        );
+
+        let filled = frames(&parse(EXAMPLE_PART2).unwrap(), Part::Two);
+
+        assert_eq!(filled.len(), 3);
+        assert_eq!(filled[2].caption, "4 tiles enclosed");
+        assert_eq!(filled[2].to_plain().lines().nth(7), Some(".│II│.│II│."));
+    }
+}
@@ -0,0 +1,223 @@
+mod part1;
+mod part2;
+
+use std::{error::Error, fmt};
+
+use common::{checked, checked::Checked, Overflow, Position, Solution};
+use grid::{Grid, Point};
+use tracing::trace;
+
+pub const EXAMPLE_PART1: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+pub const EXAMPLE_PART2: &str = EXAMPLE_PART1;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    Empty,
+    InvalidCharacter(Position),
+    RaggedLine(Position, usize),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the image is empty"),
+            Self::InvalidCharacter(position) => write!(
+                f,
+                "{position}: invalid character `{}`, expected `.` or `#`",
+                position.text
+            ),
+            Self::RaggedLine(position, width) => write!(
+                f,
+                "{position}: expected {width} characters, got {} in `{}`",
+                position.text.chars().count(),
+                position.text
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<grid::ParseError> for ParseError {
+    fn from(error: grid::ParseError) -> Self {
+        match error {
+            grid::ParseError::Empty => Self::Empty,
+            grid::ParseError::InvalidCell(position) => Self::InvalidCharacter(position),
+            grid::ParseError::RaggedLine(position, width) => Self::RaggedLine(position, width),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Image {
+    pub galaxies: Vec<Point>,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Image {
+    /// Galaxy positions after expansion, in 128 bits since a large expansion rate can push them
+    /// past `usize::MAX`.
+    fn expand(&self, expansion_rate: usize) -> Result<Vec<(u128, u128)>, Overflow> {
+        let expansion_rate: u128 = checked::convert(expansion_rate.try_sub(1)?)?;
+
+        let empty_rows: Vec<usize> = (0..self.height)
+            .filter(|row| !self.galaxies.iter().any(|galaxy| galaxy.y == *row))
+            .collect();
+
+        let empty_columns: Vec<usize> = (0..self.width)
+            .filter(|column| !self.galaxies.iter().any(|galaxy| galaxy.x == *column))
+            .collect();
+
+        trace!(?empty_rows, ?empty_columns, "Expanding rows and columns");
+
+        let expand = |position: usize, offset: usize| -> Result<u128, Overflow> {
+            let offset: u128 = checked::convert(offset)?;
+
+            checked::convert::<u128, _>(position)?.try_add(offset.try_mul(expansion_rate)?)
+        };
+
+        self.galaxies
+            .iter()
+            .map(|galaxy| {
+                let row_offset = empty_rows.iter().filter(|row| **row < galaxy.y).count();
+                let column_offset = empty_columns
+                    .iter()
+                    .filter(|column| **column < galaxy.x)
+                    .count();
+
+                Ok((
+                    expand(galaxy.x, column_offset)?,
+                    expand(galaxy.y, row_offset)?,
+                ))
+            })
+            .collect()
+    }
+}
+
+pub fn sum_galaxy_pair_distances(image: &Image, expansion_rate: usize) -> Result<u128, Overflow> {
+    let galaxies = image.expand(expansion_rate)?;
+
+    trace!(?galaxies, "Expanded");
+
+    checked::try_sum(galaxies.iter().enumerate().flat_map(|(index, galaxy)| {
+        let distances: Vec<Result<u128, Overflow>> = galaxies
+            .iter()
+            .skip(index + 1)
+            .map(|other| {
+                other
+                    .0
+                    .abs_diff(galaxy.0)
+                    .try_add(other.1.abs_diff(galaxy.1))
+            })
+            .collect();
+
+        trace!(?distances, "Distances");
+
+        distances
+    }))
+}
+
+pub fn parse(input: &str) -> Result<Image, ParseError> {
+    let image = Grid::parse(input, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+
+    Ok(Image {
+        galaxies: image
+            .iter()
+            .filter(|(_, galaxy)| **galaxy)
+            .map(|(point, _)| point)
+            .collect(),
+        width: image.width(),
+        height: image.height(),
+    })
+}
+
+pub fn solve_part1(image: &Image) -> Result<u128, Overflow> {
+    sum_galaxy_pair_distances(image, part1::EXPANSION_RATE)
+}
+
+pub fn solve_part2(image: &Image) -> Result<u128, Overflow> {
+    sum_galaxy_pair_distances(image, part2::EXPANSION_RATE)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Image;
+    type Error = ParseError;
+    type Part1 = u128;
+    type Part2 = u128;
+
+    const DAY: u8 = 11;
+
+    const EXAMPLE_PART1: &'static str = EXAMPLE_PART1;
+    const EXAMPLE_PART2: &'static str = EXAMPLE_PART2;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Overflow> {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Overflow> {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_error() {
+        let lines = "...#......
+.......#..
+#...o.....";
+
+        assert_eq!(
+            parse(lines).unwrap_err(),
+            ParseError::InvalidCharacter(Position {
+                line: 3,
+                column: 5,
+                text: String::from("o"),
+            })
+        );
+
+        let lines = "...#......
+.......#.";
+
+        assert_eq!(
+            parse(lines).unwrap_err().to_string(),
+            "line 2, column 1: expected 10 characters, got 9 in `.......#.`"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_never_panics(
+            input in prop_oneof![
+                any::<String>(),
+                r"[.#\n]{0,80}",
+            ]
+        ) {
+            let _ = parse(&input);
+        }
+    }
+}
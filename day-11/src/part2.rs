@@ -0,0 +1,58 @@
+pub(crate) const EXPANSION_RATE: usize = 1_000_000;
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, sum_galaxy_pair_distances, EXAMPLE_PART2};
+    use common::Overflow;
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART2;
+
+        let result = sum_galaxy_pair_distances(&parse(lines).unwrap(), 2);
+
+        assert_eq!(result, Ok(374));
+
+        let lines = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+        let result = sum_galaxy_pair_distances(&parse(lines).unwrap(), 10);
+
+        assert_eq!(result, Ok(1030));
+
+        let lines = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+        let result = sum_galaxy_pair_distances(&parse(lines).unwrap(), 100);
+
+        assert_eq!(result, Ok(8410));
+    }
+
+    #[test]
+    fn test_expansion_past_usize() {
+        let image = parse("#.#").unwrap();
+
+        assert_eq!(
+            sum_galaxy_pair_distances(&image, usize::MAX),
+            Ok(u128::from(u64::MAX) + 1)
+        );
+
+        assert_eq!(sum_galaxy_pair_distances(&image, 0), Err(Overflow));
+    }
+}
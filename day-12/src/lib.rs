@@ -0,0 +1,253 @@
+mod part1;
+mod part2;
+
+use std::{collections::HashMap, error::Error, fmt};
+
+use common::{checked::Checked, Line, Overflow, Position, Solution, Stats};
+use tracing::trace;
+
+pub const EXAMPLE_PART1: &str = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+pub const EXAMPLE_PART2: &str = EXAMPLE_PART1;
+
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub springs: String,
+    pub groups: Vec<usize>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    MissingGroups(Position),
+    InvalidSpring(Position),
+    InvalidGroup(Position),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingGroups(position) => write!(
+                f,
+                "{position}: expected springs and groups, got `{}`",
+                position.text
+            ),
+            Self::InvalidSpring(position) => write!(
+                f,
+                "{position}: invalid spring `{}`, expected `.`, `#` or `?`",
+                position.text
+            ),
+            Self::InvalidGroup(position) => {
+                write!(f, "{position}: invalid group size `{}`", position.text)
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+#[allow(
+    clippy::cast_possible_wrap,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::needless_pass_by_value
+)]
+fn arrange(
+    line: String,
+    groups: &[usize],
+    cache: &mut HashMap<String, u128>,
+    stats: &mut Stats,
+) -> Result<u128, Overflow> {
+    trace!(%line, "Arranging line");
+
+    let key = format!("{}{:?}", &line, &groups);
+
+    if cache.contains_key(&key) {
+        stats.add("cache_hits", 1);
+
+        return Ok(*cache.get(&key).unwrap());
+    }
+
+    if groups.is_empty() {
+        return Ok(u128::from(!line.chars().any(|c| c == '#')));
+    }
+
+    let mut arrangements = 0;
+
+    let next_groups = Vec::from(&groups[1..]);
+
+    // Group sizes can be anything up to `usize::MAX`, so their total is only safe in 128 bits.
+    let iterations: i128 = line.len() as i128
+        - next_groups.iter().map(|group| *group as i128).sum::<i128>()
+        + next_groups.len() as i128
+        - *groups.first().unwrap() as i128
+        + 1;
+
+    if iterations <= 0 {
+        cache.insert(key, arrangements);
+
+        return Ok(arrangements);
+    }
+
+    let iterations = iterations as usize;
+
+    for n in 0..iterations {
+        let operational = String::from(".").repeat(n);
+
+        let damaged = String::from("#").repeat(*groups.first().unwrap());
+
+        let to_test = format!("{}{}.", &operational, &damaged);
+
+        let difference = line
+            .chars()
+            .zip(to_test.chars())
+            .filter(|(a, b)| a != b && *a != '?')
+            .count();
+
+        if difference == 0 {
+            let next_line = line
+                .get(to_test.len()..)
+                .map_or_else(String::new, std::string::ToString::to_string);
+
+            arrangements = arrangements.try_add(arrange(next_line, &next_groups, cache, stats)?)?;
+        }
+    }
+
+    cache.insert(key, arrangements);
+
+    Ok(arrangements)
+}
+
+pub fn count_arrangements(record: &Record, stats: &mut Stats) -> Result<u128, Overflow> {
+    let mut cache: HashMap<String, u128> = HashMap::default();
+
+    let arrangements = arrange(record.springs.clone(), &record.groups, &mut cache, stats);
+
+    stats.add("cache_entries", cache.len() as u64);
+
+    arrangements
+}
+
+fn parse_record(line: Line) -> Result<Record, ParseError> {
+    trace!(%line.text, "Parsing line");
+
+    let Some((springs, groups)) = line.text.split_once(' ') else {
+        return Err(ParseError::MissingGroups(line.whole()));
+    };
+
+    if let Some((index, c)) = springs
+        .char_indices()
+        .find(|(_, c)| !['.', '#', '?'].contains(c))
+    {
+        return Err(ParseError::InvalidSpring(
+            line.position(&springs[index..index + c.len_utf8()]),
+        ));
+    }
+
+    let groups = groups
+        .split(',')
+        .map(|number| {
+            number
+                .parse::<usize>()
+                .map_err(|_| ParseError::InvalidGroup(line.position(number)))
+        })
+        .collect::<Result<Vec<usize>, ParseError>>()?;
+
+    Ok(Record {
+        springs: springs.to_string(),
+        groups,
+    })
+}
+
+pub fn parse(input: &str) -> Result<Vec<Record>, ParseError> {
+    common::lines(input).map(parse_record).collect()
+}
+
+pub fn solve_part1(records: &[Record]) -> Result<u128, Overflow> {
+    part1::sum_possible_arrangements(records, &mut Stats::default())
+}
+
+pub fn solve_part2(records: &[Record]) -> Result<u128, Overflow> {
+    part2::sum_possible_arrangements(records, &mut Stats::default())
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Record>;
+    type Error = ParseError;
+    type Part1 = u128;
+    type Part2 = u128;
+
+    const DAY: u8 = 12;
+
+    const EXAMPLE_PART1: &'static str = EXAMPLE_PART1;
+    const EXAMPLE_PART2: &'static str = EXAMPLE_PART2;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Overflow> {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Overflow> {
+        solve_part2(input)
+    }
+
+    fn part1_with_stats(input: &Self::Input, stats: &mut Stats) -> Result<Self::Part1, Overflow> {
+        part1::sum_possible_arrangements(input, stats)
+    }
+
+    fn part2_with_stats(input: &Self::Input, stats: &mut Stats) -> Result<Self::Part2, Overflow> {
+        part2::sum_possible_arrangements(input, stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_error() {
+        let lines = "???.### 1,1,3
+.??..??...?##. 1,,3";
+
+        assert_eq!(
+            parse(lines).unwrap_err(),
+            ParseError::InvalidGroup(Position {
+                line: 2,
+                column: 18,
+                text: String::new(),
+            })
+        );
+
+        assert_eq!(
+            parse("?#?#?#?#?#?#?#?").unwrap_err().to_string(),
+            "line 1, column 1: expected springs and groups, got `?#?#?#?#?#?#?#?`"
+        );
+
+        assert!(matches!(
+            parse("????.#..x#... 4,1,1"),
+            Err(ParseError::InvalidSpring(_))
+        ));
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_never_panics(
+            input in prop_oneof![
+                any::<String>(),
+                r"([?.#]{0,10} [0-9]{1,3}(,[0-9]{0,2}){0,3}\n?){0,4}",
+            ]
+        ) {
+            let _ = parse(&input);
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use common::{checked, Overflow, Stats};
+
+use crate::{count_arrangements, Record};
+
+pub(crate) fn possible_arrangements(record: &Record, stats: &mut Stats) -> Result<u128, Overflow> {
+    count_arrangements(record, stats)
+}
+
+pub(crate) fn sum_possible_arrangements(
+    records: &[Record],
+    stats: &mut Stats,
+) -> Result<u128, Overflow> {
+    checked::try_sum(
+        records
+            .iter()
+            .map(|record| possible_arrangements(record, stats)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part1, EXAMPLE_PART1};
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART1;
+
+        let result = solve_part1(&parse(lines).unwrap());
+
+        assert_eq!(result, Ok(21));
+    }
+
+    #[test]
+    fn test_single_line() {
+        let line = "???.### 1,1,3";
+
+        let result = possible_arrangements(&parse(line).unwrap()[0], &mut Stats::default());
+
+        assert_eq!(result, Ok(1));
+
+        let line = ".??..??...?##. 1,1,3";
+
+        let result = possible_arrangements(&parse(line).unwrap()[0], &mut Stats::default());
+
+        assert_eq!(result, Ok(4));
+
+        let line = "?#?#?#?#?#?#?#? 1,3,1,6";
+
+        let result = possible_arrangements(&parse(line).unwrap()[0], &mut Stats::default());
+
+        assert_eq!(result, Ok(1));
+
+        let line = "????.#...#... 4,1,1";
+
+        let result = possible_arrangements(&parse(line).unwrap()[0], &mut Stats::default());
+
+        assert_eq!(result, Ok(1));
+
+        let line = "????.######..#####. 1,6,5";
+
+        let result = possible_arrangements(&parse(line).unwrap()[0], &mut Stats::default());
+
+        assert_eq!(result, Ok(4));
+
+        let line = "?###???????? 3,2,1";
+
+        let result = possible_arrangements(&parse(line).unwrap()[0], &mut Stats::default());
+
+        assert_eq!(result, Ok(10));
+    }
+
+    #[test]
+    fn test_wide_arrangements() {
+        // `groups` springs of one, spread out in every way over `groups * 2 - 1 + spare` unknowns.
+        let record = |groups: usize, spare: usize| {
+            let line = format!(
+                "{} {}",
+                "?".repeat(groups * 2 - 1 + spare),
+                vec!["1"; groups].join(",")
+            );
+
+            parse(&line).unwrap().remove(0)
+        };
+
+        // 80 choose 40.
+        assert_eq!(
+            possible_arrangements(&record(40, 40), &mut Stats::default()),
+            Ok(107_507_208_733_336_176_461_620)
+        );
+
+        // 140 choose 70.
+        assert_eq!(
+            possible_arrangements(&record(70, 70), &mut Stats::default()),
+            Err(Overflow)
+        );
+    }
+
+    #[test]
+    fn test_huge_groups() {
+        let line = format!("??? {},{}", usize::MAX, usize::MAX);
+
+        assert_eq!(solve_part1(&parse(&line).unwrap()), Ok(0));
+    }
+}
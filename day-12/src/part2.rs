@@ -0,0 +1,111 @@
+use common::{checked::Checked, Overflow, Stats};
+use rayon::prelude::*;
+
+use crate::{count_arrangements, Record};
+
+fn unfold(record: &Record) -> Record {
+    let mut unfolded_springs = String::default();
+
+    let mut unfolded_groups: Vec<usize> = Vec::default();
+
+    for n in 0..5 {
+        if n > 0 {
+            unfolded_springs.push('?');
+        }
+
+        unfolded_springs.push_str(&record.springs);
+
+        unfolded_groups.append(&mut record.groups.clone());
+    }
+
+    Record {
+        springs: unfolded_springs,
+        groups: unfolded_groups,
+    }
+}
+
+pub(crate) fn possible_arrangements(record: &Record, stats: &mut Stats) -> Result<u128, Overflow> {
+    count_arrangements(&unfold(record), stats)
+}
+
+pub(crate) fn sum_possible_arrangements(
+    records: &[Record],
+    stats: &mut Stats,
+) -> Result<u128, Overflow> {
+    let (sum, record_stats) = records
+        .par_iter()
+        .map(|record| {
+            let mut record_stats = Stats::default();
+
+            (
+                possible_arrangements(record, &mut record_stats),
+                record_stats,
+            )
+        })
+        .reduce(
+            || (Ok(0), Stats::default()),
+            |(a, mut a_stats), (b, b_stats)| {
+                a_stats.merge(&b_stats);
+
+                (a.and_then(|a| a.try_add(b?)), a_stats)
+            },
+        );
+
+    stats.merge(&record_stats);
+
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part2, EXAMPLE_PART2};
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART2;
+
+        let result = solve_part2(&parse(lines).unwrap());
+
+        assert_eq!(result, Ok(525_152));
+    }
+
+    #[test]
+    fn test_single_line() {
+        let line = "???.### 1,1,3";
+
+        let result = possible_arrangements(&parse(line).unwrap()[0], &mut Stats::default());
+
+        assert_eq!(result, Ok(1));
+
+        let line = ".??..??...?##. 1,1,3";
+
+        let result = possible_arrangements(&parse(line).unwrap()[0], &mut Stats::default());
+
+        assert_eq!(result, Ok(16384));
+
+        let line = "?#?#?#?#?#?#?#? 1,3,1,6";
+
+        let result = possible_arrangements(&parse(line).unwrap()[0], &mut Stats::default());
+
+        assert_eq!(result, Ok(1));
+
+        let line = "????.#...#... 4,1,1";
+
+        let result = possible_arrangements(&parse(line).unwrap()[0], &mut Stats::default());
+
+        assert_eq!(result, Ok(16));
+
+        let line = "????.######..#####. 1,6,5";
+
+        let result = possible_arrangements(&parse(line).unwrap()[0], &mut Stats::default());
+
+        assert_eq!(result, Ok(2500));
+
+        let line = "?###???????? 3,2,1";
+
+        let result = possible_arrangements(&parse(line).unwrap()[0], &mut Stats::default());
+
+        assert_eq!(result, Ok(506_250));
+    }
+}
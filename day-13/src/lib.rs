@@ -0,0 +1,174 @@
+mod part1;
+mod part2;
+
+use std::{error::Error, fmt};
+
+use common::{checked, checked::Checked, Line, Overflow, Position, Solution};
+use grid::Grid;
+use tracing::trace;
+
+pub const EXAMPLE_PART1: &str = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+pub const EXAMPLE_PART2: &str = EXAMPLE_PART1;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    InvalidCharacter(Position),
+    RaggedLine(Position, usize),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidCharacter(position) => write!(
+                f,
+                "{position}: invalid character `{}`, expected `.` or `#`",
+                position.text
+            ),
+            Self::RaggedLine(position, width) => write!(
+                f,
+                "{position}: expected {width} characters, got {} in `{}`",
+                position.text.chars().count(),
+                position.text
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+fn parse_grid(lines: &[Line]) -> Result<Grid<char>, ParseError> {
+    trace!(lines = lines.len(), "Parsing grid");
+
+    Grid::from_lines(lines.iter().copied(), |c| {
+        ['.', '#'].contains(&c).then_some(c)
+    })
+    .map_err(|error| match error {
+        grid::ParseError::InvalidCell(position) => ParseError::InvalidCharacter(position),
+        grid::ParseError::RaggedLine(position, width) => ParseError::RaggedLine(position, width),
+        grid::ParseError::Empty => unreachable!("blank lines separate the grids"),
+    })
+}
+
+pub fn parse(input: &str) -> Result<Vec<Grid<char>>, ParseError> {
+    let mut grids: Vec<Vec<Line>> = vec![Vec::default()];
+
+    for line in common::lines(input) {
+        if line.text.is_empty() {
+            grids.push(Vec::default());
+        } else {
+            grids.last_mut().unwrap().push(line);
+        }
+    }
+
+    grids
+        .iter()
+        .filter(|lines| !lines.is_empty())
+        .map(|lines| parse_grid(lines))
+        .collect()
+}
+
+pub fn solve_part1(grids: &[Grid<char>]) -> Result<u64, Overflow> {
+    checked::try_sum(grids.iter().map(part1::summarise))
+}
+
+pub fn solve_part2(grids: &[Grid<char>]) -> Result<u64, Overflow> {
+    checked::try_sum(grids.iter().map(part2::summarise))
+}
+
+/// Adds up the lines before each reflection, those above horizontal ones counting a hundred times.
+fn summarise(columns: usize, rows: usize) -> Result<u64, Overflow> {
+    checked::convert::<u64, _>(columns)?.try_add(100.try_mul(checked::convert(rows)?)?)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Grid<char>>;
+    type Error = ParseError;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    const DAY: u8 = 13;
+
+    const EXAMPLE_PART1: &'static str = EXAMPLE_PART1;
+    const EXAMPLE_PART2: &'static str = EXAMPLE_PART2;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Overflow> {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Overflow> {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_error() {
+        let lines = "#.##..##.
+..#.##.#.
+
+#...##..#
+#....#..#
+..##..##O";
+
+        assert_eq!(
+            parse(lines).unwrap_err(),
+            ParseError::InvalidCharacter(Position {
+                line: 6,
+                column: 9,
+                text: String::from("O"),
+            })
+        );
+
+        let lines = "#.##..##.
+..#.##.#";
+
+        assert_eq!(
+            parse(lines).unwrap_err().to_string(),
+            "line 2, column 1: expected 9 characters, got 8 in `..#.##.#`"
+        );
+    }
+
+    #[test]
+    fn test_summarise() {
+        assert_eq!(summarise(5, 4), Ok(405));
+        assert_eq!(summarise(1, 50_000_000), Ok(5_000_000_001));
+        assert_eq!(summarise(0, usize::MAX), Err(Overflow));
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_never_panics(
+            input in prop_oneof![
+                any::<String>(),
+                r"([.#]{0,6}\n{1,2}){0,10}",
+            ]
+        ) {
+            let _ = parse(&input);
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use common::Overflow;
+use grid::Grid;
+
+fn count_reflected_lines(lines: &[&[char]]) -> usize {
+    let possible_middle_pairs: Vec<(usize, usize)> = lines
+        .iter()
+        .enumerate()
+        .zip(lines.iter().enumerate().skip(1))
+        .filter(|(a, b)| a.1 == b.1)
+        .map(|(a, b)| (a.0, b.0))
+        .collect();
+
+    possible_middle_pairs
+        .iter()
+        .map(|(end, start)| {
+            let mut count = 0;
+
+            for n in 0..=*end {
+                if let Some(left) = lines.get(end - n) {
+                    if let Some(right) = lines.get(start + n) {
+                        if left != right {
+                            count = 0;
+                            break;
+                        }
+                    }
+                }
+
+                count += 1;
+            }
+
+            count
+        })
+        .filter(|result| *result != 0)
+        .sum()
+}
+
+pub(crate) fn summarise(grid: &Grid<char>) -> Result<u64, Overflow> {
+    let rows: Vec<&[char]> = grid.rows().collect();
+
+    let transposed = grid.transpose();
+    let columns: Vec<&[char]> = transposed.rows().collect();
+
+    crate::summarise(
+        count_reflected_lines(&columns),
+        count_reflected_lines(&rows),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, solve_part1, EXAMPLE_PART1};
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART1;
+
+        let result = solve_part1(&parse(lines).unwrap());
+
+        assert_eq!(result, Ok(405));
+    }
+}
@@ -0,0 +1,96 @@
+use common::Overflow;
+use grid::Grid;
+use tracing::trace;
+
+fn differences(left: &[char], right: &[char]) -> usize {
+    left.iter().zip(right).filter(|(a, b)| a != b).count()
+}
+
+/// Counts the lines before each reflection that only holds once exactly one smudge is fixed.
+fn count_smudged_reflected_lines(lines: &[&[char]]) -> Vec<usize> {
+    let possible_middle_pairs: Vec<(usize, usize)> = lines
+        .iter()
+        .enumerate()
+        .zip(lines.iter().enumerate().skip(1))
+        .filter_map(|(a, b)| {
+            if a.1 == b.1 || differences(a.1, b.1) == 1 {
+                Some((a.0, b.0))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    possible_middle_pairs
+        .iter()
+        .map(|(end, start)| {
+            let mut count = 0;
+
+            let mut difference_spotted: Option<usize> = None;
+
+            for n in 0..=*end {
+                if let Some(left) = lines.get(end - n) {
+                    if let Some(right) = lines.get(start + n) {
+                        let differences = differences(left, right);
+
+                        let mut index_difference: Option<usize> = None;
+
+                        if differences == 1 {
+                            index_difference =
+                                left.iter().zip(right.iter()).position(|(a, b)| a != b);
+                        }
+
+                        if left != right
+                            && (differences != 1
+                                || difference_spotted.is_some()
+                                    && difference_spotted != index_difference)
+                        {
+                            difference_spotted = None;
+                            count = 0;
+                            break;
+                        }
+
+                        if differences == 1 {
+                            difference_spotted = index_difference;
+                        }
+                    }
+                }
+
+                count += 1;
+            }
+
+            (count, difference_spotted.is_some())
+        })
+        .filter(|result| result.0 != 0 && result.1)
+        .map(|result| result.0)
+        .collect()
+}
+
+pub(crate) fn summarise(grid: &Grid<char>) -> Result<u64, Overflow> {
+    let rows: Vec<&[char]> = grid.rows().collect();
+
+    let transposed = grid.transpose();
+    let columns: Vec<&[char]> = transposed.rows().collect();
+
+    let results_rows = count_smudged_reflected_lines(&rows);
+    let results_columns = count_smudged_reflected_lines(&columns);
+
+    trace!(?results_columns, "Results Columns");
+    trace!(?results_rows, "Results Rows");
+
+    crate::summarise(results_columns.iter().sum(), results_rows.iter().sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, solve_part2, EXAMPLE_PART2};
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART2;
+
+        let result = solve_part2(&parse(lines).unwrap());
+
+        assert_eq!(result, Ok(400));
+    }
+}
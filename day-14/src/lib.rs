@@ -0,0 +1,202 @@
+mod part1;
+mod part2;
+pub mod visualise;
+
+use std::{error::Error, fmt};
+
+use common::{Overflow, Position, Solution, Stats};
+use grid::Grid;
+use tracing::trace;
+
+pub const EXAMPLE_PART1: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+pub const EXAMPLE_PART2: &str = EXAMPLE_PART1;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    Empty,
+    InvalidCharacter(Position),
+    RaggedLine(Position, usize),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the platform is empty"),
+            Self::InvalidCharacter(position) => write!(
+                f,
+                "{position}: invalid character `{}`, expected `O`, `#` or `.`",
+                position.text
+            ),
+            Self::RaggedLine(position, width) => write!(
+                f,
+                "{position}: expected {width} characters, got {} in `{}`",
+                position.text.chars().count(),
+                position.text
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<grid::ParseError> for ParseError {
+    fn from(error: grid::ParseError) -> Self {
+        match error {
+            grid::ParseError::Empty => Self::Empty,
+            grid::ParseError::InvalidCell(position) => Self::InvalidCharacter(position),
+            grid::ParseError::RaggedLine(position, width) => Self::RaggedLine(position, width),
+        }
+    }
+}
+
+/// Rolls the round rocks in each section of `line` between cube rocks as far towards its start,
+/// or its end, as they will go.
+fn roll(line: &mut [char], towards_start: bool) {
+    for section in line.split_mut(|c| *c == '#') {
+        let rock_count = section.iter().filter(|c| **c == 'O').count();
+
+        let (front, back) = if towards_start {
+            section.split_at_mut(rock_count)
+        } else {
+            section.split_at_mut(section.len() - rock_count)
+        };
+
+        front.fill(if towards_start { 'O' } else { '.' });
+        back.fill(if towards_start { '.' } else { 'O' });
+    }
+}
+
+fn tilt_rows(grid: &Grid<char>, towards_start: bool) -> Grid<char> {
+    let mut tilted = grid.clone();
+
+    for y in 0..tilted.height() {
+        roll(tilted.row_mut(y), towards_start);
+    }
+
+    trace!(%tilted, "Tilted");
+
+    tilted
+}
+
+pub fn tilt_north(grid: &Grid<char>) -> Grid<char> {
+    tilt_west(&grid.transpose()).transpose()
+}
+
+pub fn tilt_west(grid: &Grid<char>) -> Grid<char> {
+    tilt_rows(grid, true)
+}
+
+pub fn tilt_south(grid: &Grid<char>) -> Grid<char> {
+    tilt_east(&grid.transpose()).transpose()
+}
+
+pub fn tilt_east(grid: &Grid<char>) -> Grid<char> {
+    tilt_rows(grid, false)
+}
+
+fn compute_load(tilted_grid: &Grid<char>) -> usize {
+    let multiplier = tilted_grid.height();
+
+    tilted_grid
+        .rows()
+        .enumerate()
+        .map(|(index, line)| line.iter().filter(|c| **c == 'O').count() * (multiplier - index))
+        .sum()
+}
+
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Ok(Grid::parse(input, |c| {
+        ['O', '#', '.'].contains(&c).then_some(c)
+    })?)
+}
+
+pub fn solve_part1(grid: &Grid<char>) -> usize {
+    compute_load(&tilt_north(grid))
+}
+
+pub fn solve_part2(grid: &Grid<char>) -> usize {
+    compute_load(&part2::spin_cycles(grid, &mut Stats::default()))
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Grid<char>;
+    type Error = ParseError;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const DAY: u8 = 14;
+
+    const EXAMPLE_PART1: &'static str = EXAMPLE_PART1;
+    const EXAMPLE_PART2: &'static str = EXAMPLE_PART2;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Overflow> {
+        Ok(solve_part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Overflow> {
+        Ok(solve_part2(input))
+    }
+
+    fn part2_with_stats(input: &Self::Input, stats: &mut Stats) -> Result<Self::Part2, Overflow> {
+        Ok(compute_load(&part2::spin_cycles(input, stats)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(parse(""), Err(ParseError::Empty));
+
+        let lines = "O....#....
+O.OO#..0.#";
+
+        assert_eq!(
+            parse(lines),
+            Err(ParseError::InvalidCharacter(Position {
+                line: 2,
+                column: 8,
+                text: String::from("0"),
+            }))
+        );
+
+        let lines = "O....#....
+O.OO#....#.";
+
+        assert_eq!(
+            parse(lines).unwrap_err().to_string(),
+            "line 2, column 1: expected 10 characters, got 11 in `O.OO#....#.`"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_never_panics(
+            input in prop_oneof![
+                any::<String>(),
+                r"[.#O\n]{0,80}",
+            ]
+        ) {
+            let _ = parse(&input);
+        }
+    }
+}
@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod tests {
+    use crate::{parse, solve_part1, tilt_north, EXAMPLE_PART1};
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART1;
+
+        let result = solve_part1(&parse(lines).unwrap());
+
+        assert_eq!(result, 136);
+    }
+
+    #[test]
+    fn test_tilt_north() {
+        let lines = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+        let expected = "OOOO.#.O..
+OO..#....#
+OO..O##..O
+O..#.OO...
+........#.
+..#....#.#
+..O..#.O.O
+..O.......
+#....###..
+#....#....";
+
+        let result = tilt_north(&parse(lines).unwrap());
+
+        assert_eq!(result.to_string(), expected);
+    }
+}
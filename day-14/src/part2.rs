@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+use common::Stats;
+
+use grid::Grid;
+
+use crate::{tilt_east, tilt_north, tilt_south, tilt_west};
+use tracing::{debug, debug_span};
+
+const CYCLES: usize = 1_000_000_000;
+
+pub(crate) fn spin_cycles(grid: &Grid<char>, stats: &mut Stats) -> Grid<char> {
+    let _span = debug_span!("spin_cycles", cycles = CYCLES).entered();
+
+    let mut cache: HashMap<Grid<char>, usize> = HashMap::new();
+
+    let mut tilted_grid = grid.clone();
+
+    let mut last_cycle = grid.clone();
+
+    for n in 0..CYCLES {
+        let _span = debug_span!("cycle", n).entered();
+
+        tilted_grid = tilt_north(&tilted_grid);
+
+        tilted_grid = tilt_west(&tilted_grid);
+
+        tilted_grid = tilt_south(&tilted_grid);
+
+        tilted_grid = tilt_east(&tilted_grid);
+
+        if let Some(cached_grid_index) = cache.get(&tilted_grid) {
+            if (CYCLES - n).is_multiple_of(n - cached_grid_index) {
+                debug!(
+                    cycle_start = cached_grid_index,
+                    cycle_length = n - cached_grid_index,
+                    "Found repeating cycle"
+                );
+
+                stats.set("cycle_start", *cached_grid_index as u64);
+                stats.set("cycle_length", (n - cached_grid_index) as u64);
+                stats.set("spin_cycles", n as u64 + 1);
+
+                tilted_grid = last_cycle;
+                break;
+            }
+        }
+
+        last_cycle = tilted_grid.clone();
+
+        cache.insert(tilted_grid.clone(), n);
+    }
+
+    tilted_grid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part2, EXAMPLE_PART2};
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART2;
+
+        let result = solve_part2(&parse(lines).unwrap());
+
+        assert_eq!(result, 64);
+    }
+
+    #[test]
+    fn test_tilt() {
+        let lines = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+        let expected = ".....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....";
+
+        let mut result = parse(lines).unwrap();
+
+        result = tilt_north(&result);
+        result = tilt_west(&result);
+        result = tilt_south(&result);
+        result = tilt_east(&result);
+
+        assert_eq!(result.to_string(), expected);
+
+        let expected = ".....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#..OO###..
+#.OOO#...O";
+
+        result = tilt_north(&result);
+        result = tilt_west(&result);
+        result = tilt_south(&result);
+        result = tilt_east(&result);
+
+        assert_eq!(result.to_string(), expected);
+
+        let expected = ".....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#...O###.O
+#.OOO#...O";
+
+        result = tilt_north(&result);
+        result = tilt_west(&result);
+        result = tilt_south(&result);
+        result = tilt_east(&result);
+
+        assert_eq!(result.to_string(), expected);
+    }
+}
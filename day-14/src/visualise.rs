@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+use common::Part;
+use grid::{
+    render::{Colour, Frame},
+    Grid,
+};
+
+use crate::{compute_load, tilt_east, tilt_north, tilt_south, tilt_west};
+
+type Tilt = fn(&Grid<char>) -> Grid<char>;
+
+/// The platform with its round rocks coloured, and those that moved since `previous` picked out.
+fn frame(platform: &Grid<char>, previous: &Grid<char>, caption: String) -> Frame {
+    let mut frame = Frame::new(platform, |c| *c).with_caption(caption);
+
+    frame
+        .region(
+            platform
+                .iter()
+                .filter(|(_, c)| **c == 'O')
+                .map(|(point, _)| point),
+            Colour::Yellow,
+        )
+        .highlight(
+            platform
+                .iter()
+                .filter(|(point, c)| **c == 'O' && previous[*point] != 'O')
+                .map(|(point, _)| point),
+            Colour::Blue,
+        );
+
+    frame
+}
+
+/// Part one tilts the platform north once. Part two shows every tilt of every spin cycle until
+/// the platform comes back to an arrangement it has been in before.
+pub fn frames(platform: &Grid<char>, part: Part) -> Vec<Frame> {
+    let mut frames = vec![frame(platform, platform, String::from("Initial platform"))];
+
+    if part == Part::One {
+        let tilted = tilt_north(platform);
+        let caption = format!("Tilted north, load {}", compute_load(&tilted));
+
+        frames.push(frame(&tilted, platform, caption));
+
+        return frames;
+    }
+
+    let tilts: [(&str, Tilt); 4] = [
+        ("north", tilt_north),
+        ("west", tilt_west),
+        ("south", tilt_south),
+        ("east", tilt_east),
+    ];
+
+    let mut seen: HashMap<Grid<char>, usize> = HashMap::new();
+    let mut current = platform.clone();
+
+    for cycle in 1.. {
+        for (name, tilt) in tilts {
+            let tilted = tilt(&current);
+            let caption = format!(
+                "Cycle {cycle}, tilted {name}, load {}",
+                compute_load(&tilted)
+            );
+
+            frames.push(frame(&tilted, &current, caption));
+
+            current = tilted;
+        }
+
+        if let Some(first) = seen.insert(current.clone(), cycle) {
+            if let Some(last) = frames.last_mut() {
+                last.caption += &format!(", same as cycle {first}");
+            }
+
+            break;
+        }
+    }
+
+    frames
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, EXAMPLE_PART2};
+
+    #[test]
+    fn test_frames() {
+        let platform = parse(EXAMPLE_PART2).unwrap();
+
+        let tilted = frames(&platform, Part::One);
+
+        assert_eq!(tilted.len(), 2);
+        assert_eq!(tilted[1].caption, "Tilted north, load 136");
+        assert_eq!(tilted[1].to_plain().lines().nth(1), Some("OOOO.#.O.."));
+
+        let cycles = frames(&platform, Part::Two);
+
+        // The example repeats from cycle 3 every 7 cycles, so cycle 10 is the first repeat.
+        assert_eq!(cycles.len(), 1 + 10 * 4);
+        assert_eq!(
+            cycles.last().unwrap().caption,
+            "Cycle 10, tilted east, load 69, same as cycle 3"
+        );
+    }
+}
@@ -0,0 +1,167 @@
+mod part1;
+mod part2;
+
+use std::{error::Error, fmt};
+
+use common::{Overflow, Position, Solution};
+use tracing::trace;
+
+pub const EXAMPLE_PART1: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+pub const EXAMPLE_PART2: &str = EXAMPLE_PART1;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    Empty,
+    InvalidStep(Position),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the initialization sequence is empty"),
+            Self::InvalidStep(position) => write!(
+                f,
+                "{position}: invalid step `{}`, expected `label=N` or `label-`",
+                position.text
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+pub fn hash(sequence: &str) -> u32 {
+    trace!(%sequence, "Hashing");
+
+    sequence
+        .chars()
+        .fold(0, |accumulator, c| ((accumulator + (c as u32)) * 17) % 256)
+}
+
+fn is_valid_step(step: &str) -> bool {
+    let (label, operation) = match step.find(['=', '-']) {
+        Some(index) => step.split_at(index),
+        None => return false,
+    };
+
+    let valid_operation = match operation.as_bytes() {
+        [b'-'] => true,
+        [b'=', digit] => (b'1'..=b'9').contains(digit),
+        _ => false,
+    };
+
+    !label.is_empty() && label.chars().all(|c| c.is_ascii_lowercase()) && valid_operation
+}
+
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    let mut steps = Vec::new();
+
+    for line in common::lines(input).filter(|line| !line.text.is_empty()) {
+        for step in line.text.split(',') {
+            if !is_valid_step(step) {
+                return Err(ParseError::InvalidStep(line.position(step)));
+            }
+
+            steps.push(step.to_string());
+        }
+    }
+
+    if steps.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    Ok(steps)
+}
+
+pub fn solve_part1(steps: &[String]) -> Result<u64, Overflow> {
+    part1::sum_hashes(steps)
+}
+
+pub fn solve_part2(steps: &[String]) -> Result<u64, Overflow> {
+    part2::calculate_focusing_power(steps)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<String>;
+    type Error = ParseError;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    const DAY: u8 = 15;
+
+    const EXAMPLE_PART1: &'static str = EXAMPLE_PART1;
+    const EXAMPLE_PART2: &'static str = EXAMPLE_PART2;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Overflow> {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Overflow> {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_hash() {
+        let line = "rn=1";
+
+        let result = hash(line);
+
+        assert_eq!(result, 30);
+
+        let line = "cm-";
+
+        let result = hash(line);
+
+        assert_eq!(result, 253);
+
+        let line = "qp=3";
+
+        let result = hash(line);
+
+        assert_eq!(result, 97);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(parse("\n"), Err(ParseError::Empty));
+
+        assert_eq!(
+            parse("rn=1,cm-,qp=x,cm=2"),
+            Err(ParseError::InvalidStep(Position {
+                line: 1,
+                column: 10,
+                text: String::from("qp=x"),
+            }))
+        );
+
+        assert_eq!(
+            parse("rn=1,,cm-").unwrap_err().to_string(),
+            "line 1, column 6: invalid step ``, expected `label=N` or `label-`"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_never_panics(
+            input in prop_oneof![
+                any::<String>(),
+                r"([a-z]{0,4}(=[0-9]{1,2}|-)?,?){0,8}\n?",
+            ]
+        ) {
+            let _ = parse(&input);
+        }
+    }
+}
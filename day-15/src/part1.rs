@@ -0,0 +1,23 @@
+use crate::hash;
+use common::{checked, Overflow};
+use tracing::trace;
+
+pub(crate) fn sum_hashes(steps: &[String]) -> Result<u64, Overflow> {
+    trace!(?steps, "Summing hashes of");
+
+    checked::sum(steps.iter().map(|step| u64::from(hash(step))))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, solve_part1, EXAMPLE_PART1};
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART1;
+
+        let result = solve_part1(&parse(lines).unwrap());
+
+        assert_eq!(result, Ok(1320));
+    }
+}
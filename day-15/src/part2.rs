@@ -0,0 +1,136 @@
+use crate::hash;
+use common::{checked, checked::Checked, Overflow};
+use tracing::trace;
+
+#[derive(Clone, Debug)]
+enum LensType {
+    Replacement,
+    Removal,
+}
+
+#[derive(Clone, Debug)]
+struct Lens {
+    label: String,
+    focal_length: Option<u8>,
+    lens_type: LensType,
+}
+
+fn parse_lens(lens: &str) -> Option<Lens> {
+    let lens_type = if lens.contains('=') {
+        LensType::Replacement
+    } else if lens.contains('-') {
+        LensType::Removal
+    } else {
+        return None;
+    };
+
+    let (label, _) = match lens_type {
+        LensType::Replacement => lens.split_once('=').unwrap(),
+        LensType::Removal => lens.split_once('-').unwrap(),
+    };
+
+    let label = label.to_string();
+
+    let focal_length = match lens_type {
+        LensType::Replacement => {
+            let digits: String = lens.chars().filter(|c| c.is_numeric()).collect();
+            digits.parse::<u8>().ok()
+        }
+        LensType::Removal => None,
+    };
+
+    Some(Lens {
+        label,
+        focal_length,
+        lens_type,
+    })
+}
+
+pub(crate) fn calculate_focusing_power(steps: &[String]) -> Result<u64, Overflow> {
+    trace!(?steps, "Summing hashes of");
+
+    let lenses: Vec<(u32, Lens)> = steps
+        .iter()
+        .filter_map(|step| parse_lens(step))
+        .map(|lens| (hash(&lens.label), lens))
+        .collect();
+
+    let mut sum: u64 = 0;
+
+    for n in 0..256_u64 {
+        let mut box_of_lenses: Vec<Lens> = Vec::default();
+
+        for (_, lens) in lenses.iter().filter(|lens| u64::from(lens.0) == n).cloned() {
+            match lens.lens_type {
+                LensType::Replacement => {
+                    if let Some(index) = box_of_lenses
+                        .iter()
+                        .position(|item| item.label == lens.label)
+                    {
+                        box_of_lenses[index] = lens;
+                    } else {
+                        box_of_lenses.push(lens);
+                    }
+                }
+                LensType::Removal => {
+                    if let Some(index) = box_of_lenses
+                        .iter()
+                        .position(|item| item.label == lens.label)
+                    {
+                        box_of_lenses.remove(index);
+                    }
+                }
+            }
+        }
+
+        trace!(%n, ?box_of_lenses, "Box of lenses");
+
+        for (index_lens, lens) in box_of_lenses.iter().enumerate() {
+            let slot: u64 = checked::convert(index_lens + 1)?;
+
+            sum = sum.try_add(
+                (n + 1)
+                    .try_mul(slot)?
+                    .try_mul(u64::from(lens.focal_length.unwrap()))?,
+            )?;
+        }
+    }
+
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{hash, parse, solve_part2, EXAMPLE_PART2};
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART2;
+
+        let result = solve_part2(&parse(lines).unwrap());
+
+        assert_eq!(result, Ok(145));
+    }
+
+    #[test]
+    fn test_wide_focusing_power() {
+        // Enough lenses in the last box for the focusing power to pass `u32::MAX`.
+        let steps: Vec<String> = (0..26_u32.pow(5))
+            .map(|n| {
+                (0..5)
+                    .map(|digit| {
+                        char::from(b'a' + u8::try_from(n / 26_u32.pow(digit) % 26).unwrap())
+                    })
+                    .collect::<String>()
+            })
+            .filter(|label| hash(label) == 255)
+            .take(2000)
+            .map(|label| format!("{label}=9"))
+            .collect();
+
+        assert_eq!(
+            solve_part2(&parse(&steps.join(",")).unwrap()),
+            Ok(256 * 9 * 2000 * 2001 / 2)
+        );
+    }
+}
@@ -0,0 +1,241 @@
+mod part1;
+mod part2;
+pub mod visualise;
+
+use std::{collections::HashSet, error::Error, fmt};
+
+use common::{Overflow, Position, Solution};
+use grid::{Direction, Grid, Point};
+use tracing::{trace, trace_span};
+
+pub const EXAMPLE_PART1: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+
+pub const EXAMPLE_PART2: &str = EXAMPLE_PART1;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    Empty,
+    InvalidCharacter(Position),
+    RaggedLine(Position, usize),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the contraption is empty"),
+            Self::InvalidCharacter(position) => write!(
+                f,
+                "{position}: invalid tile `{}`, expected one of `./\\|-`",
+                position.text
+            ),
+            Self::RaggedLine(position, width) => write!(
+                f,
+                "{position}: expected {width} characters, got {} in `{}`",
+                position.text.chars().count(),
+                position.text
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<grid::ParseError> for ParseError {
+    fn from(error: grid::ParseError) -> Self {
+        match error {
+            grid::ParseError::Empty => Self::Empty,
+            grid::ParseError::InvalidCell(position) => Self::InvalidCharacter(position),
+            grid::ParseError::RaggedLine(position, width) => Self::RaggedLine(position, width),
+        }
+    }
+}
+
+fn new_directions(c: char, direction: Direction) -> Vec<Direction> {
+    trace!(%c, ?direction, "New Direction for char and direction");
+
+    let mut directions = Vec::default();
+
+    match c {
+        '\\' => match direction {
+            Direction::North => directions.push(Direction::West),
+            Direction::West => directions.push(Direction::North),
+            Direction::South => directions.push(Direction::East),
+            Direction::East => directions.push(Direction::South),
+        },
+        '/' => match direction {
+            Direction::North => directions.push(Direction::East),
+            Direction::East => directions.push(Direction::North),
+            Direction::South => directions.push(Direction::West),
+            Direction::West => directions.push(Direction::South),
+        },
+        '|' => match direction {
+            Direction::North | Direction::South => directions.push(direction),
+            Direction::East | Direction::West => {
+                directions.push(Direction::North);
+                directions.push(Direction::South);
+            }
+        },
+        '-' => match direction {
+            Direction::East | Direction::West => directions.push(direction),
+            Direction::North | Direction::South => {
+                directions.push(Direction::East);
+                directions.push(Direction::West);
+            }
+        },
+        _ => directions.push(direction),
+    }
+
+    directions
+}
+
+fn trace_beam(
+    already_seen: &mut HashSet<(Point, Direction)>,
+    grid: &Grid<char>,
+    starting_position: Point,
+    direction: Direction,
+) -> HashSet<Point> {
+    let _span = trace_span!(
+        "beam",
+        x = starting_position.x,
+        y = starting_position.y,
+        ?direction
+    )
+    .entered();
+
+    if already_seen.contains(&(starting_position, direction)) {
+        return HashSet::default();
+    }
+
+    already_seen.insert((starting_position, direction));
+
+    let mut energised_tiles: HashSet<Point> = HashSet::default();
+
+    energised_tiles.insert(starting_position);
+
+    let mut next_directions: Vec<Direction> = vec![direction];
+
+    let mut current_position = starting_position;
+
+    while next_directions == vec![direction] {
+        let Some(new_position) = grid.step(current_position, direction) else {
+            break;
+        };
+
+        trace!(x = new_position.x, y = new_position.y, "Moved");
+
+        current_position = new_position;
+        energised_tiles.insert(new_position);
+        next_directions = new_directions(grid[new_position], direction);
+
+        trace!(?next_directions, "Next Directions");
+
+        if next_directions.len() > 1 {
+            break;
+        }
+    }
+
+    for next_direction in next_directions {
+        energised_tiles.extend(trace_beam(
+            already_seen,
+            grid,
+            current_position,
+            next_direction,
+        ));
+    }
+
+    trace!(energised = energised_tiles.len(), "Beam finished");
+
+    energised_tiles
+}
+
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Ok(Grid::parse(input, |c| {
+        ['.', '/', '\\', '|', '-'].contains(&c).then_some(c)
+    })?)
+}
+
+pub fn solve_part1(grid: &Grid<char>) -> usize {
+    part1::count_energised_tiles(grid)
+}
+
+pub fn solve_part2(grid: &Grid<char>) -> usize {
+    part2::find_highest_energised_tiles_configuration(grid)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Grid<char>;
+    type Error = ParseError;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const DAY: u8 = 16;
+
+    const EXAMPLE_PART1: &'static str = EXAMPLE_PART1;
+    const EXAMPLE_PART2: &'static str = EXAMPLE_PART2;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Overflow> {
+        Ok(solve_part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Overflow> {
+        Ok(solve_part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(parse(""), Err(ParseError::Empty));
+
+        let lines = r".|...\....
+|.-.\#....";
+
+        assert_eq!(
+            parse(lines),
+            Err(ParseError::InvalidCharacter(Position {
+                line: 2,
+                column: 6,
+                text: String::from("#"),
+            }))
+        );
+
+        let lines = r".|...\....
+|.-.\.";
+
+        assert_eq!(
+            parse(lines).unwrap_err().to_string(),
+            r"line 2, column 1: expected 10 characters, got 6 in `|.-.\.`"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_never_panics(
+            input in prop_oneof![
+                any::<String>(),
+                r"[./\\|\-\n]{0,80}",
+            ]
+        ) {
+            let _ = parse(&input);
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use std::collections::HashSet;
+
+use grid::{Direction, Grid, Point};
+
+use crate::{new_directions, trace_beam};
+
+pub(crate) fn count_energised_tiles(grid: &Grid<char>) -> usize {
+    let mut energised_tiles: HashSet<Point> = HashSet::default();
+
+    let mut already_seen: HashSet<(Point, Direction)> = HashSet::default();
+
+    let mut starting_position = Point::new(0, 0);
+    let mut starting_direction = Direction::East;
+
+    let c = grid[starting_position];
+
+    if c != '.' {
+        let new_directions = new_directions(c, starting_direction);
+
+        if new_directions != vec![Direction::East] {
+            energised_tiles.insert(starting_position);
+            starting_direction = Direction::South;
+            starting_position = Point::new(0, 1);
+        }
+    }
+
+    energised_tiles.extend(trace_beam(
+        &mut already_seen,
+        grid,
+        starting_position,
+        starting_direction,
+    ));
+
+    energised_tiles.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, solve_part1, EXAMPLE_PART1};
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART1;
+
+        let result = solve_part1(&parse(lines).unwrap());
+
+        assert_eq!(result, 46);
+    }
+}
@@ -0,0 +1,126 @@
+use grid::{Direction, Grid, Point};
+use rayon::prelude::*;
+use std::collections::HashSet;
+use tracing::{debug, debug_span, Span};
+
+use crate::{new_directions, trace_beam};
+
+fn count_energised_tiles(
+    grid: &Grid<char>,
+    original_position: Point,
+    original_direction: Direction,
+    parent: &Span,
+) -> usize {
+    let _span = debug_span!(
+        parent: parent,
+        "configuration",
+        x = original_position.x,
+        y = original_position.y,
+        direction = ?original_direction
+    )
+    .entered();
+
+    let mut energised_tiles: HashSet<Point> = HashSet::default();
+
+    let mut already_seen: HashSet<(Point, Direction)> = HashSet::default();
+
+    let c = grid[original_position];
+
+    if c == '.' {
+        energised_tiles.extend(trace_beam(
+            &mut already_seen,
+            grid,
+            original_position,
+            original_direction,
+        ));
+    } else {
+        let new_directions = new_directions(c, original_direction);
+
+        if new_directions == vec![original_direction] {
+            energised_tiles.extend(trace_beam(
+                &mut already_seen,
+                grid,
+                original_position,
+                original_direction,
+            ));
+        } else {
+            energised_tiles.insert(original_position);
+
+            for new_direction in new_directions {
+                if new_direction == original_direction.opposite() {
+                    continue;
+                }
+
+                let Some(new_position) = grid.step(original_position, new_direction) else {
+                    continue;
+                };
+
+                energised_tiles.extend(trace_beam(
+                    &mut already_seen,
+                    grid,
+                    new_position,
+                    new_direction,
+                ));
+            }
+        }
+    }
+
+    debug!(energised = energised_tiles.len(), "Configuration finished");
+
+    energised_tiles.len()
+}
+
+pub(crate) fn find_highest_energised_tiles_configuration(grid: &Grid<char>) -> usize {
+    let mut all_starts: Vec<(Point, Direction)> = Vec::default();
+
+    let length = grid.width();
+    let height = grid.height();
+
+    for i in 0..length {
+        for j in 0..height {
+            let point = Point::new(i, j);
+
+            if i == 0 {
+                all_starts.push((point, Direction::East));
+            }
+
+            if j == 0 {
+                all_starts.push((point, Direction::South));
+            }
+
+            if i == length - 1 {
+                all_starts.push((point, Direction::West));
+            }
+
+            if j == height - 1 {
+                all_starts.push((point, Direction::North));
+            }
+        }
+    }
+
+    // Rayon's worker threads don't inherit the current span, so pass it along explicitly.
+    let parent = Span::current();
+
+    let mut results: Vec<usize> = all_starts
+        .par_iter()
+        .map(|(point, direction)| count_energised_tiles(grid, *point, *direction, &parent))
+        .collect();
+
+    results.sort_unstable();
+
+    *results.last().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, solve_part2, EXAMPLE_PART2};
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART2;
+
+        let result = solve_part2(&parse(lines).unwrap());
+
+        assert_eq!(result, 51);
+    }
+}
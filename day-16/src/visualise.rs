@@ -0,0 +1,150 @@
+use std::collections::{HashSet, VecDeque};
+
+use common::Part;
+use grid::{
+    render::{Colour, Frame},
+    Direction, Grid, Point,
+};
+
+use crate::new_directions;
+
+/// The straight runs of a beam entering `start` heading in `direction`, in the order a
+/// breadth-first walk reaches them. Each run ends on the tile that turns or splits the beam.
+fn segments(grid: &Grid<char>, start: Point, direction: Direction) -> Vec<Vec<Point>> {
+    let mut segments = Vec::default();
+    let mut seen: HashSet<(Point, Direction)> = HashSet::default();
+    let mut queue = VecDeque::from([(start, direction)]);
+
+    while let Some((start, direction)) = queue.pop_front() {
+        if !seen.insert((start, direction)) {
+            continue;
+        }
+
+        let mut segment = vec![start];
+        let mut position = start;
+
+        loop {
+            let directions = new_directions(grid[position], direction);
+
+            if directions != [direction] {
+                for next_direction in directions {
+                    if let Some(next) = grid.step(position, next_direction) {
+                        queue.push_back((next, next_direction));
+                    }
+                }
+
+                break;
+            }
+
+            match grid.step(position, direction) {
+                Some(next) if seen.insert((next, direction)) => {
+                    segment.push(next);
+                    position = next;
+                }
+                _ => break,
+            }
+        }
+
+        segments.push(segment);
+    }
+
+    segments
+}
+
+/// Every tile along an edge with the direction a beam entering there would head in.
+fn entrances(grid: &Grid<char>) -> impl Iterator<Item = (Point, Direction)> + '_ {
+    let (width, height) = (grid.width(), grid.height());
+
+    (0..width)
+        .flat_map(move |x| {
+            [
+                (Point::new(x, 0), Direction::South),
+                (Point::new(x, height - 1), Direction::North),
+            ]
+        })
+        .chain((0..height).flat_map(move |y| {
+            [
+                (Point::new(0, y), Direction::East),
+                (Point::new(width - 1, y), Direction::West),
+            ]
+        }))
+}
+
+fn energised(segments: &[Vec<Point>]) -> HashSet<Point> {
+    segments.iter().flatten().copied().collect()
+}
+
+/// The beam from the top left corner for part one, or from whichever entrance energises the most
+/// tiles for part two, growing one straight segment per frame.
+pub fn frames(grid: &Grid<char>, part: Part) -> Vec<Frame> {
+    let (start, direction) = match part {
+        Part::One => (Point::new(0, 0), Direction::East),
+        Part::Two => entrances(grid)
+            .max_by_key(|(start, direction)| energised(&segments(grid, *start, *direction)).len())
+            .unwrap_or((Point::new(0, 0), Direction::East)),
+    };
+
+    let segments = segments(grid, start, direction);
+
+    let mut lit: HashSet<Point> = HashSet::default();
+
+    let mut frames = vec![Frame::new(grid, |c| *c)
+        .with_caption(format!("Beam entering at {start} heading {direction:?}"))];
+
+    for (index, segment) in segments.iter().enumerate() {
+        lit.extend(segment);
+
+        let mut frame = Frame::new(grid, |c| *c).with_caption(format!(
+            "Segment {} of {}, {} tiles energised",
+            index + 1,
+            segments.len(),
+            lit.len()
+        ));
+
+        frame
+            .highlight(lit.iter().copied(), Colour::Yellow)
+            .mark(lit.iter().copied(), '#')
+            .path(segment, Colour::Red);
+
+        frames.push(frame);
+    }
+
+    frames
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part1, solve_part2, EXAMPLE_PART1};
+
+    fn energised_count(frame: &Frame) -> usize {
+        frame
+            .cells
+            .iter()
+            .filter(|(_, cell)| cell.background.is_some())
+            .count()
+    }
+
+    #[test]
+    fn test_frames() {
+        let grid = parse(EXAMPLE_PART1).unwrap();
+
+        let beam = frames(&grid, Part::One);
+
+        assert_eq!(beam[0].caption, "Beam entering at (0, 0) heading East");
+        assert_eq!(energised_count(beam.last().unwrap()), solve_part1(&grid));
+        assert_eq!(
+            beam[1].to_plain(),
+            format!(
+                "Segment 1 of {}, 2 tiles energised\n>#...\\....\n{}",
+                beam.len() - 1,
+                EXAMPLE_PART1.lines().skip(1).collect::<Vec<_>>().join("\n")
+            )
+        );
+
+        let best = frames(&grid, Part::Two);
+
+        assert_eq!(best[0].caption, "Beam entering at (3, 0) heading South");
+        assert_eq!(energised_count(best.last().unwrap()), solve_part2(&grid));
+    }
+}
@@ -0,0 +1,283 @@
+mod part1;
+mod part2;
+pub mod visualise;
+
+use common::{Overflow, Position, Solution, Stats};
+use grid::{Direction, Grid, Point};
+use priority_queue::PriorityQueue;
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+use tracing::{debug, debug_span, trace};
+
+pub const EXAMPLE_PART1: &str = "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+
+pub const EXAMPLE_PART2: &str = EXAMPLE_PART1;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    Empty,
+    InvalidDigit(Position),
+    RaggedLine(Position, usize),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the map is empty"),
+            Self::InvalidDigit(position) => {
+                write!(f, "{position}: invalid heat loss `{}`", position.text)
+            }
+            Self::RaggedLine(position, width) => write!(
+                f,
+                "{position}: expected {width} characters, got {} in `{}`",
+                position.text.chars().count(),
+                position.text
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<grid::ParseError> for ParseError {
+    fn from(error: grid::ParseError) -> Self {
+        match error {
+            grid::ParseError::Empty => Self::Empty,
+            grid::ParseError::InvalidCell(position) => Self::InvalidDigit(position),
+            grid::ParseError::RaggedLine(position, width) => Self::RaggedLine(position, width),
+        }
+    }
+}
+
+#[derive(Hash, Eq, PartialEq, Copy, Clone, Debug)]
+struct Step {
+    position: Point,
+    direction: Direction,
+    heat_loss: usize,
+    steps: usize,
+}
+
+pub fn compute_least_heat_loss(
+    grid: &Grid<usize>,
+    min_steps: usize,
+    max_steps: usize,
+    stats: &mut Stats,
+) -> usize {
+    search(grid, min_steps, max_steps, stats, |_| {}, |_, _| {}).map_or(0, |goal| goal.heat_loss)
+}
+
+/// Dijkstra's search from the top left to the bottom right, returning the state that reaches the
+/// goal. `on_pop` sees each state as it is explored and `on_push` each newly reached state along
+/// with the state it was reached from.
+fn search(
+    grid: &Grid<usize>,
+    min_steps: usize,
+    max_steps: usize,
+    stats: &mut Stats,
+    mut on_pop: impl FnMut(&Step),
+    mut on_push: impl FnMut(&Step, &Step),
+) -> Option<Step> {
+    let _span = debug_span!("dijkstra", min_steps, max_steps).entered();
+
+    let goal = Point::new(grid.width() - 1, grid.height() - 1);
+
+    let start_east = Step {
+        position: Point::default(),
+        direction: Direction::East,
+        heat_loss: 0,
+        steps: 0,
+    };
+
+    let start_south = Step {
+        position: Point::default(),
+        direction: Direction::South,
+        heat_loss: 0,
+        steps: 0,
+    };
+
+    let mut priority_queue = PriorityQueue::new();
+
+    priority_queue.push(start_east, Reverse(0));
+    priority_queue.push(start_south, Reverse(0));
+
+    let mut visited: HashSet<(Point, Direction, usize)> = HashSet::new();
+
+    visited.insert((start_east.position, start_east.direction, start_east.steps));
+    visited.insert((
+        start_south.position,
+        start_south.direction,
+        start_south.steps,
+    ));
+
+    while let Some((current, _)) = priority_queue.pop() {
+        stats.add("states_popped", 1);
+
+        trace!(
+            x = current.position.x,
+            y = current.position.y,
+            direction = ?current.direction,
+            steps = current.steps,
+            heat_loss = current.heat_loss,
+            "Popped"
+        );
+
+        if current.position == goal {
+            if current.steps < min_steps {
+                continue;
+            }
+
+            debug!(heat_loss = current.heat_loss, "Reached the goal");
+
+            return Some(current);
+        }
+
+        on_pop(&current);
+
+        // Going straight on first, then turning towards the west or south.
+        let turns = if current.direction.is_vertical() {
+            [Direction::West, Direction::East]
+        } else {
+            [Direction::South, Direction::North]
+        };
+
+        for direction in [current.direction, turns[0], turns[1]] {
+            if current.steps >= max_steps && current.direction == direction {
+                continue;
+            }
+
+            if current.steps < min_steps && current.direction != direction {
+                continue;
+            }
+
+            let Some(position) = grid.step(current.position, direction) else {
+                continue;
+            };
+
+            let step = Step {
+                position,
+                direction,
+                heat_loss: current.heat_loss + grid[position],
+                steps: if current.direction == direction {
+                    current.steps + 1
+                } else {
+                    1
+                },
+            };
+
+            if visited.insert((step.position, step.direction, step.steps)) {
+                on_push(&step, &current);
+
+                priority_queue.push(step, Reverse(step.heat_loss));
+
+                stats.add("states_pushed", 1);
+            }
+        }
+    }
+
+    None
+}
+
+pub fn parse(input: &str) -> Result<Grid<usize>, ParseError> {
+    Ok(Grid::parse(input, |c| {
+        c.to_digit(10).map(|digit| digit as usize)
+    })?)
+}
+
+pub fn solve_part1(grid: &Grid<usize>) -> usize {
+    part1::compute_path(grid, &mut Stats::default())
+}
+
+pub fn solve_part2(grid: &Grid<usize>) -> usize {
+    part2::compute_path(grid, &mut Stats::default())
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid<usize>;
+    type Error = ParseError;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const DAY: u8 = 17;
+
+    const EXAMPLE_PART1: &'static str = EXAMPLE_PART1;
+    const EXAMPLE_PART2: &'static str = EXAMPLE_PART2;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Overflow> {
+        Ok(solve_part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Overflow> {
+        Ok(solve_part2(input))
+    }
+
+    fn part1_with_stats(input: &Self::Input, stats: &mut Stats) -> Result<Self::Part1, Overflow> {
+        Ok(part1::compute_path(input, stats))
+    }
+
+    fn part2_with_stats(input: &Self::Input, stats: &mut Stats) -> Result<Self::Part2, Overflow> {
+        Ok(part2::compute_path(input, stats))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(parse(""), Err(ParseError::Empty));
+
+        let lines = "2413432311323
+3215453x35623";
+
+        assert_eq!(
+            parse(lines),
+            Err(ParseError::InvalidDigit(Position {
+                line: 2,
+                column: 8,
+                text: String::from("x"),
+            }))
+        );
+
+        let lines = "2413432311323
+321545";
+
+        assert_eq!(
+            parse(lines).unwrap_err().to_string(),
+            "line 2, column 1: expected 13 characters, got 6 in `321545`"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_never_panics(
+            input in prop_oneof![
+                any::<String>(),
+                r"[0-9\n]{0,80}",
+            ]
+        ) {
+            let _ = parse(&input);
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use common::Stats;
+use grid::Grid;
+
+use crate::compute_least_heat_loss;
+use tracing::trace;
+
+pub(crate) fn compute_path(grid: &Grid<usize>, stats: &mut Stats) -> usize {
+    let result = compute_least_heat_loss(grid, 1, 3, stats);
+
+    trace!(%result, "Result");
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, solve_part1, EXAMPLE_PART1};
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART1;
+
+        let result = solve_part1(&parse(lines).unwrap());
+
+        assert_eq!(result, 102);
+    }
+}
@@ -0,0 +1,40 @@
+use common::Stats;
+use grid::Grid;
+
+use crate::compute_least_heat_loss;
+use tracing::trace;
+
+pub(crate) fn compute_path(grid: &Grid<usize>, stats: &mut Stats) -> usize {
+    let result = compute_least_heat_loss(grid, 4, 10, stats);
+
+    trace!(%result, "Result");
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, solve_part2, EXAMPLE_PART2};
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART2;
+
+        let result = solve_part2(&parse(lines).unwrap());
+
+        assert_eq!(result, 94);
+    }
+
+    #[test]
+    fn test_new_example() {
+        let lines = "111111111111
+999999999991
+999999999991
+999999999991
+999999999991";
+
+        let result = solve_part2(&parse(lines).unwrap());
+
+        assert_eq!(result, 71);
+    }
+}
@@ -0,0 +1,130 @@
+use std::collections::{HashMap, HashSet};
+
+use common::{Part, Stats};
+use grid::{
+    render::{Colour, Frame},
+    Direction, Grid, Point,
+};
+
+use crate::{search, Step};
+
+type State = (Point, Direction, usize);
+
+const fn state(step: &Step) -> State {
+    (step.position, step.direction, step.steps)
+}
+
+fn map(grid: &Grid<usize>) -> Frame {
+    Frame::new(grid, |heat_loss| {
+        char::from_digit(u32::try_from(*heat_loss).unwrap_or(u32::MAX), 10).unwrap_or('?')
+    })
+}
+
+/// The blocks explored by the search as it spreads out from the top left, a frame for every so
+/// many states popped, and then the path with the least heat loss.
+pub fn frames(grid: &Grid<usize>, part: Part) -> Vec<Frame> {
+    let (min_steps, max_steps) = match part {
+        Part::One => (1, 3),
+        Part::Two => (4, 10),
+    };
+
+    let interval = grid.width() * grid.height();
+
+    let mut frames = vec![map(grid).with_caption("Heat loss map")];
+    let mut explored: HashSet<Point> = HashSet::default();
+    let mut popped = 0;
+    let mut previous: HashMap<State, Step> = HashMap::default();
+
+    let goal = search(
+        grid,
+        min_steps,
+        max_steps,
+        &mut Stats::default(),
+        |step| {
+            explored.insert(step.position);
+            popped += 1;
+
+            if popped % interval == 0 {
+                let mut frame = map(grid).with_caption(format!(
+                    "{popped} states explored, heat loss up to {}",
+                    step.heat_loss
+                ));
+
+                frame
+                    .highlight(explored.iter().copied(), Colour::Blue)
+                    .mark(explored.iter().copied(), '#');
+
+                frames.push(frame);
+            }
+        },
+        |step, from| {
+            previous.insert(state(step), *from);
+        },
+    );
+
+    let Some(goal) = goal else {
+        frames.push(map(grid).with_caption("The bottom right cannot be reached"));
+        return frames;
+    };
+
+    let mut path = vec![goal.position];
+    let mut current = goal;
+
+    while let Some(from) = previous.get(&state(&current)) {
+        path.push(from.position);
+        current = *from;
+    }
+
+    path.reverse();
+
+    let mut frame = map(grid).with_caption(format!("Least heat loss {}", goal.heat_loss));
+
+    frame
+        .highlight(explored, Colour::Blue)
+        .path(&path, Colour::Red)
+        .mark(path.last().copied(), '*');
+
+    frames.push(frame);
+
+    frames
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, EXAMPLE_PART1, EXAMPLE_PART2};
+
+    #[test]
+    fn test_frames() {
+        let grid = parse(EXAMPLE_PART1).unwrap();
+
+        let searched = frames(&grid, Part::One);
+        let path = searched.last().unwrap();
+
+        assert_eq!(path.caption, "Least heat loss 102");
+        assert_eq!(
+            path.to_plain(),
+            "Least heat loss 102
+>>v34>>>v1323
+32>>>^35v5623
+32552456>>v54
+3446585845v52
+4546657867>v6
+14385987984v4
+44578769877v6
+36378779796>v
+465496798688v
+456467998645v
+12246868655v<
+25465488877v5
+43226746555>*"
+        );
+
+        let grid = parse(EXAMPLE_PART2).unwrap();
+
+        assert_eq!(
+            frames(&grid, Part::Two).last().unwrap().caption,
+            "Least heat loss 94"
+        );
+    }
+}
@@ -0,0 +1,414 @@
+mod part1;
+mod part2;
+
+use std::{error::Error, fmt};
+
+use common::{checked::Checked, Line, Overflow, Position, Solution};
+use tracing::trace;
+
+pub const EXAMPLE_PART1: &str = "R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+
+pub const EXAMPLE_PART2: &str = EXAMPLE_PART1;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    Empty,
+    MissingField(Position),
+    InvalidDirection(Position),
+    InvalidCount(Position),
+    InvalidColour(Position),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the dig plan is empty"),
+            Self::MissingField(position) => write!(
+                f,
+                "{position}: expected `direction count (#colour)` in `{}`",
+                position.text
+            ),
+            Self::InvalidDirection(position) => {
+                write!(f, "{position}: invalid direction `{}`", position.text)
+            }
+            Self::InvalidCount(position) => {
+                write!(f, "{position}: invalid count `{}`", position.text)
+            }
+            Self::InvalidColour(position) => {
+                write!(f, "{position}: invalid colour `{}`", position.text)
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// One straight stretch of trench.
+#[derive(Debug, Clone, Copy)]
+pub struct Step {
+    pub direction: Direction,
+    pub count: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct DiggingInstruction {
+    pub direction: Direction,
+    pub count: i64,
+    pub colour: String,
+    /// The step hidden in the colour, which part two digs instead.
+    pub decoded: Step,
+}
+
+impl DiggingInstruction {
+    pub const fn step(&self) -> Step {
+        Step {
+            direction: self.direction,
+            count: self.count,
+        }
+    }
+}
+
+/// A corner of the trench, in 128 bits so that neither walking nor the shoelace products can wrap
+/// for any count that parses.
+#[derive(Copy, Clone)]
+struct Point {
+    x: i128,
+    y: i128,
+}
+
+impl Point {
+    fn apply(self, step: Step) -> Result<Self, Overflow> {
+        let count = i128::from(step.count);
+
+        Ok(match step.direction {
+            Direction::Up => Self {
+                x: self.x,
+                y: self.y.try_add(count)?,
+            },
+            Direction::Down => Self {
+                x: self.x,
+                y: self.y.try_sub(count)?,
+            },
+            Direction::Left => Self {
+                x: self.x.try_sub(count)?,
+                y: self.y,
+            },
+            Direction::Right => Self {
+                x: self.x.try_add(count)?,
+                y: self.y,
+            },
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    fn from_str(value: &str) -> Option<Self> {
+        match value {
+            "U" => Some(Self::Up),
+            "R" => Some(Self::Right),
+            "D" => Some(Self::Down),
+            "L" => Some(Self::Left),
+            _ => None,
+        }
+    }
+}
+
+/// The area enclosed by the trench corners, or nothing when no trench was dug.
+fn shoelace(trenches: &[Point], perimeter: i128) -> Result<u128, Overflow> {
+    let (Some(first), Some(last)) = (trenches.first(), trenches.last()) else {
+        return Ok(0);
+    };
+
+    let closing = [*last, *first];
+
+    let sum = trenches
+        .windows(2)
+        .chain([&closing[..]])
+        .try_fold(0_i128, |sum, pair| {
+            let (current, next) = (pair[0], pair[1]);
+
+            sum.try_add(
+                current
+                    .x
+                    .try_mul(next.y)?
+                    .try_sub(current.y.try_mul(next.x)?)?,
+            )
+        })?;
+
+    (sum.unsigned_abs() / 2)
+        .try_add(perimeter.unsigned_abs() / 2)?
+        .try_add(1)
+}
+
+fn parse_instruction(line: Line) -> Result<DiggingInstruction, ParseError> {
+    let (direction_string, count_and_colour_string) = line
+        .text
+        .split_once(' ')
+        .ok_or_else(|| ParseError::MissingField(line.whole()))?;
+
+    let (count, colour_string) = count_and_colour_string
+        .split_once(' ')
+        .ok_or_else(|| ParseError::MissingField(line.whole()))?;
+
+    let direction = Direction::from_str(direction_string)
+        .ok_or_else(|| ParseError::InvalidDirection(line.position(direction_string)))?;
+
+    let count = count
+        .chars()
+        .all(|c| c.is_ascii_digit())
+        .then(|| count.parse::<i64>().ok())
+        .flatten()
+        .ok_or_else(|| ParseError::InvalidCount(line.position(count)))?;
+
+    // The colour doubles as the part two instruction.
+    let (colour, decoded) = colour_string
+        .strip_prefix("(#")
+        .and_then(|colour| colour.strip_suffix(')'))
+        .and_then(|colour| Some((colour, part2::decode_colour(colour)?)))
+        .ok_or_else(|| ParseError::InvalidColour(line.position(colour_string)))?;
+
+    Ok(DiggingInstruction {
+        direction,
+        count,
+        colour: colour.to_string(),
+        decoded,
+    })
+}
+
+fn calculate_area(steps: impl IntoIterator<Item = Step>) -> Result<u128, Overflow> {
+    let initial = Point { x: 0, y: 0 };
+
+    let mut current = initial;
+
+    let mut trenches: Vec<Point> = Vec::default();
+
+    let mut perimeter: i128 = 0;
+
+    for step in steps {
+        let new = current.apply(step)?;
+
+        trenches.push(new);
+
+        current = new;
+
+        perimeter = perimeter.try_add(i128::from(step.count))?;
+    }
+
+    let result = shoelace(&trenches, perimeter)?;
+
+    trace!(%result, "Result");
+
+    Ok(result)
+}
+
+pub fn parse(input: &str) -> Result<Vec<DiggingInstruction>, ParseError> {
+    let instructions = common::lines(input)
+        .map(parse_instruction)
+        .collect::<Result<Vec<DiggingInstruction>, ParseError>>()?;
+
+    if instructions.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    Ok(instructions)
+}
+
+pub fn solve_part1(instructions: &[DiggingInstruction]) -> Result<u128, Overflow> {
+    calculate_area(instructions.iter().map(DiggingInstruction::step))
+}
+
+pub fn solve_part2(instructions: &[DiggingInstruction]) -> Result<u128, Overflow> {
+    calculate_area(instructions.iter().map(|instruction| instruction.decoded))
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<DiggingInstruction>;
+    type Error = ParseError;
+    type Part1 = u128;
+    type Part2 = u128;
+
+    const DAY: u8 = 18;
+
+    const EXAMPLE_PART1: &'static str = EXAMPLE_PART1;
+    const EXAMPLE_PART2: &'static str = EXAMPLE_PART2;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Overflow> {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Overflow> {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Dig plans tracing the outline of a histogram, which is always a simple polygon.
+    fn dig_plan() -> impl Strategy<Value = Vec<(char, isize)>> {
+        prop::collection::vec((1..20isize, 1..20isize), 1..8).prop_map(|columns| {
+            let mut plan: Vec<(char, isize)> = Vec::default();
+            let mut height = 0;
+
+            for (width, column_height) in &columns {
+                match column_height - height {
+                    rise if rise > 0 => plan.push(('U', rise)),
+                    fall if fall < 0 => plan.push(('D', -fall)),
+                    _ => {}
+                }
+
+                plan.push(('R', *width));
+                height = *column_height;
+            }
+
+            plan.push(('D', height));
+            plan.push(('L', columns.iter().map(|(width, _)| width).sum()));
+
+            plan
+        })
+    }
+
+    fn area(plan: &[(char, isize)]) -> Result<u128, Overflow> {
+        let input: Vec<String> = plan
+            .iter()
+            .map(|(direction, count)| format!("{direction} {count} (#000000)"))
+            .collect();
+
+        solve_part1(&parse(&input.join("\n")).unwrap())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(parse("").unwrap_err(), ParseError::Empty);
+
+        assert_eq!(
+            parse("R 6 (#70c710)\nX 5 (#0dc571)").unwrap_err(),
+            ParseError::InvalidDirection(Position {
+                line: 2,
+                column: 1,
+                text: String::from("X"),
+            })
+        );
+
+        assert_eq!(
+            parse("R 6 (#70c710)\nD -5 (#0dc571)").unwrap_err(),
+            ParseError::InvalidCount(Position {
+                line: 2,
+                column: 3,
+                text: String::from("-5"),
+            })
+        );
+
+        assert_eq!(
+            parse("R 6 (#70c710)\nD 5 (#0dc57)")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 5: invalid colour `(#0dc57)`"
+        );
+
+        assert_eq!(
+            parse("R 6 (#70c714)").unwrap_err().to_string(),
+            "line 1, column 5: invalid colour `(#70c714)`"
+        );
+
+        assert_eq!(
+            parse("R 6").unwrap_err().to_string(),
+            "line 1, column 1: expected `direction count (#colour)` in `R 6`"
+        );
+    }
+
+    #[test]
+    fn test_empty_plan() {
+        assert_eq!(solve_part1(&[]), Ok(0));
+        assert_eq!(solve_part2(&[]), Ok(0));
+    }
+
+    #[test]
+    fn test_wide_area() {
+        let side = 1_isize << 40;
+
+        assert_eq!(
+            area(&[('R', side), ('D', side), ('L', side), ('U', side)]),
+            Ok((1 << 80) + (1 << 41) + 1)
+        );
+
+        // Corners `2 * isize::MAX` apart, whose products no longer fit in 128 bits.
+        let plan: Vec<(char, isize)> = ['R', 'R', 'D', 'D', 'L', 'L', 'U', 'U']
+            .into_iter()
+            .map(|direction| (direction, isize::MAX))
+            .collect();
+
+        assert_eq!(area(&plan), Err(Overflow));
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_never_panics(
+            input in prop_oneof![
+                any::<String>(),
+                r"([UDLRX] [0-9]{1,3} \(#[0-9a-fg]{5,7}\)\n?){0,6}",
+            ]
+        ) {
+            let _ = parse(&input);
+        }
+
+        #[test]
+        fn test_area_independent_of_start_and_orientation(
+            plan in dig_plan(),
+            start in any::<prop::sample::Index>()
+        ) {
+            let expected = area(&plan);
+
+            let mut rotated = plan.clone();
+            rotated.rotate_left(start.index(plan.len()));
+
+            prop_assert_eq!(area(&rotated), expected);
+
+            let reversed: Vec<(char, isize)> = plan
+                .iter()
+                .rev()
+                .map(|(direction, count)| {
+                    let opposite = match direction {
+                        'U' => 'D',
+                        'D' => 'U',
+                        'L' => 'R',
+                        _ => 'L',
+                    };
+
+                    (opposite, *count)
+                })
+                .collect();
+
+            prop_assert_eq!(area(&reversed), expected);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{Direction, Step};
+
+fn direction_from_hex(value: &str) -> Option<Direction> {
+    match value {
+        "0" => Some(Direction::Right),
+        "1" => Some(Direction::Down),
+        "2" => Some(Direction::Left),
+        "3" => Some(Direction::Up),
+        _ => None,
+    }
+}
+
+/// The step hidden in a colour: five hex digits of distance followed by a direction digit
+/// between 0 and 3.
+pub(crate) fn decode_colour(colour: &str) -> Option<Step> {
+    let (meters, direction) = colour.split_at_checked(5)?;
+
+    // `from_str_radix` would also take a sign.
+    if !meters.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    Some(Step {
+        direction: direction_from_hex(direction)?,
+        count: i64::from_str_radix(meters, 16).ok()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part2, EXAMPLE_PART2};
+
+    #[test]
+    fn test_decode_colour() {
+        let step = decode_colour("70c710").unwrap();
+
+        assert!(matches!(step.direction, Direction::Right));
+        assert_eq!(step.count, 461_937);
+
+        assert!(matches!(
+            decode_colour("caa173").map(|step| step.direction),
+            Some(Direction::Up)
+        ));
+
+        for colour in ["", "70c71", "70c714", "70c7100", "+0c710", "70c7é0"] {
+            assert!(decode_colour(colour).is_none(), "{colour}");
+        }
+    }
+
+    #[test]
+    fn test_given_example() {
+        let lines = EXAMPLE_PART2;
+
+        let result = solve_part2(&parse(lines).unwrap());
+
+        assert_eq!(result, Ok(952_408_144_115));
+    }
+}
@@ -0,0 +1,462 @@
+pub mod render;
+
+use std::{
+    error::Error,
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use common::{Line, Position};
+
+/// A cell in a [`Grid`], with `x` counting columns from the left and `y` rows from the top.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// The neighbouring point in `direction`, or `None` when that would leave the top or left
+    /// edge. Use [`Grid::step`] to also stay within the bottom and right edges.
+    pub fn step(self, direction: Direction) -> Option<Self> {
+        Some(match direction {
+            Direction::North => Self::new(self.x, self.y.checked_sub(1)?),
+            Direction::East => Self::new(self.x.checked_add(1)?, self.y),
+            Direction::South => Self::new(self.x, self.y.checked_add(1)?),
+            Direction::West => Self::new(self.x.checked_sub(1)?, self.y),
+        })
+    }
+
+    pub const fn manhattan_distance(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    pub const ALL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::North => Self::South,
+            Self::East => Self::West,
+            Self::South => Self::North,
+            Self::West => Self::East,
+        }
+    }
+
+    pub const fn turn_left(self) -> Self {
+        match self {
+            Self::North => Self::West,
+            Self::East => Self::North,
+            Self::South => Self::East,
+            Self::West => Self::South,
+        }
+    }
+
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Self::North => Self::East,
+            Self::East => Self::South,
+            Self::South => Self::West,
+            Self::West => Self::North,
+        }
+    }
+
+    pub const fn is_vertical(self) -> bool {
+        matches!(self, Self::North | Self::South)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    Empty,
+    InvalidCell(Position),
+    RaggedLine(Position, usize),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the grid is empty"),
+            Self::InvalidCell(position) => {
+                write!(f, "{position}: invalid cell `{}`", position.text)
+            }
+            Self::RaggedLine(position, width) => write!(
+                f,
+                "{position}: expected {width} characters, got {} in `{}`",
+                position.text.chars().count(),
+                position.text
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// A rectangular grid of cells stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, or `None` if they are not all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x, y)))
+            .map(&mut f)
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one cell per character of `input`, using `cell` to convert each character.
+    pub fn parse(input: &str, cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        Self::from_lines(common::lines(input), cell)
+    }
+
+    /// Like [`Grid::parse`], for grids that are only part of the input, so that errors still
+    /// point at the right line.
+    pub fn from_lines<'a>(
+        lines: impl IntoIterator<Item = Line<'a>>,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width: Option<usize> = None;
+        let mut height = 0;
+        let mut cells: Vec<T> = Vec::default();
+
+        for line in lines {
+            for (index, c) in line.text.char_indices() {
+                cells.push(cell(c).ok_or_else(|| {
+                    ParseError::InvalidCell(line.position(&line.text[index..index + c.len_utf8()]))
+                })?);
+            }
+
+            let line_width = line.text.chars().count();
+
+            match width {
+                Some(width) if width != line_width => {
+                    return Err(ParseError::RaggedLine(line.whole(), width));
+                }
+                _ => width = Some(line_width),
+            }
+
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self {
+                width,
+                height,
+                cells,
+            }),
+            _ => Err(ParseError::Empty),
+        }
+    }
+
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    pub const fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.cells[point.y * self.width + point.x])
+    }
+
+    /// The neighbouring point in `direction`, or `None` when that would leave the grid.
+    pub fn step(&self, point: Point, direction: Direction) -> Option<Point> {
+        point
+            .step(direction)
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// The up to four points sharing an edge with `point`, as the direction to reach them and
+    /// the point itself.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = (Direction, Point)> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| Some((direction, self.step(point, direction)?)))
+    }
+
+    /// The up to eight points sharing an edge or a corner with `point`.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        (-1..=1isize)
+            .flat_map(|dy| (-1..=1isize).map(move |dx| (dx, dy)))
+            .filter(|offset| *offset != (0, 0))
+            .filter_map(move |(dx, dy)| {
+                Some(Point::new(
+                    point.x.checked_add_signed(dx)?,
+                    point.y.checked_add_signed(dy)?,
+                ))
+            })
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every point in the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Swaps rows and columns, mirroring the grid along its main diagonal.
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |point| {
+            self[Point::new(point.y, point.x)].clone()
+        })
+    }
+
+    #[must_use]
+    pub fn rotate_clockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |point| {
+            self[Point::new(point.y, self.height - 1 - point.x)].clone()
+        })
+    }
+
+    #[must_use]
+    pub fn rotate_counterclockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |point| {
+            self[Point::new(self.width - 1 - point.y, point.x)].clone()
+        })
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point} is outside the grid"))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(input: &str) -> Grid<char> {
+        Grid::parse(input, Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let parsed = grid("ab\ncd\nef");
+
+        assert_eq!(parsed.width(), 2);
+        assert_eq!(parsed.height(), 3);
+        assert_eq!(parsed[Point::new(1, 2)], 'f');
+        assert_eq!(parsed.to_string(), "ab\ncd\nef");
+
+        assert_eq!(Grid::parse("", Some), Err(ParseError::Empty));
+
+        assert_eq!(
+            Grid::parse("ab\nc", Some).unwrap_err().to_string(),
+            "line 2, column 1: expected 2 characters, got 1 in `c`"
+        );
+
+        assert_eq!(
+            Grid::parse("12\n3x", |c| c.to_digit(10)),
+            Err(ParseError::InvalidCell(Position {
+                line: 2,
+                column: 2,
+                text: String::from("x"),
+            }))
+        );
+
+        let lines = [Line::new(7, "#."), Line::new(8, "#")];
+
+        assert!(matches!(
+            Grid::from_lines(lines, Some),
+            Err(ParseError::RaggedLine(Position { line: 8, .. }, 2))
+        ));
+    }
+
+    #[test]
+    fn test_direction() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.opposite().opposite(), direction);
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+            assert_ne!(direction.is_vertical(), direction.turn_left().is_vertical());
+        }
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let parsed = grid("abc\ndef\nghi");
+
+        let corner: Vec<(Direction, Point)> = parsed.neighbours4(Point::new(0, 0)).collect();
+
+        assert_eq!(
+            corner,
+            vec![
+                (Direction::East, Point::new(1, 0)),
+                (Direction::South, Point::new(0, 1))
+            ]
+        );
+
+        assert_eq!(parsed.neighbours4(Point::new(1, 1)).count(), 4);
+        assert_eq!(parsed.neighbours8(Point::new(1, 1)).count(), 8);
+        assert_eq!(parsed.neighbours8(Point::new(2, 2)).count(), 3);
+
+        assert_eq!(parsed.step(Point::new(2, 0), Direction::East), None);
+        assert_eq!(
+            parsed.step(Point::new(2, 0), Direction::South),
+            Some(Point::new(2, 1))
+        );
+        assert_eq!(parsed.get(Point::new(3, 0)), None);
+    }
+
+    #[test]
+    fn test_views() {
+        let parsed = grid("abc\ndef");
+
+        assert_eq!(parsed.row(1), ['d', 'e', 'f']);
+        assert_eq!(parsed.rows().count(), 2);
+        assert_eq!(parsed.column(2).collect::<String>(), "cf");
+
+        let columns: Vec<String> = parsed.columns().map(Iterator::collect).collect();
+
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+
+        assert_eq!(
+            parsed
+                .iter()
+                .find(|(_, c)| **c == 'e')
+                .map(|(point, _)| point),
+            Some(Point::new(1, 1))
+        );
+    }
+
+    #[test]
+    fn test_transform() {
+        let parsed = grid("abc\ndef");
+
+        assert_eq!(parsed.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(parsed.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(parsed.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(parsed.rotate_clockwise().rotate_counterclockwise(), parsed);
+        assert_eq!(
+            parsed.map(|c| c.to_ascii_uppercase()).to_string(),
+            "ABC\nDEF"
+        );
+    }
+}
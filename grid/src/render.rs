@@ -0,0 +1,348 @@
+//! Drawing grids with highlighted cells, paths and coloured regions, either to a terminal with
+//! ANSI colours or as plain text, one frame at a time.
+
+use std::{
+    fmt::Write as _,
+    io::{self, BufRead, Write},
+    thread,
+    time::Duration,
+};
+
+use crate::{Direction, Grid, Point};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Colour {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Colour {
+    const fn foreground_code(self) -> u8 {
+        30 + self as u8
+    }
+
+    const fn background_code(self) -> u8 {
+        40 + self as u8
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cell {
+    pub glyph: char,
+    pub foreground: Option<Colour>,
+    pub background: Option<Colour>,
+    /// Drawn instead of `glyph` in plain text, where colours cannot pick the cell out.
+    pub mark: Option<char>,
+}
+
+impl Cell {
+    pub const fn new(glyph: char) -> Self {
+        Self {
+            glyph,
+            foreground: None,
+            background: None,
+            mark: None,
+        }
+    }
+}
+
+/// One picture of a grid, with a caption saying what it shows.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    pub caption: String,
+    pub cells: Grid<Cell>,
+}
+
+impl Frame {
+    pub fn new<T>(grid: &Grid<T>, mut glyph: impl FnMut(&T) -> char) -> Self {
+        Self {
+            caption: String::new(),
+            cells: grid.map(|value| Cell::new(glyph(value))),
+        }
+    }
+
+    #[must_use]
+    pub fn with_caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = caption.into();
+        self
+    }
+
+    /// Applies `f` to each cell at `points`, skipping any outside the grid.
+    fn update(&mut self, points: impl IntoIterator<Item = Point>, mut f: impl FnMut(&mut Cell)) {
+        for point in points {
+            if let Some(cell) = self.cells.get_mut(point) {
+                f(cell);
+            }
+        }
+    }
+
+    /// Colours the background of `points`.
+    pub fn highlight(
+        &mut self,
+        points: impl IntoIterator<Item = Point>,
+        colour: Colour,
+    ) -> &mut Self {
+        self.update(points, |cell| cell.background = Some(colour));
+        self
+    }
+
+    /// Colours the glyphs of `points`.
+    pub fn region(&mut self, points: impl IntoIterator<Item = Point>, colour: Colour) -> &mut Self {
+        self.update(points, |cell| cell.foreground = Some(colour));
+        self
+    }
+
+    /// Shows `points` as `glyph` in plain text.
+    pub fn mark(&mut self, points: impl IntoIterator<Item = Point>, glyph: char) -> &mut Self {
+        self.update(points, |cell| cell.mark = Some(glyph));
+        self
+    }
+
+    /// Draws `path` as arrows from each point towards the next, in `colour`. The last point, and
+    /// any point not next to its successor, keeps its glyph but is still coloured.
+    pub fn path(&mut self, path: &[Point], colour: Colour) -> &mut Self {
+        for (index, point) in path.iter().enumerate() {
+            let direction = path.get(index + 1).and_then(|next| {
+                Direction::ALL
+                    .into_iter()
+                    .find(|direction| point.step(*direction) == Some(*next))
+            });
+
+            self.update([*point], |cell| {
+                if let Some(direction) = direction {
+                    let arrow = match direction {
+                        Direction::North => '^',
+                        Direction::East => '>',
+                        Direction::South => 'v',
+                        Direction::West => '<',
+                    };
+
+                    cell.glyph = arrow;
+                    cell.mark = Some(arrow);
+                }
+
+                cell.foreground = Some(colour);
+            });
+        }
+
+        self
+    }
+
+    pub fn to_plain(&self) -> String {
+        let mut text = self.caption.clone();
+
+        for row in self.cells.rows() {
+            text.push('\n');
+            text.extend(row.iter().map(|cell| cell.mark.unwrap_or(cell.glyph)));
+        }
+
+        text
+    }
+
+    /// The frame with ANSI escape codes for its colours, resetting them at the end of each run
+    /// of identically styled cells.
+    pub fn to_ansi(&self) -> String {
+        let mut text = format!("\x1b[1m{}\x1b[0m", self.caption);
+
+        for row in self.cells.rows() {
+            text.push('\n');
+
+            let mut style = (None, None);
+
+            for cell in row {
+                let cell_style = (cell.foreground, cell.background);
+
+                if cell_style != style {
+                    if style != (None, None) {
+                        text.push_str("\x1b[0m");
+                    }
+
+                    if let Some(colour) = cell.foreground {
+                        let _ = write!(text, "\x1b[{}m", colour.foreground_code());
+                    }
+
+                    if let Some(colour) = cell.background {
+                        let _ = write!(text, "\x1b[{}m", colour.background_code());
+                    }
+
+                    style = cell_style;
+                }
+
+                text.push(cell.glyph);
+            }
+
+            if style != (None, None) {
+                text.push_str("\x1b[0m");
+            }
+        }
+
+        text
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Playback {
+    /// Every frame in turn, separated by blank lines.
+    Dump,
+    /// One frame at a time, waiting for Enter before the next; `p` goes back and `q` stops.
+    Step,
+    /// One frame at a time in place, pausing between them.
+    Animate(Duration),
+}
+
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// Plays `frames` to `out`, reading keys from `keys` when stepping through them.
+pub fn play(
+    frames: &[Frame],
+    playback: Playback,
+    colour: bool,
+    out: &mut impl Write,
+    keys: &mut impl BufRead,
+) -> io::Result<()> {
+    let render = |frame: &Frame| {
+        if colour {
+            frame.to_ansi()
+        } else {
+            frame.to_plain()
+        }
+    };
+
+    match playback {
+        Playback::Dump => {
+            for (index, frame) in frames.iter().enumerate() {
+                if index > 0 {
+                    writeln!(out)?;
+                }
+
+                writeln!(out, "{}", render(frame))?;
+            }
+        }
+        Playback::Step => {
+            let mut index = 0;
+
+            while let Some(frame) = frames.get(index) {
+                write!(out, "{CLEAR_SCREEN}{}\n\n", render(frame))?;
+                write!(
+                    out,
+                    "Frame {} of {}: Enter for the next, p for the previous, q to quit ",
+                    index + 1,
+                    frames.len()
+                )?;
+                out.flush()?;
+
+                let mut key = String::new();
+
+                if keys.read_line(&mut key)? == 0 {
+                    break;
+                }
+
+                match key.trim() {
+                    "q" => break,
+                    "p" => index = index.saturating_sub(1),
+                    _ => index += 1,
+                }
+            }
+
+            writeln!(out)?;
+        }
+        Playback::Animate(delay) => {
+            for frame in frames {
+                write!(out, "{CLEAR_SCREEN}{}", render(frame))?;
+                out.flush()?;
+                thread::sleep(delay);
+            }
+
+            writeln!(out)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame() -> Frame {
+        Frame::new(&Grid::parse("...\n.#.\n...", Some).unwrap(), |c| *c).with_caption("Test")
+    }
+
+    #[test]
+    fn test_plain() {
+        let mut frame = frame();
+
+        frame
+            .path(
+                &[
+                    Point::new(0, 0),
+                    Point::new(1, 0),
+                    Point::new(2, 0),
+                    Point::new(2, 1),
+                    Point::new(2, 2),
+                ],
+                Colour::Red,
+            )
+            .highlight([Point::new(0, 2), Point::new(1, 2)], Colour::Yellow)
+            .mark([Point::new(0, 2), Point::new(1, 2), Point::new(5, 5)], 'I');
+
+        assert_eq!(frame.to_plain(), "Test\n>>v\n.#v\nII.");
+    }
+
+    #[test]
+    fn test_ansi() {
+        let mut frame = frame();
+
+        frame
+            .highlight([Point::new(0, 0), Point::new(1, 0)], Colour::Yellow)
+            .region([Point::new(1, 1)], Colour::Red);
+
+        assert_eq!(
+            frame.to_ansi(),
+            "\x1b[1mTest\x1b[0m\n\x1b[43m..\x1b[0m.\n.\x1b[31m#\x1b[0m.\n..."
+        );
+    }
+
+    #[test]
+    fn test_play() {
+        let frames = vec![
+            frame().with_caption("One"),
+            frame().with_caption("Two"),
+            frame().with_caption("Three"),
+        ];
+
+        let mut out = Vec::new();
+        play(&frames, Playback::Dump, false, &mut out, &mut io::empty()).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "One\n...\n.#.\n...\n\nTwo\n...\n.#.\n...\n\nThree\n...\n.#.\n...\n"
+        );
+
+        let mut out = Vec::new();
+        play(
+            &frames,
+            Playback::Step,
+            false,
+            &mut out,
+            &mut "\np\n\nq\n".as_bytes(),
+        )
+        .unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+
+        let captions: Vec<&str> = out
+            .split(CLEAR_SCREEN)
+            .skip(1)
+            .filter_map(|frame| frame.lines().next())
+            .collect();
+
+        assert_eq!(captions, ["One", "Two", "One", "Two"]);
+    }
+}